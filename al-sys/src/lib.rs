@@ -7,6 +7,8 @@ mod alc;
 mod al;
 mod efx;
 mod efx_presets;
+#[cfg(feature = "software")]
+mod software;
 
 pub use alc::*;
 pub use al::*;
@@ -14,9 +16,76 @@ pub use efx::*;
 pub use efx_presets::*;
 
 
+/// Items shared by both flavors of `al_api!`.
+macro_rules! al_api_common {
+	{
+		ext {
+			$($ext_sym:ident: unsafe extern "C" fn ($($ext_param:ident: $ext_param_ty:ty),*) -> $ext_ret_ty:ty,)*
+		}
+
+		$($sym:ident: unsafe extern "C" fn ($($param:ident: $param_ty:ty),*) -> $ret_ty:ty,)*
+	} => {
+		/// Table of entry points, for implementations that are not loaded from a library.
+		#[allow(non_snake_case)]
+		#[derive(Copy, Clone)]
+		pub struct AlFnTable {
+			$(pub $sym: unsafe extern "C" fn ($($param: $param_ty),*) -> $ret_ty,)*
+		}
+
+
+		/// A completed call through a traced `AlApi`, with its arguments and return value.
+		#[allow(non_camel_case_types, non_snake_case)]
+		#[derive(Copy, Clone, Debug)]
+		pub enum AlCall {
+			$($sym{$($param: $param_ty,)* ret: $ret_ty},)*
+			$($ext_sym{$($ext_param: $ext_param_ty,)* ret: $ext_ret_ty},)*
+		}
+
+
+		impl AlCall {
+			/// Name of the entry point that was called.
+			pub fn name(&self) -> &'static str {
+				match *self {
+					$(AlCall::$sym{..} => stringify!($sym),)*
+					$(AlCall::$ext_sym{..} => stringify!($ext_sym),)*
+				}
+			}
+		}
+
+
+		impl AlApi {
+			$(/// Call an extension entry point obtained through `alGetProcAddress` or `alcGetProcAddress`,
+			/// reporting the call to the tracer if these entry points are traced.
+			#[allow(non_snake_case)]
+			pub unsafe fn $ext_sym(&self, entry: unsafe extern "C" fn ($($ext_param: $ext_param_ty),*) -> $ext_ret_ty, $($ext_param: $ext_param_ty),*) -> $ext_ret_ty {
+				match self.0 {
+					Symbols::Traced(ref api, ref tracer) => {
+						let ret = api.$ext_sym(entry, $($ext_param),*);
+						tracer.trace(&AlCall::$ext_sym{$($ext_param: $ext_param,)* ret: ret});
+						ret
+					},
+					_ => entry($($ext_param),*),
+				}
+			})*
+		}
+
+
+		/// Observer of the calls made through an `AlApi` created with `AlApi::traced`.
+		pub trait AlTrace: Send + Sync {
+			/// Invoked after every call returns, while any pointers in the call are still valid.
+			unsafe fn trace(&self, call: &AlCall);
+		}
+	};
+}
+
+
 #[cfg(all(not(target_os = "emscripten"), feature = "dynamic"))]
 macro_rules! al_api {
 	{
+		ext {
+			$($ext_sym:ident: unsafe extern "C" fn ($($ext_param:ident: $ext_param_ty:ty),*) -> $ext_ret_ty:ty,)*
+		}
+
 		$($sym:ident: unsafe extern "C" fn ($($param:ident: $param_ty:ty),*) -> $ret_ty:ty,)*
 	} => {
 		mod al_api {
@@ -24,10 +93,20 @@ macro_rules! al_api {
 
 			use std::io;
 			use std::path::Path;
+			use std::sync::Arc;
 
 			use super::*;
 
 
+			al_api_common! {
+				ext {
+					$($ext_sym: unsafe extern "C" fn ($($ext_param: $ext_param_ty),*) -> $ext_ret_ty,)*
+				}
+
+				$($sym: unsafe extern "C" fn ($($param: $param_ty),*) -> $ret_ty,)*
+			}
+
+
 			#[allow(non_snake_case)]
 			pub struct AlSymbols<'lib> {
 				$(pub $sym: libloading::Symbol<'lib, unsafe extern "C" fn ($($param: $param_ty),*) -> $ret_ty>,)*
@@ -48,7 +127,14 @@ macro_rules! al_api {
 			use self::rent::RentSymbols;
 
 
-			pub struct AlApi(rent::RentSymbols);
+			pub struct AlApi(Symbols);
+
+
+			enum Symbols {
+				Lib(rent::RentSymbols),
+				Table(AlFnTable),
+				Traced(Box<AlApi>, Arc<dyn AlTrace>),
+			}
 
 
 			impl AlApi {
@@ -71,21 +157,52 @@ macro_rules! al_api {
 					match RentSymbols::try_new(Box::new(lib), |lib| Ok(AlSymbols{
 						$($sym: unsafe { lib.get(stringify!($sym).as_bytes())? },)*
 					})) {
-						Ok(syms) => Ok(AlApi(syms)),
+						Ok(syms) => Ok(AlApi(Symbols::Lib(syms))),
 						Err(rental::RentalError(e, _)) => return Err(e),
 					}
 				}
 
 
+				/// Use the entry points in `table` in place of a loaded library.
+				/// Every entry must implement the OpenAL 1.1 semantics of the function it replaces,
+				/// and `alGetProcAddress`/`alcGetProcAddress` must return the entry points of any extension advertised by the table.
+				pub fn from_table(table: AlFnTable) -> AlApi {
+					AlApi(Symbols::Table(table))
+				}
+
+
+				/// Wrap another set of entry points, reporting every call made through them to `tracer`.
+				pub fn traced(api: AlApi, tracer: Arc<dyn AlTrace>) -> AlApi {
+					AlApi(Symbols::Traced(Box::new(api), tracer))
+				}
+
+
+				/// Whether calls through these entry points are being reported to a tracer.
+				pub fn is_traced(&self) -> bool {
+					match self.0 {
+						Symbols::Traced(..) => true,
+						_ => false,
+					}
+				}
+
+
 				$(#[allow(non_snake_case)]
 				#[inline]
 				pub unsafe fn $sym(&self, $($param: $param_ty),*) -> $ret_ty {
-					self.0.rent(|s| (s.$sym)($($param),*))
+					match self.0 {
+						Symbols::Lib(ref syms) => syms.rent(|s| (s.$sym)($($param),*)),
+						Symbols::Table(ref table) => (table.$sym)($($param),*),
+						Symbols::Traced(ref api, ref tracer) => {
+							let ret = api.$sym($($param),*);
+							tracer.trace(&AlCall::$sym{$($param: $param,)* ret: ret});
+							ret
+						},
+					}
 				})*
 			}
 		}
 
-		pub use al_api::AlApi;
+		pub use al_api::{AlApi, AlFnTable, AlCall, AlTrace};
 	};
 }
 
@@ -93,11 +210,17 @@ macro_rules! al_api {
 #[cfg(any(target_os = "emscripten", not(feature = "dynamic")))]
 macro_rules! al_api {
 	{
+		ext {
+			$($ext_sym:ident: unsafe extern "C" fn ($($ext_param:ident: $ext_param_ty:ty),*) -> $ext_ret_ty:ty,)*
+		}
+
 		$($sym:ident: unsafe extern "C" fn ($($param:ident: $param_ty:ty),*) -> $ret_ty:ty,)*
 	} => {
 		mod al_api {
 			use std::io;
 			use std::path::Path;
+			use std::sync::Arc;
+
 			use super::*;
 
 
@@ -111,12 +234,28 @@ macro_rules! al_api {
 			}
 
 
-			pub struct AlApi;
+			al_api_common! {
+				ext {
+					$($ext_sym: unsafe extern "C" fn ($($ext_param: $ext_param_ty),*) -> $ext_ret_ty,)*
+				}
+
+				$($sym: unsafe extern "C" fn ($($param: $param_ty),*) -> $ret_ty,)*
+			}
+
+
+			pub struct AlApi(Symbols);
+
+
+			enum Symbols {
+				Static,
+				Table(AlFnTable),
+				Traced(Box<AlApi>, Arc<dyn AlTrace>),
+			}
 
 
 			impl AlApi {
 				pub fn load_default() -> io::Result<AlApi> {
-					Ok(AlApi)
+					Ok(AlApi(Symbols::Static))
 				}
 
 
@@ -125,20 +264,125 @@ macro_rules! al_api {
 				}
 
 
+				/// Use the entry points in `table` in place of a loaded library.
+				/// Every entry must implement the OpenAL 1.1 semantics of the function it replaces,
+				/// and `alGetProcAddress`/`alcGetProcAddress` must return the entry points of any extension advertised by the table.
+				pub fn from_table(table: AlFnTable) -> AlApi {
+					AlApi(Symbols::Table(table))
+				}
+
+
+				/// Wrap another set of entry points, reporting every call made through them to `tracer`.
+				pub fn traced(api: AlApi, tracer: Arc<dyn AlTrace>) -> AlApi {
+					AlApi(Symbols::Traced(Box::new(api), tracer))
+				}
+
+
+				/// Whether calls through these entry points are being reported to a tracer.
+				pub fn is_traced(&self) -> bool {
+					match self.0 {
+						Symbols::Traced(..) => true,
+						_ => false,
+					}
+				}
+
+
 				$(#[allow(non_snake_case)]
 				#[inline]
 				pub unsafe fn $sym(&self, $($param: $param_ty),*) -> $ret_ty {
-					al_symbols::$sym($($param),*)
+					match self.0 {
+						Symbols::Static => al_symbols::$sym($($param),*),
+						Symbols::Table(ref table) => (table.$sym)($($param),*),
+						Symbols::Traced(ref api, ref tracer) => {
+							let ret = api.$sym($($param),*);
+							tracer.trace(&AlCall::$sym{$($param: $param,)* ret: ret});
+							ret
+						},
+					}
 				})*
 			}
 		}
 
-		pub use al_api::AlApi;
+		pub use al_api::{AlApi, AlFnTable, AlCall, AlTrace};
 	};
 }
 
 
 al_api! {
+	// Extension entry points that are traced along with the core ones.
+	ext {
+		alcSetThreadContext: unsafe extern "C" fn(context: *mut ALCcontext) -> ALCboolean,
+		alcGetThreadContext: unsafe extern "C" fn() -> *mut ALCcontext,
+
+		alcLoopbackOpenDeviceSOFT: unsafe extern "C" fn(deviceName: *const ALCchar) -> *mut ALCdevice,
+		alcIsRenderFormatSupportedSOFT: unsafe extern "C" fn(device: *mut ALCdevice, frequency: ALCsizei, channels: ALCenum, type_: ALCenum) -> ALCboolean,
+		alcRenderSamplesSOFT: unsafe extern "C" fn(device: *mut ALCdevice, buffer: *mut ALvoid, samples: ALCsizei) -> (),
+		alcGetStringiSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, paramName: ALCenum, index: ALCsizei) -> *const ALCchar,
+		alcResetDeviceSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, attrList: *const ALCint) -> ALCboolean,
+		alcDevicePauseSOFT: unsafe extern "C" fn(dev: *mut ALCdevice) -> (),
+		alcDeviceResumeSOFT: unsafe extern "C" fn(dev: *mut ALCdevice) -> (),
+		alcGetInteger64vSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, pname: ALCenum, size: ALsizei, values: *mut i64) -> (),
+		alcReopenDeviceSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, deviceName: *const ALCchar, attribs: *const ALCint) -> ALCboolean,
+
+		alGenAuxiliaryEffectSlots: unsafe extern "C" fn(n: ALsizei, auxiliaryeffectslots: *mut ALuint) -> (),
+		alDeleteAuxiliaryEffectSlots: unsafe extern "C" fn(n: ALsizei, auxiliaryeffectslots: *mut ALuint) -> (),
+		alAuxiliaryEffectSloti: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, iValue: ALint) -> (),
+		alAuxiliaryEffectSlotiv: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, piValues: *mut ALint) -> (),
+		alAuxiliaryEffectSlotf: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, flValue: ALfloat) -> (),
+		alAuxiliaryEffectSlotfv: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, pflValues: *mut ALfloat) -> (),
+		alGetAuxiliaryEffectSloti: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, piValue: *mut ALint) -> (),
+		alGetAuxiliaryEffectSlotiv: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, piValues: *mut ALint) -> (),
+		alGetAuxiliaryEffectSlotf: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, pflValue: *mut ALfloat) -> (),
+		alGetAuxiliaryEffectSlotfv: unsafe extern "C" fn(auxiliaryeffectslot: ALuint, param: ALenum, pflValues: *mut ALfloat) -> (),
+		alGenEffects: unsafe extern "C" fn(n: ALsizei, effects: *mut ALuint) -> (),
+		alDeleteEffects: unsafe extern "C" fn(n: ALsizei, effects: *mut ALuint) -> (),
+		alEffecti: unsafe extern "C" fn(effect: ALuint, param: ALenum, iValue: ALint) -> (),
+		alEffectiv: unsafe extern "C" fn(effect: ALuint, param: ALenum, piValues: *mut ALint) -> (),
+		alEffectf: unsafe extern "C" fn(effect: ALuint, param: ALenum, flValue: ALfloat) -> (),
+		alEffectfv: unsafe extern "C" fn(effect: ALuint, param: ALenum, pflValues: *mut ALfloat) -> (),
+		alGetEffecti: unsafe extern "C" fn(effect: ALuint, param: ALenum, piValue: *mut ALint) -> (),
+		alGetEffectiv: unsafe extern "C" fn(effect: ALuint, param: ALenum, piValues: *mut ALint) -> (),
+		alGetEffectf: unsafe extern "C" fn(effect: ALuint, param: ALenum, pflValue: *mut ALfloat) -> (),
+		alGetEffectfv: unsafe extern "C" fn(effect: ALuint, param: ALenum, pflValues: *mut ALfloat) -> (),
+		alGenFilters: unsafe extern "C" fn(n: ALsizei, filters: *mut ALuint) -> (),
+		alDeleteFilters: unsafe extern "C" fn(n: ALsizei, filters: *mut ALuint) -> (),
+		alFilteri: unsafe extern "C" fn(filter: ALuint, param: ALenum, iValue: ALint) -> (),
+		alFilteriv: unsafe extern "C" fn(filter: ALuint, param: ALenum, piValues: *mut ALint) -> (),
+		alFilterf: unsafe extern "C" fn(filter: ALuint, param: ALenum, flValue: ALfloat) -> (),
+		alFilterfv: unsafe extern "C" fn(filter: ALuint, param: ALenum, pflValues: *mut ALfloat) -> (),
+		alGetFilteri: unsafe extern "C" fn(filter: ALuint, param: ALenum, piValue: *mut ALint) -> (),
+		alGetFilteriv: unsafe extern "C" fn(filter: ALuint, param: ALenum, piValues: *mut ALint) -> (),
+		alGetFilterf: unsafe extern "C" fn(filter: ALuint, param: ALenum, pflValue: *mut ALfloat) -> (),
+		alGetFilterfv: unsafe extern "C" fn(filter: ALuint, param: ALenum, pflValues: *mut ALfloat) -> (),
+
+		alBufferSubDataSOFT: unsafe extern "C" fn(buffer: ALuint, format: ALenum, data: *const ALvoid, offset: ALsizei, length: ALsizei) -> (),
+		alBufferCallbackSOFT: unsafe extern "C" fn(buffer: ALuint, format: ALenum, freq: ALsizei, callback: Option<unsafe extern "C" fn(userptr: *mut ALvoid, sampledata: *mut ALvoid, numbytes: ALsizei) -> ALsizei>, userptr: *mut ALvoid) -> (),
+		alGetBufferPtrSOFT: unsafe extern "C" fn(buffer: ALuint, param: ALenum, ptr: *mut *mut ALvoid) -> (),
+		alGetBuffer3PtrSOFT: unsafe extern "C" fn(buffer: ALuint, param: ALenum, ptr0: *mut *mut ALvoid, ptr1: *mut *mut ALvoid, ptr2: *mut *mut ALvoid) -> (),
+		alGetBufferPtrvSOFT: unsafe extern "C" fn(buffer: ALuint, param: ALenum, ptr: *mut *mut ALvoid) -> (),
+		alDeferUpdatesSOFT: unsafe extern "C" fn() -> (),
+		alProcessUpdatesSOFT: unsafe extern "C" fn() -> (),
+		alEventControlSOFT: unsafe extern "C" fn(count: ALsizei, types: *const ALenum, enable: ALboolean) -> (),
+		alEventCallbackSOFT: unsafe extern "C" fn(callback: Option<unsafe extern "C" fn(event_type: ALenum, object: ALuint, param: ALuint, length: ALsizei, message: *const ALchar, user_param: *mut ALvoid)>, userParam: *mut ALvoid) -> (),
+		alGetPointerSOFT: unsafe extern "C" fn(pname: ALenum) -> *mut ALvoid,
+		alGetPointervSOFT: unsafe extern "C" fn(pname: ALenum, values: *mut *mut ALvoid) -> (),
+		alSourcedSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value: ALdouble) -> (),
+		alSource3dSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value1: ALdouble, value2: ALdouble, value3: ALdouble) -> (),
+		alSourcedvSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, values: *const ALdouble) -> (),
+		alGetSourcedSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value: *mut ALdouble) -> (),
+		alGetSource3dSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value1: *mut ALdouble, value2: *mut ALdouble, value3: *mut ALdouble) -> (),
+		alGetSourcedvSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, values: *mut ALdouble) -> (),
+		alSourcei64SOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value: i64) -> (),
+		alSource3i64SOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value1: i64, value2: i64, value3: i64) -> (),
+		alSourcei64vSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, values: *const i64) -> (),
+		alGetSourcei64SOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value: *mut i64) -> (),
+		alGetSource3i64SOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, value1: *mut i64, value2: *mut i64, value3: *mut i64) -> (),
+		alGetSourcei64vSOFT: unsafe extern "C" fn(source: ALuint, param: ALenum, values: *mut i64) -> (),
+		alSourcePlayAtTimeSOFT: unsafe extern "C" fn(source: ALuint, start_time: i64) -> (),
+		alSourcePlayAtTimevSOFT: unsafe extern "C" fn(n: ALsizei, sources: *const ALuint, start_time: i64) -> (),
+		alGetStringiSOFT: unsafe extern "C" fn(paramName: ALenum, index: ALsizei) -> *const ALchar,
+	}
+
 	alcCreateContext: unsafe extern "C" fn(device: *mut ALCdevice, attrlist: *const ALCint) -> *mut ALCcontext,
 	alcMakeContextCurrent: unsafe extern "C" fn(context: *mut ALCcontext) -> ALCboolean,
 	alcProcessContext: unsafe extern "C" fn(context: *mut ALCcontext) -> (),
@@ -0,0 +1,3533 @@
+//! An in-process implementation of the core OpenAL 1.1 entry points, for use where no native library is available.
+//!
+//! All state is process global, so every `AlApi` created by `AlApi::software()` sees the same devices.
+//! Output devices advance in real time and discard what they render, while loopback devices
+//! (`ALC_SOFT_loopback`) render a plain mix of every playing source, without panning or distance
+//! attenuation, which makes them suitable for deterministic testing. Capture devices record silence.
+//!
+//! EFX objects (`ALC_EXT_EFX`) are stored and can be attached, but have no effect on the mix.
+//! Output devices stay connected until `AlApi::software_disconnect` is called.
+//!
+//! This module is only built with the `software` feature. It relies on `const` initialization of
+//! `Mutex` statics and thread locals, and so needs Rust 1.63 or later, unlike the rest of the crate.
+
+#![allow(non_snake_case)]
+
+use std::cell::Cell;
+use std::cmp;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use super::*;
+
+
+const ALC_BYTE_SOFT: ALCenum = 0x1400;
+const ALC_UNSIGNED_BYTE_SOFT: ALCenum = 0x1401;
+const ALC_SHORT_SOFT: ALCenum = 0x1402;
+const ALC_UNSIGNED_SHORT_SOFT: ALCenum = 0x1403;
+const ALC_INT_SOFT: ALCenum = 0x1404;
+const ALC_UNSIGNED_INT_SOFT: ALCenum = 0x1405;
+const ALC_FLOAT_SOFT: ALCenum = 0x1406;
+const ALC_MONO_SOFT: ALCenum = 0x1500;
+const ALC_STEREO_SOFT: ALCenum = 0x1501;
+const ALC_QUAD_SOFT: ALCenum = 0x1503;
+const ALC_5POINT1_SOFT: ALCenum = 0x1504;
+const ALC_6POINT1_SOFT: ALCenum = 0x1505;
+const ALC_7POINT1_SOFT: ALCenum = 0x1506;
+const ALC_FORMAT_CHANNELS_SOFT: ALCenum = 0x1990;
+const ALC_FORMAT_TYPE_SOFT: ALCenum = 0x1991;
+const ALC_OUTPUT_MODE_SOFT: ALCenum = 0x19AC;
+const ALC_ANY_SOFT: ALCenum = 0x19AD;
+const ALC_STEREO_BASIC_SOFT: ALCenum = 0x19AE;
+const ALC_STEREO_UHJ_SOFT: ALCenum = 0x19AF;
+const ALC_STEREO_HRTF_SOFT: ALCenum = 0x19B2;
+const ALC_DEVICE_CLOCK_SOFT: ALCenum = 0x1600;
+const ALC_DEVICE_LATENCY_SOFT: ALCenum = 0x1601;
+const ALC_DEVICE_CLOCK_LATENCY_SOFT: ALCenum = 0x1602;
+
+const AL_FORMAT_MONO_FLOAT32: ALenum = 0x10010;
+const AL_FORMAT_STEREO_FLOAT32: ALenum = 0x10011;
+const AL_BYTE_RW_OFFSETS_SOFT: ALenum = 0x1031;
+const AL_LOOP_POINTS_SOFT: ALenum = 0x2015;
+const AL_SAMPLE_RW_OFFSETS_SOFT: ALenum = 0x1032;
+const AL_BUFFER_CALLBACK_FUNCTION_SOFT: ALenum = 0x19A0;
+const AL_BUFFER_CALLBACK_USER_PARAM_SOFT: ALenum = 0x19A1;
+const AL_EVENT_CALLBACK_FUNCTION_SOFT: ALenum = 0x19A2;
+const AL_EVENT_CALLBACK_USER_PARAM_SOFT: ALenum = 0x19A3;
+const AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT: ALenum = 0x19A4;
+const AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT: ALenum = 0x19A5;
+const AL_EVENT_TYPE_DISCONNECTED_SOFT: ALenum = 0x19A6;
+const ALC_CONNECTED: ALCenum = 0x313;
+
+// EFX enums that the bindings declare as unsigned.
+const ALC_EFX_MAJOR_VERSION: ALCenum = 0x20001;
+const ALC_EFX_MINOR_VERSION: ALCenum = 0x20002;
+const ALC_MAX_AUXILIARY_SENDS: ALCenum = 0x20003;
+const AL_METERS_PER_UNIT: ALenum = 0x20004;
+const AL_DIRECT_FILTER: ALenum = 0x20005;
+const AL_AUXILIARY_SEND_FILTER: ALenum = 0x20006;
+const AL_AIR_ABSORPTION_FACTOR: ALenum = 0x20007;
+const AL_ROOM_ROLLOFF_FACTOR: ALenum = 0x20008;
+const AL_CONE_OUTER_GAINHF: ALenum = 0x20009;
+const AL_DIRECT_FILTER_GAINHF_AUTO: ALenum = 0x2000A;
+const AL_AUXILIARY_SEND_FILTER_GAIN_AUTO: ALenum = 0x2000B;
+const AL_AUXILIARY_SEND_FILTER_GAINHF_AUTO: ALenum = 0x2000C;
+
+const ALC_ENUMS: &'static [(&'static [u8], ALCenum)] = &[
+	(b"ALC_BYTE_SOFT", ALC_BYTE_SOFT),
+	(b"ALC_UNSIGNED_BYTE_SOFT", ALC_UNSIGNED_BYTE_SOFT),
+	(b"ALC_SHORT_SOFT", ALC_SHORT_SOFT),
+	(b"ALC_UNSIGNED_SHORT_SOFT", ALC_UNSIGNED_SHORT_SOFT),
+	(b"ALC_INT_SOFT", ALC_INT_SOFT),
+	(b"ALC_UNSIGNED_INT_SOFT", ALC_UNSIGNED_INT_SOFT),
+	(b"ALC_FLOAT_SOFT", ALC_FLOAT_SOFT),
+	(b"ALC_MONO_SOFT", ALC_MONO_SOFT),
+	(b"ALC_STEREO_SOFT", ALC_STEREO_SOFT),
+	(b"ALC_QUAD_SOFT", ALC_QUAD_SOFT),
+	(b"ALC_5POINT1_SOFT", ALC_5POINT1_SOFT),
+	(b"ALC_6POINT1_SOFT", ALC_6POINT1_SOFT),
+	(b"ALC_7POINT1_SOFT", ALC_7POINT1_SOFT),
+	(b"ALC_FORMAT_CHANNELS_SOFT", ALC_FORMAT_CHANNELS_SOFT),
+	(b"ALC_FORMAT_TYPE_SOFT", ALC_FORMAT_TYPE_SOFT),
+	(b"ALC_OUTPUT_MODE_SOFT", ALC_OUTPUT_MODE_SOFT),
+	(b"ALC_ANY_SOFT", ALC_ANY_SOFT),
+	(b"ALC_STEREO_BASIC_SOFT", ALC_STEREO_BASIC_SOFT),
+	(b"ALC_STEREO_UHJ_SOFT", ALC_STEREO_UHJ_SOFT),
+	(b"ALC_STEREO_HRTF_SOFT", ALC_STEREO_HRTF_SOFT),
+	(b"ALC_SURROUND_5_1_SOFT", ALC_5POINT1_SOFT),
+	(b"ALC_SURROUND_6_1_SOFT", ALC_6POINT1_SOFT),
+	(b"ALC_SURROUND_7_1_SOFT", ALC_7POINT1_SOFT),
+	(b"ALC_DEVICE_CLOCK_SOFT", ALC_DEVICE_CLOCK_SOFT),
+	(b"ALC_DEVICE_LATENCY_SOFT", ALC_DEVICE_LATENCY_SOFT),
+	(b"ALC_DEVICE_CLOCK_LATENCY_SOFT", ALC_DEVICE_CLOCK_LATENCY_SOFT),
+	(b"ALC_CONNECTED", ALC_CONNECTED),
+];
+const AL_ENUMS: &'static [(&'static [u8], ALenum)] = &[
+	(b"AL_FORMAT_MONO_FLOAT32", AL_FORMAT_MONO_FLOAT32),
+	(b"AL_FORMAT_STEREO_FLOAT32", AL_FORMAT_STEREO_FLOAT32),
+	(b"AL_BYTE_RW_OFFSETS_SOFT", AL_BYTE_RW_OFFSETS_SOFT),
+	(b"AL_SAMPLE_RW_OFFSETS_SOFT", AL_SAMPLE_RW_OFFSETS_SOFT),
+	(b"AL_LOOP_POINTS_SOFT", AL_LOOP_POINTS_SOFT),
+	(b"AL_BUFFER_CALLBACK_FUNCTION_SOFT", AL_BUFFER_CALLBACK_FUNCTION_SOFT),
+	(b"AL_BUFFER_CALLBACK_USER_PARAM_SOFT", AL_BUFFER_CALLBACK_USER_PARAM_SOFT),
+	(b"AL_EVENT_CALLBACK_FUNCTION_SOFT", AL_EVENT_CALLBACK_FUNCTION_SOFT),
+	(b"AL_EVENT_CALLBACK_USER_PARAM_SOFT", AL_EVENT_CALLBACK_USER_PARAM_SOFT),
+	(b"AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT", AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT),
+	(b"AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT", AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT),
+	(b"AL_EVENT_TYPE_DISCONNECTED_SOFT", AL_EVENT_TYPE_DISCONNECTED_SOFT),
+];
+
+macro_rules! enum_table {
+	($($name:ident,)*) => { &[$((stringify!($name).as_bytes(), $name as ALenum),)*] };
+}
+
+/// Names of `ALC_EXT_EFX` enums, which can be looked up through both `alcGetEnumValue` and `alGetEnumValue`.
+const EFX_ENUMS: &'static [(&'static [u8], ALenum)] = enum_table![
+	AL_EFFECTSLOT_EFFECT,
+	AL_EFFECTSLOT_GAIN,
+	AL_EFFECTSLOT_AUXILIARY_SEND_AUTO,
+	AL_EFFECTSLOT_NULL,
+	AL_EFFECT_TYPE,
+	AL_EFFECT_NULL,
+	AL_EFFECT_EAXREVERB,
+	AL_EAXREVERB_DENSITY,
+	AL_EAXREVERB_DIFFUSION,
+	AL_EAXREVERB_GAIN,
+	AL_EAXREVERB_GAINHF,
+	AL_EAXREVERB_GAINLF,
+	AL_EAXREVERB_DECAY_TIME,
+	AL_EAXREVERB_DECAY_HFRATIO,
+	AL_EAXREVERB_DECAY_LFRATIO,
+	AL_EAXREVERB_REFLECTIONS_GAIN,
+	AL_EAXREVERB_REFLECTIONS_DELAY,
+	AL_EAXREVERB_REFLECTIONS_PAN,
+	AL_EAXREVERB_LATE_REVERB_GAIN,
+	AL_EAXREVERB_LATE_REVERB_DELAY,
+	AL_EAXREVERB_LATE_REVERB_PAN,
+	AL_EAXREVERB_ECHO_TIME,
+	AL_EAXREVERB_ECHO_DEPTH,
+	AL_EAXREVERB_MODULATION_TIME,
+	AL_EAXREVERB_MODULATION_DEPTH,
+	AL_EAXREVERB_AIR_ABSORPTION_GAINHF,
+	AL_EAXREVERB_HFREFERENCE,
+	AL_EAXREVERB_LFREFERENCE,
+	AL_EAXREVERB_ROOM_ROLLOFF_FACTOR,
+	AL_EAXREVERB_DECAY_HFLIMIT,
+	AL_EFFECT_REVERB,
+	AL_REVERB_DENSITY,
+	AL_REVERB_DIFFUSION,
+	AL_REVERB_GAIN,
+	AL_REVERB_GAINHF,
+	AL_REVERB_DECAY_TIME,
+	AL_REVERB_DECAY_HFRATIO,
+	AL_REVERB_REFLECTIONS_GAIN,
+	AL_REVERB_REFLECTIONS_DELAY,
+	AL_REVERB_LATE_REVERB_GAIN,
+	AL_REVERB_LATE_REVERB_DELAY,
+	AL_REVERB_AIR_ABSORPTION_GAINHF,
+	AL_REVERB_ROOM_ROLLOFF_FACTOR,
+	AL_REVERB_DECAY_HFLIMIT,
+	AL_EFFECT_CHORUS,
+	AL_CHORUS_WAVEFORM,
+	AL_CHORUS_PHASE,
+	AL_CHORUS_RATE,
+	AL_CHORUS_DEPTH,
+	AL_CHORUS_FEEDBACK,
+	AL_CHORUS_DELAY,
+	AL_EFFECT_DISTORTION,
+	AL_DISTORTION_EDGE,
+	AL_DISTORTION_GAIN,
+	AL_DISTORTION_LOWPASS_CUTOFF,
+	AL_DISTORTION_EQCENTER,
+	AL_DISTORTION_EQBANDWIDTH,
+	AL_EFFECT_ECHO,
+	AL_ECHO_DELAY,
+	AL_ECHO_LRDELAY,
+	AL_ECHO_DAMPING,
+	AL_ECHO_FEEDBACK,
+	AL_ECHO_SPREAD,
+	AL_EFFECT_FLANGER,
+	AL_FLANGER_WAVEFORM,
+	AL_FLANGER_PHASE,
+	AL_FLANGER_RATE,
+	AL_FLANGER_DEPTH,
+	AL_FLANGER_FEEDBACK,
+	AL_FLANGER_DELAY,
+	AL_EFFECT_FREQUENCY_SHIFTER,
+	AL_FREQUENCY_SHIFTER_FREQUENCY,
+	AL_FREQUENCY_SHIFTER_LEFT_DIRECTION,
+	AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION,
+	AL_EFFECT_VOCAL_MORPHER,
+	AL_VOCAL_MORPHER_PHONEMEA,
+	AL_VOCAL_MORPHER_PHONEMEB,
+	AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING,
+	AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING,
+	AL_VOCAL_MORPHER_WAVEFORM,
+	AL_VOCAL_MORPHER_RATE,
+	AL_EFFECT_PITCH_SHIFTER,
+	AL_PITCH_SHIFTER_COARSE_TUNE,
+	AL_PITCH_SHIFTER_FINE_TUNE,
+	AL_EFFECT_RING_MODULATOR,
+	AL_RING_MODULATOR_FREQUENCY,
+	AL_RING_MODULATOR_HIGHPASS_CUTOFF,
+	AL_RING_MODULATOR_WAVEFORM,
+	AL_EFFECT_AUTOWAH,
+	AL_AUTOWAH_ATTACK_TIME,
+	AL_AUTOWAH_RELEASE_TIME,
+	AL_AUTOWAH_RESONANCE,
+	AL_AUTOWAH_PEAK_GAIN,
+	AL_EFFECT_COMPRESSOR,
+	AL_COMPRESSOR_ONOFF,
+	AL_EFFECT_EQUALIZER,
+	AL_EQUALIZER_LOW_GAIN,
+	AL_EQUALIZER_LOW_CUTOFF,
+	AL_EQUALIZER_MID1_GAIN,
+	AL_EQUALIZER_MID1_CENTER,
+	AL_EQUALIZER_MID1_WIDTH,
+	AL_EQUALIZER_MID2_GAIN,
+	AL_EQUALIZER_MID2_CENTER,
+	AL_EQUALIZER_MID2_WIDTH,
+	AL_EQUALIZER_HIGH_GAIN,
+	AL_EQUALIZER_HIGH_CUTOFF,
+	AL_FILTER_TYPE,
+	AL_FILTER_NULL,
+	AL_FILTER_LOWPASS,
+	AL_LOWPASS_GAIN,
+	AL_LOWPASS_GAINHF,
+	AL_FILTER_HIGHPASS,
+	AL_HIGHPASS_GAIN,
+	AL_HIGHPASS_GAINLF,
+	AL_FILTER_BANDPASS,
+	AL_BANDPASS_GAIN,
+	AL_BANDPASS_GAINLF,
+	AL_BANDPASS_GAINHF,
+	AL_DIRECT_FILTER,
+	AL_AUXILIARY_SEND_FILTER,
+	AL_AIR_ABSORPTION_FACTOR,
+	AL_ROOM_ROLLOFF_FACTOR,
+	AL_CONE_OUTER_GAINHF,
+	AL_DIRECT_FILTER_GAINHF_AUTO,
+	AL_AUXILIARY_SEND_FILTER_GAIN_AUTO,
+	AL_AUXILIARY_SEND_FILTER_GAINHF_AUTO,
+	AL_METERS_PER_UNIT,
+	ALC_EFX_MAJOR_VERSION,
+	ALC_EFX_MINOR_VERSION,
+	ALC_MAX_AUXILIARY_SENDS,
+];
+
+const EFFECT_TYPES: &'static [ALint] = &[
+	AL_EFFECT_NULL, AL_EFFECT_EAXREVERB, AL_EFFECT_REVERB, AL_EFFECT_CHORUS, AL_EFFECT_DISTORTION, AL_EFFECT_ECHO,
+	AL_EFFECT_FLANGER, AL_EFFECT_FREQUENCY_SHIFTER, AL_EFFECT_VOCAL_MORPHER, AL_EFFECT_PITCH_SHIFTER,
+	AL_EFFECT_RING_MODULATOR, AL_EFFECT_AUTOWAH, AL_EFFECT_COMPRESSOR, AL_EFFECT_EQUALIZER,
+];
+const FILTER_TYPES: &'static [ALint] = &[AL_FILTER_NULL, AL_FILTER_LOWPASS, AL_FILTER_HIGHPASS, AL_FILTER_BANDPASS];
+
+const ALC_EXTENSIONS_STR: &'static [u8] = b"ALC_ENUMERATION_EXT ALC_EXT_CAPTURE ALC_EXT_disconnect ALC_EXT_EFX ALC_EXT_thread_local_context ALC_SOFT_device_clock ALC_SOFT_loopback ALC_SOFT_output_mode ALC_SOFT_reopen_device\0";
+const AL_EXTENSIONS_STR: &'static [u8] = b"AL_EXT_float32 AL_SOFT_buffer_sub_data AL_SOFT_callback_buffer AL_SOFT_events AL_SOFT_loop_points AL_SOFT_source_start_delay\0";
+
+const OUTPUT_NAME: &'static [u8] = b"Alto Software\0";
+const OUTPUT_LIST: &'static [u8] = b"Alto Software\0\0";
+const CAPTURE_LIST: &'static [u8] = b"Alto Software Capture\0\0";
+
+const DEFAULT_FREQUENCY: ALCint = 44100;
+const MAX_AUXILIARY_SENDS: usize = 2;
+
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+/// Held while a device is brought up to date, so callbacks are never called from two threads at once.
+static RENDER: Mutex<()> = Mutex::new(());
+
+thread_local! {
+	/// Context made current on this thread with `alcSetThreadContext`, which takes precedence over `State::current`.
+	static THREAD_CONTEXT: Cell<Option<usize>> = const { Cell::new(None) };
+	/// Whether this thread is running a buffer or event callback.
+	static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+
+struct State {
+	next_handle: usize,
+	devices: HashMap<usize, Device>,
+	contexts: HashMap<usize, Context>,
+	current: Option<usize>,
+	error: ALCenum,
+	/// Null separated lists of device names, with the default first. Replaced lists are leaked,
+	/// since the strings returned by `alcGetString` must stay valid.
+	outputs: &'static [u8],
+	captures: &'static [u8],
+}
+
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum DeviceKind {
+	Output,
+	Loopback,
+	Capture,
+}
+
+
+struct Device {
+	kind: DeviceKind,
+	spec: &'static [u8],
+	error: ALCenum,
+	frequency: ALCint,
+	channels: ALCenum,
+	sample_type: ALCenum,
+	refresh: ALCint,
+	mono_sources: ALCint,
+	stereo_sources: ALCint,
+	buffers: HashMap<ALuint, Buffer>,
+	next_buffer: ALuint,
+	clock: Instant,
+	rendered: u64,
+	/// Nanoseconds of audio mixed since the device was opened.
+	time: u64,
+	connected: bool,
+	effects: HashMap<ALuint, EfxObject>,
+	filters: HashMap<ALuint, EfxObject>,
+	next_efx_object: ALuint,
+	/// Extensions reported for the device and for its contexts.
+	alc_extensions: &'static [u8],
+	al_extensions: &'static [u8],
+	capture: Option<CaptureState>,
+}
+
+
+struct CaptureState {
+	frame_size: usize,
+	silence: u8,
+	capacity: usize,
+	available: usize,
+	running: bool,
+	clock: Instant,
+	counted: u64,
+}
+
+
+struct Buffer {
+	channels: usize,
+	bits: ALint,
+	frequency: ALint,
+	size: ALint,
+	samples: Vec<f32>,
+	/// Start and end frame of the section repeated by a looping source, if set with `AL_LOOP_POINTS_SOFT`.
+	loop_points: Option<(usize, usize)>,
+	callback: Option<BufferCallback>,
+}
+
+
+type BufferCallbackProc = unsafe extern "C" fn(*mut c_void, *mut c_void, ALsizei) -> ALsizei;
+
+
+/// Source of samples for a callback buffer, which are appended to the buffer as the mixer needs them.
+struct BufferCallback {
+	callback: BufferCallbackProc,
+	/// User parameter of the callback, stored as an integer so the state can be shared between threads.
+	param: usize,
+	ended: bool,
+}
+
+
+/// Request for more samples from the callback of a callback buffer, which is made without the state lock held.
+struct Pull {
+	dev: usize,
+	buffer: ALuint,
+	callback: BufferCallbackProc,
+	param: usize,
+	bytes: usize,
+}
+
+
+/// An effect or filter. Properties are stored as given, without checking that the type has them or that they are in range.
+#[derive(Clone)]
+struct EfxObject {
+	ty: ALint,
+	props: HashMap<ALenum, Vec<f64>>,
+}
+
+
+struct AuxEffectSlot {
+	gain: f32,
+	send_auto: bool,
+	effect_id: ALuint,
+	/// Copy of the effect loaded into the slot, which is unaffected by later changes to the effect itself.
+	effect: Option<EfxObject>,
+}
+
+
+type EventProc = unsafe extern "C" fn(ALenum, ALuint, ALuint, ALsizei, *const ALchar, *mut c_void);
+
+
+/// Event for an event callback, which is delivered once the state lock is released.
+struct Event {
+	callback: EventProc,
+	param: usize,
+	ty: ALenum,
+	object: ALuint,
+	value: ALuint,
+	message: &'static [u8],
+}
+
+
+struct Context {
+	device: usize,
+	error: ALenum,
+	event_callback: Option<EventProc>,
+	/// User parameter of the event callback, stored as an integer so the state can be shared between threads.
+	event_param: usize,
+	events_enabled: Vec<ALenum>,
+	listener: Listener,
+	sources: HashMap<ALuint, Source>,
+	next_source: ALuint,
+	slots: HashMap<ALuint, AuxEffectSlot>,
+	next_slot: ALuint,
+	doppler_factor: f32,
+	doppler_velocity: f32,
+	speed_of_sound: f32,
+	distance_model: ALenum,
+}
+
+
+struct Listener {
+	gain: f32,
+	position: [f32; 3],
+	velocity: [f32; 3],
+	orientation: [f32; 6],
+	meters_per_unit: f32,
+}
+
+
+struct Source {
+	pitch: f32,
+	gain: f32,
+	min_gain: f32,
+	max_gain: f32,
+	reference_distance: f32,
+	rolloff_factor: f32,
+	max_distance: f32,
+	cone_outer_gain: f32,
+	cone_inner_angle: f32,
+	cone_outer_angle: f32,
+	position: [f32; 3],
+	velocity: [f32; 3],
+	direction: [f32; 3],
+	relative: bool,
+	looping: bool,
+	kind: ALint,
+	state: ALint,
+	queue: Vec<ALuint>,
+	current: usize,
+	offset: f64,
+	pending_offset: Option<(ALenum, f64)>,
+	/// Device clock time in nanoseconds at which a scheduled play begins.
+	start_time: Option<u64>,
+	air_absorption_factor: f32,
+	room_rolloff_factor: f32,
+	cone_outer_gainhf: f32,
+	direct_filter_gainhf_auto: bool,
+	send_filter_gain_auto: bool,
+	send_filter_gainhf_auto: bool,
+	direct_filter: Option<EfxObject>,
+	/// Slot fed by each aux send, along with a copy of the send's filter.
+	sends: Vec<Option<(ALuint, Option<EfxObject>)>>,
+}
+
+
+impl AlApi {
+	/// Entry points backed by alto's software implementation rather than a native library.
+	pub fn software() -> AlApi {
+		AlApi::from_table(AlFnTable{
+			alcCreateContext: alcCreateContext,
+			alcMakeContextCurrent: alcMakeContextCurrent,
+			alcProcessContext: alcProcessContext,
+			alcSuspendContext: alcSuspendContext,
+			alcDestroyContext: alcDestroyContext,
+			alcGetCurrentContext: alcGetCurrentContext,
+			alcGetContextsDevice: alcGetContextsDevice,
+			alcOpenDevice: alcOpenDevice,
+			alcCloseDevice: alcCloseDevice,
+			alcGetError: alcGetError,
+			alcIsExtensionPresent: alcIsExtensionPresent,
+			alcGetProcAddress: alcGetProcAddress,
+			alcGetEnumValue: alcGetEnumValue,
+			alcGetString: alcGetString,
+			alcGetIntegerv: alcGetIntegerv,
+			alcCaptureOpenDevice: alcCaptureOpenDevice,
+			alcCaptureCloseDevice: alcCaptureCloseDevice,
+			alcCaptureStart: alcCaptureStart,
+			alcCaptureStop: alcCaptureStop,
+			alcCaptureSamples: alcCaptureSamples,
+
+			alDopplerFactor: alDopplerFactor,
+			alDopplerVelocity: alDopplerVelocity,
+			alSpeedOfSound: alSpeedOfSound,
+			alDistanceModel: alDistanceModel,
+			alEnable: alEnable,
+			alDisable: alDisable,
+			alIsEnabled: alIsEnabled,
+			alGetString: alGetString,
+			alGetBooleanv: alGetBooleanv,
+			alGetIntegerv: alGetIntegerv,
+			alGetFloatv: alGetFloatv,
+			alGetDoublev: alGetDoublev,
+			alGetBoolean: alGetBoolean,
+			alGetInteger: alGetInteger,
+			alGetFloat: alGetFloat,
+			alGetDouble: alGetDouble,
+			alGetError: alGetError,
+			alIsExtensionPresent: alIsExtensionPresent,
+			alGetProcAddress: alGetProcAddress,
+			alGetEnumValue: alGetEnumValue,
+			alListenerf: alListenerf,
+			alListener3f: alListener3f,
+			alListenerfv: alListenerfv,
+			alListeneri: alListeneri,
+			alListener3i: alListener3i,
+			alListeneriv: alListeneriv,
+			alGetListenerf: alGetListenerf,
+			alGetListener3f: alGetListener3f,
+			alGetListenerfv: alGetListenerfv,
+			alGetListeneri: alGetListeneri,
+			alGetListener3i: alGetListener3i,
+			alGetListeneriv: alGetListeneriv,
+			alGenSources: alGenSources,
+			alDeleteSources: alDeleteSources,
+			alIsSource: alIsSource,
+			alSourcef: alSourcef,
+			alSource3f: alSource3f,
+			alSourcefv: alSourcefv,
+			alSourcei: alSourcei,
+			alSource3i: alSource3i,
+			alSourceiv: alSourceiv,
+			alGetSourcef: alGetSourcef,
+			alGetSource3f: alGetSource3f,
+			alGetSourcefv: alGetSourcefv,
+			alGetSourcei: alGetSourcei,
+			alGetSource3i: alGetSource3i,
+			alGetSourceiv: alGetSourceiv,
+			alSourcePlayv: alSourcePlayv,
+			alSourceStopv: alSourceStopv,
+			alSourceRewindv: alSourceRewindv,
+			alSourcePausev: alSourcePausev,
+			alSourcePlay: alSourcePlay,
+			alSourceStop: alSourceStop,
+			alSourceRewind: alSourceRewind,
+			alSourcePause: alSourcePause,
+			alSourceQueueBuffers: alSourceQueueBuffers,
+			alSourceUnqueueBuffers: alSourceUnqueueBuffers,
+			alGenBuffers: alGenBuffers,
+			alDeleteBuffers: alDeleteBuffers,
+			alIsBuffer: alIsBuffer,
+			alBufferData: alBufferData,
+			alBufferf: alBufferf,
+			alBuffer3f: alBuffer3f,
+			alBufferfv: alBufferfv,
+			alBufferi: alBufferi,
+			alBuffer3i: alBuffer3i,
+			alBufferiv: alBufferiv,
+			alGetBufferf: alGetBufferf,
+			alGetBuffer3f: alGetBuffer3f,
+			alGetBufferfv: alGetBufferfv,
+			alGetBufferi: alGetBufferi,
+			alGetBuffer3i: alGetBuffer3i,
+			alGetBufferiv: alGetBufferiv,
+		})
+	}
+
+
+	/// Disconnect an output device of the software implementation, as if it had been unplugged.
+	/// Its sources stop, `alcGetIntegerv(ALC_CONNECTED)` reports `ALC_FALSE`, and contexts with
+	/// `AL_EVENT_TYPE_DISCONNECTED_SOFT` enabled are notified. `alcReopenDeviceSOFT` connects it again.
+	/// Returns `false` if `device` is not an open output device.
+	pub fn software_disconnect(device: *mut ALCdevice) -> bool {
+		let id = device as usize;
+		sync(id);
+		let events = with_state(|state| {
+			match state.devices.get_mut(&id) {
+				Some(dev) if dev.kind == DeviceKind::Output => dev.connected = false,
+				_ => return None,
+			}
+
+			let mut events = Vec::new();
+			for ctx in state.contexts.values_mut().filter(|c| c.device == id) {
+				for src in ctx.sources.values_mut().filter(|s| s.state == AL_PLAYING || s.state == AL_PAUSED) {
+					src.stop();
+				}
+				ctx.queue_events(&[(AL_EVENT_TYPE_DISCONNECTED_SOFT, 0, 0, &b"Device disconnected"[..])], &mut events);
+			}
+			Some(events)
+		});
+
+		match events {
+			Some(events) => {
+				deliver(events);
+				true
+			},
+			None => false,
+		}
+	}
+}
+
+
+impl AlApi {
+	/// Replace the output and capture devices listed by the software implementation, as if devices had been plugged in
+	/// or removed. The first name of each list is the default device, and names must not contain nul characters.
+	/// Devices that are already open are unaffected.
+	pub fn software_set_devices(outputs: &[&str], captures: &[&str]) {
+		fn list(names: &[&str]) -> &'static [u8] {
+			let mut list = Vec::new();
+			for name in names {
+				list.extend_from_slice(name.as_bytes());
+				list.push(0);
+			}
+			// An empty list still ends with two nuls.
+			list.resize(cmp::max(list.len() + 1, 2), 0);
+			Box::leak(list.into_boxed_slice())
+		}
+
+		let (outputs, captures) = (list(outputs), list(captures));
+		with_state(|state| {
+			state.outputs = outputs;
+			state.captures = captures;
+		});
+	}
+
+
+	/// Stop reporting the named ALC and AL extensions for a device of the software implementation and its contexts,
+	/// as an implementation without them would. Their entry points and enums remain available.
+	/// Returns `false` if `device` is not an open device.
+	pub fn software_hide_extensions(device: *mut ALCdevice, names: &[&str]) -> bool {
+		// Replaced lists are leaked, like device lists.
+		fn without(list: &'static [u8], names: &[&str]) -> &'static [u8] {
+			let mut kept = list[.. list.len() - 1].split(|&c| c == b' ')
+				.filter(|e| !names.iter().any(|n| n.as_bytes().eq_ignore_ascii_case(e)))
+				.collect::<Vec<_>>()
+				.join(&b' ');
+			kept.push(0);
+			Box::leak(kept.into_boxed_slice())
+		}
+
+		with_state(|state| match state.devices.get_mut(&(device as usize)) {
+			Some(dev) => {
+				dev.alc_extensions = without(dev.alc_extensions, names);
+				dev.al_extensions = without(dev.al_extensions, names);
+				true
+			},
+			None => false,
+		})
+	}
+}
+
+
+/// EFX has no way to read back what was attached to a slot or source, so these let tests check it.
+/// They are not part of the stable API.
+impl AlApi {
+	/// Property `param` of the effect loaded into an aux effect slot, with `AL_EFFECT_TYPE` giving its type.
+	#[doc(hidden)]
+	pub fn software_slot_effect(context: *mut ALCcontext, slot: ALuint, param: ALenum) -> Option<ALfloat> {
+		with_state(|state| {
+			let effect = state.contexts.get(&(context as usize))?.slots.get(&slot)?.effect.as_ref()?;
+			effect.get(param).ok().map(|v| v[0] as ALfloat)
+		})
+	}
+
+
+	/// Property `param` of the direct filter of a source, with `AL_FILTER_TYPE` giving its type.
+	#[doc(hidden)]
+	pub fn software_direct_filter(context: *mut ALCcontext, source: ALuint, param: ALenum) -> Option<ALfloat> {
+		with_state(|state| {
+			let filter = state.contexts.get(&(context as usize))?.sources.get(&source)?.direct_filter.as_ref()?;
+			filter.get(param).ok().map(|v| v[0] as ALfloat)
+		})
+	}
+
+
+	/// Aux effect slot fed by a send of a source, if any.
+	#[doc(hidden)]
+	pub fn software_aux_send(context: *mut ALCcontext, source: ALuint, send: ALint) -> Option<ALuint> {
+		with_state(|state| {
+			let src = state.contexts.get(&(context as usize))?.sources.get(&source)?;
+			src.sends.get(send as usize)?.as_ref().map(|&(slot, _)| slot)
+		})
+	}
+}
+
+
+fn with_state<R, F: FnOnce(&mut State) -> R>(f: F) -> R {
+	let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+	if state.is_none() {
+		*state = Some(State::new());
+	}
+	f(state.as_mut().unwrap())
+}
+
+
+/// Run `f` against a device of one of the given kinds, recording any error on the device.
+fn with_device<R, F>(dev: *mut ALCdevice, kinds: &[DeviceKind], default: R, f: F) -> R where
+	F: FnOnce(&mut State, usize) -> Result<R, ALCenum>,
+{
+	sync(dev as usize);
+	with_state(|state| {
+		let id = dev as usize;
+		match state.devices.get_mut(&id) {
+			Some(ref mut dev) if kinds.contains(&dev.kind) => (),
+			Some(dev) => {
+				dev.set_error(ALC_INVALID_DEVICE);
+				return default;
+			},
+			None => {
+				state.set_error(ALC_INVALID_DEVICE);
+				return default;
+			},
+		}
+
+		match f(state, id) {
+			Ok(r) => r,
+			Err(e) => {
+				if let Some(dev) = state.devices.get_mut(&id) {
+					dev.set_error(e);
+				}
+				default
+			},
+		}
+	})
+}
+
+
+/// Run `f` against the current context, recording any error on the context.
+fn with_current<R, F>(default: R, f: F) -> R where
+	F: FnOnce(&mut State, usize, usize) -> Result<R, ALenum>,
+{
+	if let Some(dev) = with_state(|state| state.current_context().map(|c| state.contexts[&c].device)) {
+		sync(dev);
+	}
+	with_state(|state| {
+		let (ctx, dev) = match state.current_context().and_then(|c| state.contexts.get(&c).map(|ctx| (c, ctx.device))) {
+			Some(cd) => cd,
+			None => return default,
+		};
+
+		match f(state, ctx, dev) {
+			Ok(r) => r,
+			Err(e) => {
+				let ctx = state.contexts.get_mut(&ctx).unwrap();
+				if ctx.error == AL_NO_ERROR {
+					ctx.error = e;
+				}
+				default
+			},
+		}
+	})
+}
+
+
+fn with_context<R, F>(default: R, f: F) -> R where
+	F: FnOnce(&mut Context, &mut Device) -> Result<R, ALenum>,
+{
+	with_current(default, |state, ctx, dev| f(state.contexts.get_mut(&ctx).unwrap(), state.devices.get_mut(&dev).unwrap()))
+}
+
+
+fn set_al_error(e: ALenum) {
+	with_context((), |_, _| Err(e))
+}
+
+
+impl State {
+	fn new() -> State {
+		State{
+			next_handle: 0,
+			devices: HashMap::new(),
+			contexts: HashMap::new(),
+			current: None,
+			error: ALC_NO_ERROR,
+			outputs: OUTPUT_LIST,
+			captures: CAPTURE_LIST,
+		}
+	}
+
+
+	fn set_error(&mut self, e: ALCenum) {
+		if self.error == ALC_NO_ERROR {
+			self.error = e;
+		}
+	}
+
+
+	/// The context current on this thread, which is its thread context if it has one that still exists.
+	fn current_context(&self) -> Option<usize> {
+		THREAD_CONTEXT.with(|c| c.get())
+			.filter(|c| self.contexts.contains_key(c))
+			.or(self.current)
+	}
+
+
+	fn add_device(&mut self, dev: Device) -> *mut ALCdevice {
+		self.next_handle += 1;
+		self.devices.insert(self.next_handle, dev);
+		self.next_handle as *mut ALCdevice
+	}
+
+
+	/// Callback buffers that need more samples before `frames` frames of a device can be mixed.
+	fn pulls(&self, dev: usize, frames: usize) -> Vec<Pull> {
+		let device = &self.devices[&dev];
+		let frequency = device.frequency as f64;
+		let mut pulls: Vec<Pull> = Vec::new();
+		for ctx in self.contexts.values().filter(|c| c.device == dev) {
+			for src in ctx.sources.values().filter(|s| s.state == AL_PLAYING) {
+				// Follow the queue from the current buffer until a callback buffer has to supply the remaining frames.
+				let mut remaining = frames as f64;
+				let mut offset = src.offset;
+				for i in 0 .. src.queue.len() {
+					let pos = match src.current + i {
+						pos if pos < src.queue.len() => pos,
+						pos if src.looping => pos % src.queue.len(),
+						_ => break,
+					};
+					let id = src.queue[pos];
+					let buf = &device.buffers[&id];
+					let step = src.pitch as f64 * buf.frequency as f64 / frequency;
+
+					match buf.callback {
+						Some(ref cb) if !cb.ended => {
+							let needed = (offset + remaining * step).ceil() as usize + 1;
+							if needed > buf.frames() && !pulls.iter().any(|p| p.buffer == id) {
+								pulls.push(Pull{dev: dev, buffer: id, callback: cb.callback, param: cb.param, bytes: (needed - buf.frames()) * buf.frame_size()});
+							}
+							break;
+						},
+						_ if step > 0.0 => {
+							remaining -= (buf.frames() as f64 - offset).max(0.0) / step;
+							if remaining <= 0.0 {
+								break;
+							}
+						},
+						_ => (),
+					}
+					offset = 0.0;
+				}
+			}
+		}
+		pulls
+	}
+
+
+	/// Append the samples returned by a buffer callback, unless the buffer has been deleted or given a new callback since.
+	fn fill(&mut self, pull: &Pull, data: &[u8]) {
+		let buf = match self.devices.get_mut(&pull.dev).and_then(|d| d.buffers.get_mut(&pull.buffer)) {
+			Some(buf) => buf,
+			None => return,
+		};
+		let frame_size = buf.frame_size();
+		match buf.callback {
+			Some(ref mut cb) if cb.callback as usize == pull.callback as usize && cb.param == pull.param && !cb.ended => {
+				cb.ended = data.len() < pull.bytes;
+			},
+			_ => return,
+		}
+		buf.samples.extend(decode(buf.bits, &data[.. data.len() / frame_size * frame_size]));
+	}
+
+
+	/// Mix every playing source of every context on a device into `out`, or just advance them if `out` is `None`.
+	/// Returns the events to deliver once the state lock is released.
+	fn mix(&mut self, dev: usize, frames: usize, mut out: Option<&mut [f32]>) -> Vec<Event> {
+		let State{ref mut devices, ref mut contexts, ..} = *self;
+		let dev_mut = devices.get_mut(&dev).unwrap();
+		let frequency = dev_mut.frequency as f64;
+		let start = dev_mut.time;
+		dev_mut.time += frames as u64 * 1_000_000_000 / dev_mut.frequency as u64;
+		let channels = channel_count(dev_mut.channels).unwrap_or(2);
+
+		let mut pending = Vec::new();
+		for ctx in contexts.values_mut().filter(|c| c.device == dev) {
+			let listener_gain = ctx.listener.gain;
+			let mut events = Vec::new();
+			for (&id, src) in ctx.sources.iter_mut().filter(|&(_, ref s)| s.state == AL_PLAYING) {
+				// A source started with `alSourcePlayAtTimeSOFT` stays silent until the device clock reaches its start time.
+				let delay = match src.start_time {
+					Some(t) if t > start => ((t - start) as f64 * frequency / 1e9).ceil() as usize,
+					_ => 0,
+				};
+				if delay >= frames {
+					continue;
+				}
+				src.start_time = None;
+
+				let current = src.current;
+				src.mix(&dev_mut.buffers, listener_gain, frequency, channels, out.as_mut().map(|o| &mut o[delay * channels ..]), frames - delay);
+
+				if src.current > current {
+					events.push((AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT, id, (src.current - current) as ALuint, &b"Buffer completed"[..]));
+				}
+				if src.state != AL_PLAYING {
+					events.push((AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT, id, src.state as ALuint, &b"Source state changed"[..]));
+				}
+			}
+			ctx.queue_events(&events, &mut pending);
+		}
+
+		// Callbacks can't be asked for samples again, so once every source has played past some, they are dropped.
+		let mut consumed: HashMap<ALuint, usize> = HashMap::new();
+		for src in contexts.values().filter(|c| c.device == dev).flat_map(|c| c.sources.values()) {
+			if src.state != AL_PLAYING && src.state != AL_PAUSED {
+				continue;
+			}
+			for (pos, &id) in src.queue.iter().enumerate().filter(|&(_, id)| dev_mut.buffers[id].callback.is_some()) {
+				let n = match pos.cmp(&src.current) {
+					cmp::Ordering::Less => usize::max_value(),
+					cmp::Ordering::Equal => src.offset as usize,
+					cmp::Ordering::Greater => 0,
+				};
+				let c = consumed.entry(id).or_insert(n);
+				*c = cmp::min(*c, n);
+			}
+		}
+		for (id, n) in consumed {
+			let buf = dev_mut.buffers.get_mut(&id).unwrap();
+			let n = cmp::min(n, buf.frames());
+			if n == 0 || buf.loop_points.is_some() {
+				continue;
+			}
+
+			buf.samples.drain(.. n * buf.channels);
+			for src in contexts.values_mut().filter(|c| c.device == dev).flat_map(|c| c.sources.values_mut()) {
+				if (src.state == AL_PLAYING || src.state == AL_PAUSED) && src.queue.get(src.current) == Some(&id) {
+					src.offset -= n as f64;
+				}
+			}
+		}
+
+		pending
+	}
+}
+
+
+/// Mix `frames` frames of a device into `out`, or bring an output device up to date with the wall clock if `frames` is `None`.
+/// Callbacks run without the state lock held, so they can call back into the API,
+/// but a device is not brought up to date again while one is running.
+fn render(dev: usize, frames: Option<usize>, mut out: Option<&mut [f32]>) {
+	let nested = IN_CALLBACK.with(|c| c.get());
+	if nested && frames.is_none() {
+		return;
+	}
+	let _guard = if nested { None } else { Some(RENDER.lock().unwrap_or_else(|e| e.into_inner())) };
+
+	let events = loop {
+		let pulls = with_state(|state| {
+			let (frames, target) = match (frames, state.devices.get(&dev)) {
+				(Some(frames), Some(_)) => (frames, None),
+				(None, Some(d)) if d.kind == DeviceKind::Output => {
+					let target = (d.clock.elapsed().as_secs_f64() * d.frequency as f64) as u64;
+					(target.saturating_sub(d.rendered) as usize, Some(target))
+				},
+				_ => return Ok(Vec::new()),
+			};
+
+			// Sources can only be mixed once callback buffers hold enough samples for them.
+			let pulls = if nested { Vec::new() } else { state.pulls(dev, frames) };
+			if !pulls.is_empty() {
+				return Err(pulls);
+			}
+
+			if let Some(target) = target {
+				state.devices.get_mut(&dev).unwrap().rendered = target;
+			}
+			Ok(if frames > 0 { state.mix(dev, frames, out.as_mut().map(|o| &mut **o)) } else { Vec::new() })
+		});
+
+		match pulls {
+			Ok(events) => break events,
+			Err(pulls) => {
+				let data = run_callbacks(|| pulls.iter().map(|p| unsafe { p.run() }).collect::<Vec<_>>());
+				with_state(|state| {
+					for (pull, data) in pulls.iter().zip(data) {
+						state.fill(pull, &data);
+					}
+				});
+			},
+		}
+	};
+	deliver(events);
+}
+
+
+/// Bring an output device up to date with the wall clock.
+fn sync(dev: usize) {
+	render(dev, None, None)
+}
+
+
+/// Run `f`, which calls user callbacks, with the thread marked as doing so.
+fn run_callbacks<R, F: FnOnce() -> R>(f: F) -> R {
+	let nested = IN_CALLBACK.with(|c| c.replace(true));
+	let r = f();
+	IN_CALLBACK.with(|c| c.set(nested));
+	r
+}
+
+
+fn deliver(events: Vec<Event>) {
+	run_callbacks(|| {
+		for e in events {
+			unsafe { (e.callback)(e.ty, e.object, e.value, e.message.len() as ALsizei, e.message.as_ptr() as *const ALchar, e.param as *mut c_void); }
+		}
+	})
+}
+
+
+impl Context {
+	/// Queue events for the event callback, if one is set and the event types are enabled.
+	fn queue_events(&self, events: &[(ALenum, ALuint, ALuint, &'static [u8])], out: &mut Vec<Event>) {
+		if let Some(callback) = self.event_callback {
+			for &(ty, object, value, message) in events.iter().filter(|e| self.events_enabled.contains(&e.0)) {
+				out.push(Event{callback: callback, param: self.event_param, ty: ty, object: object, value: value, message: message});
+			}
+		}
+	}
+
+
+	/// Set the direct filter or an aux send of a source, which refer to objects outside the source itself.
+	fn set_source_filter(&mut self, filters: &HashMap<ALuint, EfxObject>, source: ALuint, param: ALenum, v: &[f64]) -> Result<(), ALenum> {
+		let filter = |id: f64| match id as ALuint {
+			0 => Ok(None),
+			id => filters.get(&id).cloned().map(Some).ok_or(AL_INVALID_VALUE),
+		};
+
+		let src = self.sources.get_mut(&source).ok_or(AL_INVALID_NAME)?;
+		if v.len() != source_arity(param) {
+			return Err(AL_INVALID_ENUM);
+		}
+
+		if param == AL_DIRECT_FILTER {
+			src.direct_filter = filter(v[0])?;
+		} else {
+			let (slot, send) = (v[0] as ALuint, v[1]);
+			if (slot != 0 && !self.slots.contains_key(&slot)) || !(send >= 0.0 && (send as usize) < src.sends.len()) {
+				return Err(AL_INVALID_VALUE);
+			}
+			src.sends[send as usize] = if slot == 0 { None } else { Some((slot, filter(v[2])?)) };
+		}
+		Ok(())
+	}
+}
+
+
+impl Device {
+	fn new(kind: DeviceKind, spec: &'static [u8]) -> Device {
+		Device{
+			kind: kind,
+			spec: spec,
+			error: ALC_NO_ERROR,
+			frequency: DEFAULT_FREQUENCY,
+			channels: if kind == DeviceKind::Loopback { 0 } else { ALC_STEREO_SOFT },
+			sample_type: if kind == DeviceKind::Loopback { 0 } else { ALC_FLOAT_SOFT },
+			refresh: 50,
+			mono_sources: 255,
+			stereo_sources: 1,
+			buffers: HashMap::new(),
+			next_buffer: 0,
+			clock: Instant::now(),
+			rendered: 0,
+			time: 0,
+			connected: true,
+			effects: HashMap::new(),
+			filters: HashMap::new(),
+			next_efx_object: 0,
+			alc_extensions: ALC_EXTENSIONS_STR,
+			al_extensions: AL_EXTENSIONS_STR,
+			capture: None,
+		}
+	}
+
+
+	fn set_error(&mut self, e: ALCenum) {
+		if self.error == ALC_NO_ERROR {
+			self.error = e;
+		}
+	}
+
+
+	/// Apply a zero-terminated list of context attributes, as given to `alcCreateContext` or `alcReopenDeviceSOFT`.
+	unsafe fn apply_attrs(&mut self, attrlist: *const ALCint) -> Result<(), ALCenum> {
+		let mut frequency = None;
+		let mut channels = None;
+		let mut sample_type = None;
+		let mut refresh = None;
+		let mut mono_sources = None;
+		let mut stereo_sources = None;
+		let mut output_mode = None;
+
+		if attrlist != ptr::null() {
+			let mut attr = attrlist;
+			while *attr != 0 {
+				let value = *attr.offset(1);
+				match *attr {
+					ALC_FREQUENCY => frequency = Some(value),
+					ALC_REFRESH => refresh = Some(value),
+					ALC_MONO_SOURCES => mono_sources = Some(value),
+					ALC_STEREO_SOURCES => stereo_sources = Some(value),
+					ALC_FORMAT_CHANNELS_SOFT => channels = Some(value),
+					ALC_FORMAT_TYPE_SOFT => sample_type = Some(value),
+					ALC_OUTPUT_MODE_SOFT => output_mode = Some(value),
+					_ => (),
+				}
+				attr = attr.offset(2);
+			}
+		}
+
+		if self.kind == DeviceKind::Loopback {
+			match (frequency, channels, sample_type) {
+				(Some(f), Some(c), Some(t)) if f > 0 && channel_count(c).is_some() && sample_type_supported(t) => {
+					self.channels = c;
+					self.sample_type = t;
+				},
+				_ => return Err(ALC_INVALID_VALUE),
+			}
+		} else if let Some(mode) = output_mode {
+			// Stereo output is always mixed as basic panned stereo, whatever kind was asked for.
+			self.channels = match mode {
+				ALC_ANY_SOFT | ALC_STEREO_SOFT | ALC_STEREO_BASIC_SOFT | ALC_STEREO_UHJ_SOFT | ALC_STEREO_HRTF_SOFT => ALC_STEREO_SOFT,
+				_ if channel_count(mode).is_some() => mode,
+				_ => return Err(ALC_INVALID_VALUE),
+			};
+		}
+		if frequency.map(|f| f <= 0).unwrap_or(false) || refresh.map(|r| r <= 0).unwrap_or(false)
+			|| mono_sources.map(|m| m < 0).unwrap_or(false) || stereo_sources.map(|s| s < 0).unwrap_or(false)
+		{
+			return Err(ALC_INVALID_VALUE);
+		}
+
+		if let Some(f) = frequency {
+			self.frequency = f;
+			self.clock = Instant::now();
+			self.rendered = 0;
+		}
+		self.refresh = refresh.unwrap_or(self.refresh);
+		self.mono_sources = mono_sources.unwrap_or(self.mono_sources);
+		self.stereo_sources = stereo_sources.unwrap_or(self.stereo_sources);
+		Ok(())
+	}
+}
+
+
+impl CaptureState {
+	fn update(&mut self, frequency: ALCint) {
+		if self.running {
+			let total = (self.clock.elapsed().as_secs_f64() * frequency as f64) as u64;
+			let new = (total - self.counted) as usize;
+			self.counted = total;
+			self.available = cmp::min(self.capacity, self.available.saturating_add(new));
+		}
+	}
+}
+
+
+impl Buffer {
+	fn new() -> Buffer {
+		Buffer{
+			channels: 1,
+			bits: 16,
+			frequency: 0,
+			size: 0,
+			samples: Vec::new(),
+			loop_points: None,
+			callback: None,
+		}
+	}
+
+
+	fn frames(&self) -> usize { self.samples.len() / self.channels }
+	fn frame_size(&self) -> usize { self.channels * self.bits as usize / 8 }
+}
+
+
+impl Pull {
+	/// Call the buffer callback, returning the bytes it wrote.
+	unsafe fn run(&self) -> Vec<u8> {
+		// Allocated as words so the callback can treat the data as samples of any type.
+		let mut data = vec![0u64; (self.bytes + 7) / 8];
+		let len = (self.callback)(self.param as *mut c_void, data.as_mut_ptr() as *mut c_void, self.bytes as ALsizei);
+		let len = cmp::max(0, cmp::min(len, self.bytes as ALsizei)) as usize;
+		slice::from_raw_parts(data.as_ptr() as *const u8, len).to_vec()
+	}
+}
+
+
+impl Context {
+	fn new(device: usize) -> Context {
+		Context{
+			device: device,
+			error: AL_NO_ERROR,
+			event_callback: None,
+			event_param: 0,
+			events_enabled: Vec::new(),
+			listener: Listener{
+				gain: 1.0,
+				position: [0.0; 3],
+				velocity: [0.0; 3],
+				orientation: [0.0, 0.0, -1.0, 0.0, 1.0, 0.0],
+				meters_per_unit: 1.0,
+			},
+			sources: HashMap::new(),
+			next_source: 0,
+			slots: HashMap::new(),
+			next_slot: 0,
+			doppler_factor: 1.0,
+			doppler_velocity: 1.0,
+			speed_of_sound: 343.3,
+			distance_model: AL_INVERSE_DISTANCE_CLAMPED,
+		}
+	}
+
+
+	fn get(&self, param: ALenum) -> Result<f64, ALenum> {
+		match param {
+			AL_DOPPLER_FACTOR => Ok(self.doppler_factor as f64),
+			AL_DOPPLER_VELOCITY => Ok(self.doppler_velocity as f64),
+			AL_SPEED_OF_SOUND => Ok(self.speed_of_sound as f64),
+			AL_DISTANCE_MODEL => Ok(self.distance_model as f64),
+			_ => Err(AL_INVALID_ENUM),
+		}
+	}
+}
+
+
+impl EfxObject {
+	fn new() -> EfxObject {
+		EfxObject{ty: AL_EFFECT_NULL, props: HashMap::new()}
+	}
+
+
+	/// Set a property, or change the type to one of `types`, which clears every property.
+	/// `AL_EFFECT_TYPE` and `AL_FILTER_TYPE` share a value, so this serves both effects and filters.
+	fn set(&mut self, types: &[ALint], param: ALenum, v: &[f64]) -> Result<(), ALenum> {
+		if v.len() != self.arity(param) {
+			return Err(AL_INVALID_ENUM);
+		}
+
+		if param == AL_EFFECT_TYPE {
+			if !types.iter().any(|&t| t as f64 == v[0]) {
+				return Err(AL_INVALID_VALUE);
+			}
+			self.ty = v[0] as ALint;
+			self.props.clear();
+		} else if self.ty == AL_EFFECT_NULL {
+			return Err(AL_INVALID_ENUM);
+		} else {
+			self.props.insert(param, v.to_vec());
+		}
+		Ok(())
+	}
+
+
+	/// Read a property, which is 0 until it is set.
+	fn get(&self, param: ALenum) -> Result<Vec<f64>, ALenum> {
+		if param == AL_EFFECT_TYPE {
+			Ok(vec![self.ty as f64])
+		} else if self.ty == AL_EFFECT_NULL {
+			Err(AL_INVALID_ENUM)
+		} else {
+			Ok(self.props.get(&param).cloned().unwrap_or_else(|| vec![0.0; self.arity(param)]))
+		}
+	}
+
+
+	fn arity(&self, param: ALenum) -> usize {
+		match (self.ty, param) {
+			(AL_EFFECT_EAXREVERB, AL_EAXREVERB_REFLECTIONS_PAN) | (AL_EFFECT_EAXREVERB, AL_EAXREVERB_LATE_REVERB_PAN) => 3,
+			_ => 1,
+		}
+	}
+}
+
+
+impl AuxEffectSlot {
+	fn new() -> AuxEffectSlot {
+		AuxEffectSlot{
+			gain: 1.0,
+			send_auto: true,
+			effect_id: 0,
+			effect: None,
+		}
+	}
+
+
+	fn set(&mut self, effects: &HashMap<ALuint, EfxObject>, param: ALenum, v: f64) -> Result<(), ALenum> {
+		match param {
+			AL_EFFECTSLOT_EFFECT => {
+				let id = v as ALuint;
+				self.effect = if id == 0 { None } else { Some(effects.get(&id).ok_or(AL_INVALID_VALUE)?.clone()) };
+				self.effect_id = id;
+			},
+			AL_EFFECTSLOT_GAIN => self.gain = range(v, 0.0, 1.0)?,
+			AL_EFFECTSLOT_AUXILIARY_SEND_AUTO => self.send_auto = boolean(v)?,
+			_ => return Err(AL_INVALID_ENUM),
+		}
+		Ok(())
+	}
+
+
+	fn get(&self, param: ALenum) -> Result<f64, ALenum> {
+		match param {
+			AL_EFFECTSLOT_EFFECT => Ok(self.effect_id as f64),
+			AL_EFFECTSLOT_GAIN => Ok(self.gain as f64),
+			AL_EFFECTSLOT_AUXILIARY_SEND_AUTO => Ok(self.send_auto as ALint as f64),
+			_ => Err(AL_INVALID_ENUM),
+		}
+	}
+}
+
+
+impl Listener {
+	fn set(&mut self, param: ALenum, v: &[f64]) -> Result<(), ALenum> {
+		if v.len() != listener_arity(param) {
+			return Err(AL_INVALID_ENUM);
+		}
+
+		match param {
+			AL_GAIN => self.gain = range(v[0], 0.0, f32::MAX as f64)?,
+			AL_POSITION => self.position = vec3(v)?,
+			AL_VELOCITY => self.velocity = vec3(v)?,
+			AL_ORIENTATION => for (o, &v) in self.orientation.iter_mut().zip(v) {
+				*o = finite(v)?;
+			},
+			AL_METERS_PER_UNIT => self.meters_per_unit = range(v[0], f32::MIN_POSITIVE as f64, f32::MAX as f64)?,
+			_ => return Err(AL_INVALID_ENUM),
+		}
+		Ok(())
+	}
+
+
+	fn get(&self, param: ALenum) -> Result<Vec<f64>, ALenum> {
+		match param {
+			AL_GAIN => Ok(vec![self.gain as f64]),
+			AL_POSITION => Ok(self.position.iter().map(|&v| v as f64).collect()),
+			AL_VELOCITY => Ok(self.velocity.iter().map(|&v| v as f64).collect()),
+			AL_ORIENTATION => Ok(self.orientation.iter().map(|&v| v as f64).collect()),
+			AL_METERS_PER_UNIT => Ok(vec![self.meters_per_unit as f64]),
+			_ => Err(AL_INVALID_ENUM),
+		}
+	}
+}
+
+
+impl Source {
+	fn new() -> Source {
+		Source{
+			pitch: 1.0,
+			gain: 1.0,
+			min_gain: 0.0,
+			max_gain: 1.0,
+			reference_distance: 1.0,
+			rolloff_factor: 1.0,
+			max_distance: f32::MAX,
+			cone_outer_gain: 0.0,
+			cone_inner_angle: 360.0,
+			cone_outer_angle: 360.0,
+			position: [0.0; 3],
+			velocity: [0.0; 3],
+			direction: [0.0; 3],
+			relative: false,
+			looping: false,
+			kind: AL_UNDETERMINED,
+			state: AL_INITIAL,
+			queue: Vec::new(),
+			current: 0,
+			offset: 0.0,
+			pending_offset: None,
+			start_time: None,
+			air_absorption_factor: 0.0,
+			room_rolloff_factor: 0.0,
+			cone_outer_gainhf: 1.0,
+			direct_filter_gainhf_auto: true,
+			send_filter_gain_auto: true,
+			send_filter_gainhf_auto: true,
+			direct_filter: None,
+			sends: vec![None; MAX_AUXILIARY_SENDS],
+		}
+	}
+
+
+	fn set(&mut self, buffers: &HashMap<ALuint, Buffer>, param: ALenum, v: &[f64]) -> Result<(), ALenum> {
+		if v.len() != source_arity(param) {
+			return Err(AL_INVALID_ENUM);
+		}
+
+		match param {
+			AL_PITCH => if v[0] > 0.0 && v[0] <= f32::MAX as f64 {
+				self.pitch = v[0] as f32;
+			} else {
+				return Err(AL_INVALID_VALUE);
+			},
+			AL_GAIN => self.gain = range(v[0], 0.0, f32::MAX as f64)?,
+			AL_MIN_GAIN => self.min_gain = range(v[0], 0.0, 1.0)?,
+			AL_MAX_GAIN => self.max_gain = range(v[0], 0.0, 1.0)?,
+			AL_REFERENCE_DISTANCE => self.reference_distance = range(v[0], 0.0, f32::MAX as f64)?,
+			AL_ROLLOFF_FACTOR => self.rolloff_factor = range(v[0], 0.0, f32::MAX as f64)?,
+			AL_MAX_DISTANCE => self.max_distance = range(v[0], 0.0, f32::MAX as f64)?,
+			AL_CONE_OUTER_GAIN => self.cone_outer_gain = range(v[0], 0.0, 1.0)?,
+			AL_CONE_INNER_ANGLE => self.cone_inner_angle = range(v[0], 0.0, 360.0)?,
+			AL_CONE_OUTER_ANGLE => self.cone_outer_angle = range(v[0], 0.0, 360.0)?,
+			AL_POSITION => self.position = vec3(v)?,
+			AL_VELOCITY => self.velocity = vec3(v)?,
+			AL_DIRECTION => self.direction = vec3(v)?,
+			AL_SOURCE_RELATIVE => self.relative = boolean(v[0])?,
+			AL_LOOPING => self.looping = boolean(v[0])?,
+			AL_AIR_ABSORPTION_FACTOR => self.air_absorption_factor = range(v[0], 0.0, 10.0)?,
+			AL_ROOM_ROLLOFF_FACTOR => self.room_rolloff_factor = range(v[0], 0.0, 10.0)?,
+			AL_CONE_OUTER_GAINHF => self.cone_outer_gainhf = range(v[0], 0.0, 1.0)?,
+			AL_DIRECT_FILTER_GAINHF_AUTO => self.direct_filter_gainhf_auto = boolean(v[0])?,
+			AL_AUXILIARY_SEND_FILTER_GAIN_AUTO => self.send_filter_gain_auto = boolean(v[0])?,
+			AL_AUXILIARY_SEND_FILTER_GAINHF_AUTO => self.send_filter_gainhf_auto = boolean(v[0])?,
+			AL_BUFFER => {
+				if self.state != AL_INITIAL && self.state != AL_STOPPED {
+					return Err(AL_INVALID_OPERATION);
+				}
+
+				let buf = v[0] as ALuint;
+				if buf == 0 {
+					self.queue.clear();
+					self.kind = AL_UNDETERMINED;
+				} else if buffers.contains_key(&buf) {
+					self.queue = vec![buf];
+					self.kind = AL_STATIC;
+				} else {
+					return Err(AL_INVALID_NAME);
+				}
+				self.current = 0;
+				self.offset = 0.0;
+			},
+			AL_SEC_OFFSET | AL_SAMPLE_OFFSET | AL_BYTE_OFFSET => {
+				if !(v[0] >= 0.0) {
+					return Err(AL_INVALID_VALUE);
+				}
+
+				if self.state == AL_PLAYING || self.state == AL_PAUSED {
+					let frames = self.offset_frames(buffers, param, v[0]);
+					self.seek(buffers, frames)?;
+				} else {
+					self.pending_offset = Some((param, v[0]));
+				}
+			},
+			AL_SOURCE_STATE | AL_SOURCE_TYPE | AL_BUFFERS_QUEUED | AL_BUFFERS_PROCESSED => return Err(AL_INVALID_OPERATION),
+			_ => return Err(AL_INVALID_ENUM),
+		}
+		Ok(())
+	}
+
+
+	fn get(&self, buffers: &HashMap<ALuint, Buffer>, param: ALenum) -> Result<Vec<f64>, ALenum> {
+		let value = match param {
+			AL_PITCH => self.pitch as f64,
+			AL_GAIN => self.gain as f64,
+			AL_MIN_GAIN => self.min_gain as f64,
+			AL_MAX_GAIN => self.max_gain as f64,
+			AL_REFERENCE_DISTANCE => self.reference_distance as f64,
+			AL_ROLLOFF_FACTOR => self.rolloff_factor as f64,
+			AL_MAX_DISTANCE => self.max_distance as f64,
+			AL_CONE_OUTER_GAIN => self.cone_outer_gain as f64,
+			AL_CONE_INNER_ANGLE => self.cone_inner_angle as f64,
+			AL_CONE_OUTER_ANGLE => self.cone_outer_angle as f64,
+			AL_POSITION => return Ok(self.position.iter().map(|&v| v as f64).collect()),
+			AL_VELOCITY => return Ok(self.velocity.iter().map(|&v| v as f64).collect()),
+			AL_DIRECTION => return Ok(self.direction.iter().map(|&v| v as f64).collect()),
+			AL_SOURCE_RELATIVE => self.relative as ALint as f64,
+			AL_LOOPING => self.looping as ALint as f64,
+			AL_AIR_ABSORPTION_FACTOR => self.air_absorption_factor as f64,
+			AL_ROOM_ROLLOFF_FACTOR => self.room_rolloff_factor as f64,
+			AL_CONE_OUTER_GAINHF => self.cone_outer_gainhf as f64,
+			AL_DIRECT_FILTER_GAINHF_AUTO => self.direct_filter_gainhf_auto as ALint as f64,
+			AL_AUXILIARY_SEND_FILTER_GAIN_AUTO => self.send_filter_gain_auto as ALint as f64,
+			AL_AUXILIARY_SEND_FILTER_GAINHF_AUTO => self.send_filter_gainhf_auto as ALint as f64,
+			AL_BUFFER => match self.kind {
+				AL_STATIC => self.queue[0] as f64,
+				AL_STREAMING => self.queue.get(self.current).map(|&b| b as f64).unwrap_or(0.0),
+				_ => 0.0,
+			},
+			AL_SOURCE_STATE => self.state as f64,
+			AL_SOURCE_TYPE => self.kind as f64,
+			AL_BUFFERS_QUEUED => self.queue.len() as f64,
+			AL_BUFFERS_PROCESSED => self.processed() as f64,
+			AL_SEC_OFFSET | AL_SAMPLE_OFFSET | AL_BYTE_OFFSET => {
+				let frames = if self.state == AL_PLAYING || self.state == AL_PAUSED {
+					self.queue[.. self.current].iter().map(|b| buffers[b].frames() as f64).sum::<f64>() + self.offset
+				} else {
+					0.0
+				};
+
+				match (param, self.queue.first().map(|b| &buffers[b])) {
+					(AL_SEC_OFFSET, Some(buf)) if buf.frequency > 0 => frames / buf.frequency as f64,
+					(AL_BYTE_OFFSET, Some(buf)) => frames.floor() * buf.frame_size() as f64,
+					(AL_SAMPLE_OFFSET, _) => frames.floor(),
+					_ => 0.0,
+				}
+			},
+			_ => return Err(AL_INVALID_ENUM),
+		};
+
+		Ok(vec![value])
+	}
+
+
+	fn processed(&self) -> usize {
+		if self.kind != AL_STREAMING {
+			return 0;
+		}
+
+		match self.state {
+			AL_STOPPED => self.queue.len(),
+			AL_INITIAL => 0,
+			_ => if self.looping { 0 } else { self.current },
+		}
+	}
+
+
+	fn offset_frames(&self, buffers: &HashMap<ALuint, Buffer>, param: ALenum, value: f64) -> f64 {
+		let buf = match self.queue.first() {
+			Some(b) => &buffers[b],
+			None => return value,
+		};
+
+		match param {
+			AL_SEC_OFFSET => value * buf.frequency as f64,
+			AL_BYTE_OFFSET => (value as usize / cmp::max(buf.frame_size(), 1)) as f64,
+			_ => value.floor(),
+		}
+	}
+
+
+	fn seek(&mut self, buffers: &HashMap<ALuint, Buffer>, mut frames: f64) -> Result<(), ALenum> {
+		for (i, b) in self.queue.iter().enumerate() {
+			let len = buffers[b].frames() as f64;
+			if frames < len {
+				self.current = i;
+				self.offset = frames;
+				return Ok(());
+			}
+			frames -= len;
+		}
+
+		Err(AL_INVALID_VALUE)
+	}
+
+
+	fn play(&mut self, dev: &Device) {
+		let buffers = &dev.buffers;
+		self.start_time = None;
+		// Sources of a disconnected device stop as soon as they are played.
+		if !dev.connected {
+			self.stop();
+			return;
+		}
+		if self.state == AL_PAUSED {
+			self.state = AL_PLAYING;
+			return;
+		}
+
+		self.current = 0;
+		self.offset = 0.0;
+		if self.queue.is_empty() {
+			self.state = AL_STOPPED;
+			return;
+		}
+
+		if let Some((param, value)) = self.pending_offset.take() {
+			let frames = self.offset_frames(buffers, param, value);
+			let _ = self.seek(buffers, frames);
+		}
+		self.state = AL_PLAYING;
+	}
+
+
+	fn stop(&mut self) {
+		self.start_time = None;
+		self.state = AL_STOPPED;
+		self.current = self.queue.len();
+		self.offset = 0.0;
+		self.pending_offset = None;
+	}
+
+
+	fn rewind(&mut self) {
+		self.start_time = None;
+		self.state = AL_INITIAL;
+		self.current = 0;
+		self.offset = 0.0;
+		self.pending_offset = None;
+	}
+
+
+	fn pause(&mut self) {
+		if self.state == AL_PLAYING {
+			self.state = AL_PAUSED;
+		}
+	}
+
+
+	/// Advance the source by `frames` output frames, accumulating linearly interpolated samples into `out` if present.
+	fn mix(&mut self, buffers: &HashMap<ALuint, Buffer>, listener_gain: f32, frequency: f64, channels: usize, mut out: Option<&mut [f32]>, frames: usize) {
+		let gain = self.gain.max(self.min_gain).min(self.max_gain) * listener_gain;
+		let mut pos = 0;
+		let mut idle = 0;
+
+		// Buffer boundaries are crossed eagerly, so a source that has consumed all of its data stops immediately.
+		while self.state == AL_PLAYING {
+			let buf = &buffers[&self.queue[self.current]];
+			let mut len = buf.frames() as f64;
+
+			// A looping source playing a single buffer repeats only the section between the buffer's loop points.
+			if let (true, 1, Some((start, end))) = (self.looping, self.queue.len(), buf.loop_points) {
+				if self.offset >= end as f64 {
+					self.offset = start as f64 + (self.offset - end as f64) % (end - start) as f64;
+				}
+				len = end as f64;
+			}
+
+			if self.offset >= len {
+				self.offset -= len;
+				self.current += 1;
+				idle += 1;
+				if self.current == self.queue.len() {
+					// A looping queue of empty buffers would otherwise spin forever.
+					if self.looping && idle <= self.queue.len() {
+						self.current = 0;
+					} else {
+						self.stop();
+					}
+				}
+				continue;
+			}
+			idle = 0;
+			if pos == frames {
+				break;
+			}
+
+			let step = self.pitch as f64 * buf.frequency as f64 / frequency;
+			let n = cmp::min(frames - pos, ((len - self.offset) / step).ceil() as usize).max(1);
+
+			if let Some(ref mut out) = out {
+				let last = buf.frames() - 1;
+				for i in 0 .. n {
+					let x = self.offset + i as f64 * step;
+					let i0 = x as usize;
+					let i1 = cmp::min(i0 + 1, last);
+					let frac = (x - i0 as f64) as f32;
+					let frame = &mut out[(pos + i) * channels .. (pos + i + 1) * channels];
+
+					for c in 0 .. buf.channels {
+						let s = buf.samples[i0 * buf.channels + c] * (1.0 - frac) + buf.samples[i1 * buf.channels + c] * frac;
+						route(s * gain, c, buf.channels, frame);
+					}
+				}
+			}
+
+			self.offset += n as f64 * step;
+			pos += n;
+		}
+	}
+}
+
+
+fn route(s: f32, c: usize, src_channels: usize, frame: &mut [f32]) {
+	match (src_channels, frame.len()) {
+		(1, 1) => frame[0] += s,
+		(1, _) => {
+			frame[0] += s;
+			frame[1] += s;
+		},
+		(_, 1) => frame[0] += s * 0.5,
+		_ => frame[c] += s,
+	}
+}
+
+
+fn source_arity(param: ALenum) -> usize {
+	match param {
+		AL_POSITION | AL_VELOCITY | AL_DIRECTION => 3,
+		AL_PITCH | AL_GAIN | AL_MIN_GAIN | AL_MAX_GAIN | AL_REFERENCE_DISTANCE | AL_ROLLOFF_FACTOR | AL_MAX_DISTANCE
+			| AL_CONE_OUTER_GAIN | AL_CONE_INNER_ANGLE | AL_CONE_OUTER_ANGLE | AL_SOURCE_RELATIVE | AL_LOOPING | AL_BUFFER
+			| AL_SOURCE_STATE | AL_SOURCE_TYPE | AL_BUFFERS_QUEUED | AL_BUFFERS_PROCESSED
+			| AL_SEC_OFFSET | AL_SAMPLE_OFFSET | AL_BYTE_OFFSET
+			| AL_AIR_ABSORPTION_FACTOR | AL_ROOM_ROLLOFF_FACTOR | AL_CONE_OUTER_GAINHF | AL_DIRECT_FILTER_GAINHF_AUTO
+			| AL_AUXILIARY_SEND_FILTER_GAIN_AUTO | AL_AUXILIARY_SEND_FILTER_GAINHF_AUTO | AL_DIRECT_FILTER => 1,
+		AL_AUXILIARY_SEND_FILTER => 3,
+		_ => 0,
+	}
+}
+
+
+fn listener_arity(param: ALenum) -> usize {
+	match param {
+		AL_GAIN | AL_METERS_PER_UNIT => 1,
+		AL_POSITION | AL_VELOCITY => 3,
+		AL_ORIENTATION => 6,
+		_ => 0,
+	}
+}
+
+
+fn buffer_arity(param: ALenum) -> usize {
+	match param {
+		AL_FREQUENCY | AL_BITS | AL_CHANNELS | AL_SIZE => 1,
+		_ => 0,
+	}
+}
+
+
+fn range(v: f64, min: f64, max: f64) -> Result<f32, ALenum> {
+	if v >= min && v <= max { Ok(v as f32) } else { Err(AL_INVALID_VALUE) }
+}
+
+
+fn finite(v: f64) -> Result<f32, ALenum> {
+	if v.is_finite() { Ok(v as f32) } else { Err(AL_INVALID_VALUE) }
+}
+
+
+fn vec3(v: &[f64]) -> Result<[f32; 3], ALenum> {
+	Ok([finite(v[0])?, finite(v[1])?, finite(v[2])?])
+}
+
+
+fn boolean(v: f64) -> Result<bool, ALenum> {
+	if v == 0.0 {
+		Ok(false)
+	} else if v == 1.0 {
+		Ok(true)
+	} else {
+		Err(AL_INVALID_VALUE)
+	}
+}
+
+
+fn channel_count(channels: ALCenum) -> Option<usize> {
+	match channels {
+		ALC_MONO_SOFT => Some(1),
+		ALC_STEREO_SOFT => Some(2),
+		ALC_QUAD_SOFT => Some(4),
+		ALC_5POINT1_SOFT => Some(6),
+		ALC_6POINT1_SOFT => Some(7),
+		ALC_7POINT1_SOFT => Some(8),
+		_ => None,
+	}
+}
+
+
+fn sample_type_supported(sample_type: ALCenum) -> bool {
+	match sample_type {
+		ALC_BYTE_SOFT | ALC_UNSIGNED_BYTE_SOFT | ALC_SHORT_SOFT | ALC_UNSIGNED_SHORT_SOFT | ALC_INT_SOFT | ALC_UNSIGNED_INT_SOFT | ALC_FLOAT_SOFT => true,
+		_ => false,
+	}
+}
+
+
+/// Channel count and bits per sample of a buffer format.
+fn buffer_format(format: ALenum) -> Option<(usize, ALint)> {
+	match format {
+		AL_FORMAT_MONO8 => Some((1, 8)),
+		AL_FORMAT_MONO16 => Some((1, 16)),
+		AL_FORMAT_STEREO8 => Some((2, 8)),
+		AL_FORMAT_STEREO16 => Some((2, 16)),
+		AL_FORMAT_MONO_FLOAT32 => Some((1, 32)),
+		AL_FORMAT_STEREO_FLOAT32 => Some((2, 32)),
+		_ => None,
+	}
+}
+
+
+fn decode(bits: ALint, data: &[u8]) -> Vec<f32> {
+	match bits {
+		8 => data.iter().map(|&s| (s as f32 - 128.0) / 128.0).collect(),
+		16 => data.chunks(2).map(|s| i16::from_ne_bytes([s[0], s[1]]) as f32 / 32768.0).collect(),
+		_ => data.chunks(4).map(|s| f32::from_ne_bytes([s[0], s[1], s[2], s[3]])).collect(),
+	}
+}
+
+
+unsafe fn write_mix<T, C: Fn(f32) -> T>(dst: *mut c_void, mix: &[f32], conv: C) {
+	if mix.is_empty() {
+		return;
+	}
+
+	let dst = slice::from_raw_parts_mut(dst as *mut T, mix.len());
+	for (d, &s) in dst.iter_mut().zip(mix) {
+		*d = conv(s);
+	}
+}
+
+
+fn clamp(s: f32) -> f64 {
+	s.max(-1.0).min(1.0) as f64
+}
+
+
+unsafe fn name_eq(name: *const ALCchar, expected: &[u8]) -> bool {
+	CStr::from_ptr(name).to_bytes().eq_ignore_ascii_case(expected)
+}
+
+
+/// The entry of a device list named `name`, including its terminator, or the default device if `name` is null.
+unsafe fn find_device(list: &'static [u8], name: *const ALCchar) -> Option<&'static [u8]> {
+	let mut devices = list.split_inclusive(|&c| c == 0).take_while(|d| d.len() > 1);
+	if name == ptr::null() {
+		return devices.next();
+	}
+
+	let name = CStr::from_ptr(name).to_bytes();
+	devices.find(|d| d[.. d.len() - 1].eq_ignore_ascii_case(name))
+}
+
+
+unsafe fn find_extension(list: &[u8], name: *const ALCchar) -> bool {
+	let name = CStr::from_ptr(name).to_bytes();
+	list[.. list.len() - 1].split(|&c| c == b' ').any(|e| e.eq_ignore_ascii_case(name))
+}
+
+
+/// Like `slice::from_raw_parts`, but tolerates a null pointer when `n` is 0.
+unsafe fn raw_slice<'a, T>(p: *const T, n: ALsizei) -> &'a [T] {
+	if n > 0 { slice::from_raw_parts(p, n as usize) } else { &[] }
+}
+
+
+unsafe fn read_values<T: Copy + Into<f64>>(values: *const T, n: usize) -> Vec<f64> {
+	slice::from_raw_parts(values, n).iter().map(|&v| v.into()).collect()
+}
+
+
+unsafe fn write_values<T, C: Fn(f64) -> T>(dst: *mut T, values: &[f64], conv: C) {
+	for (i, &v) in values.iter().enumerate() {
+		*dst.offset(i as isize) = conv(v);
+	}
+}
+
+
+fn as_int(v: f64) -> ALint {
+	// Buffer names are unsigned, so wrap them into the signed range as the C API does.
+	if v > ALint::max_value() as f64 { v as ALuint as ALint } else { v as ALint }
+}
+
+
+fn as_bool(v: f64) -> ALboolean {
+	if v != 0.0 { AL_TRUE } else { AL_FALSE }
+}
+
+
+unsafe extern "C" fn alcCreateContext(device: *mut ALCdevice, attrlist: *const ALCint) -> *mut ALCcontext {
+	with_device(device, &[DeviceKind::Output, DeviceKind::Loopback], ptr::null_mut(), |state, dev| {
+		state.devices.get_mut(&dev).unwrap().apply_attrs(attrlist)?;
+
+		state.next_handle += 1;
+		state.contexts.insert(state.next_handle, Context::new(dev));
+		Ok(state.next_handle as *mut ALCcontext)
+	})
+}
+
+
+unsafe extern "C" fn alcMakeContextCurrent(context: *mut ALCcontext) -> ALCboolean {
+	with_state(|state| {
+		if context == ptr::null_mut() {
+			state.current = None;
+		} else if state.contexts.contains_key(&(context as usize)) {
+			state.current = Some(context as usize);
+		} else {
+			state.set_error(ALC_INVALID_CONTEXT);
+			return ALC_FALSE;
+		}
+		// Making a context current globally also clears the calling thread's context.
+		THREAD_CONTEXT.with(|c| c.set(None));
+		ALC_TRUE
+	})
+}
+
+
+unsafe extern "C" fn alcSetThreadContext(context: *mut ALCcontext) -> ALCboolean {
+	with_state(|state| {
+		if context != ptr::null_mut() && !state.contexts.contains_key(&(context as usize)) {
+			state.set_error(ALC_INVALID_CONTEXT);
+			return ALC_FALSE;
+		}
+		THREAD_CONTEXT.with(|c| c.set(if context == ptr::null_mut() { None } else { Some(context as usize) }));
+		ALC_TRUE
+	})
+}
+
+
+unsafe extern "C" fn alcGetThreadContext() -> *mut ALCcontext {
+	with_state(|state| THREAD_CONTEXT.with(|c| c.get()).filter(|c| state.contexts.contains_key(c)).unwrap_or(0) as *mut ALCcontext)
+}
+
+
+unsafe extern "C" fn alcProcessContext(context: *mut ALCcontext) {
+	with_state(|state| if !state.contexts.contains_key(&(context as usize)) {
+		state.set_error(ALC_INVALID_CONTEXT);
+	})
+}
+
+
+unsafe extern "C" fn alcSuspendContext(context: *mut ALCcontext) {
+	with_state(|state| if !state.contexts.contains_key(&(context as usize)) {
+		state.set_error(ALC_INVALID_CONTEXT);
+	})
+}
+
+
+unsafe extern "C" fn alcDestroyContext(context: *mut ALCcontext) {
+	with_state(|state| {
+		if state.contexts.remove(&(context as usize)).is_some() {
+			if state.current == Some(context as usize) {
+				state.current = None;
+			}
+		} else {
+			state.set_error(ALC_INVALID_CONTEXT);
+		}
+	})
+}
+
+
+unsafe extern "C" fn alcGetCurrentContext() -> *mut ALCcontext {
+	with_state(|state| state.current_context().unwrap_or(0) as *mut ALCcontext)
+}
+
+
+unsafe extern "C" fn alcGetContextsDevice(context: *mut ALCcontext) -> *mut ALCdevice {
+	with_state(|state| match state.contexts.get(&(context as usize)) {
+		Some(ctx) => ctx.device as *mut ALCdevice,
+		None => {
+			state.set_error(ALC_INVALID_CONTEXT);
+			ptr::null_mut()
+		},
+	})
+}
+
+
+unsafe extern "C" fn alcOpenDevice(devicename: *const ALCchar) -> *mut ALCdevice {
+	with_state(|state| {
+		let spec = match find_device(state.outputs, devicename) {
+			Some(spec) => spec,
+			None => {
+				state.set_error(ALC_INVALID_VALUE);
+				return ptr::null_mut();
+			},
+		};
+
+		state.add_device(Device::new(DeviceKind::Output, spec))
+	})
+}
+
+
+unsafe extern "C" fn alcReopenDeviceSOFT(device: *mut ALCdevice, devicename: *const ALCchar, attribs: *const ALCint) -> ALCboolean {
+	with_device(device, &[DeviceKind::Output], ALC_FALSE, |state, dev| {
+		let spec = find_device(state.outputs, devicename).ok_or(ALC_INVALID_VALUE)?;
+
+		// Mixing carries on from where the old output left off, against the new output's clock.
+		let device = state.devices.get_mut(&dev).unwrap();
+		device.apply_attrs(attribs)?;
+		device.spec = spec;
+		device.clock = Instant::now();
+		device.rendered = 0;
+		device.connected = true;
+		Ok(ALC_TRUE)
+	})
+}
+
+
+unsafe extern "C" fn alcCloseDevice(device: *mut ALCdevice) -> ALCboolean {
+	with_state(|state| {
+		let id = device as usize;
+		match state.devices.get(&id).map(|d| d.kind) {
+			Some(DeviceKind::Output) | Some(DeviceKind::Loopback) => (),
+			_ => {
+				state.set_error(ALC_INVALID_DEVICE);
+				return ALC_FALSE;
+			},
+		}
+
+		state.devices.remove(&id);
+		state.contexts.retain(|_, c| c.device != id);
+		if state.current.map(|c| !state.contexts.contains_key(&c)).unwrap_or(false) {
+			state.current = None;
+		}
+		ALC_TRUE
+	})
+}
+
+
+unsafe extern "C" fn alcGetError(device: *mut ALCdevice) -> ALCenum {
+	with_state(|state| {
+		if device == ptr::null_mut() {
+			return mem_take(&mut state.error);
+		}
+
+		match state.devices.get_mut(&(device as usize)) {
+			Some(dev) => mem_take(&mut dev.error),
+			None => ALC_INVALID_DEVICE,
+		}
+	})
+}
+
+
+fn mem_take(error: &mut ALCenum) -> ALCenum {
+	let e = *error;
+	*error = ALC_NO_ERROR;
+	e
+}
+
+
+unsafe extern "C" fn alcIsExtensionPresent(device: *mut ALCdevice, extname: *const ALCchar) -> ALCboolean {
+	with_state(|state| {
+		if extname == ptr::null() {
+			match state.devices.get_mut(&(device as usize)) {
+				Some(dev) => dev.set_error(ALC_INVALID_VALUE),
+				None => state.set_error(ALC_INVALID_VALUE),
+			}
+			return ALC_FALSE;
+		}
+
+		let list = state.devices.get(&(device as usize)).map(|d| d.alc_extensions).unwrap_or(ALC_EXTENSIONS_STR);
+		if find_extension(list, extname) { ALC_TRUE } else { ALC_FALSE }
+	})
+}
+
+
+unsafe extern "C" fn alcGetProcAddress(_device: *mut ALCdevice, funcname: *const ALCchar) -> *mut c_void {
+	if funcname == ptr::null() {
+		return ptr::null_mut();
+	}
+
+	match CStr::from_ptr(funcname).to_bytes() {
+		b"alcLoopbackOpenDeviceSOFT" => alcLoopbackOpenDeviceSOFT as *mut c_void,
+		b"alcIsRenderFormatSupportedSOFT" => alcIsRenderFormatSupportedSOFT as *mut c_void,
+		b"alcRenderSamplesSOFT" => alcRenderSamplesSOFT as *mut c_void,
+		b"alcGetInteger64vSOFT" => alcGetInteger64vSOFT as *mut c_void,
+		b"alcReopenDeviceSOFT" => alcReopenDeviceSOFT as *mut c_void,
+		b"alcSetThreadContext" => alcSetThreadContext as *mut c_void,
+		b"alcGetThreadContext" => alcGetThreadContext as *mut c_void,
+		b"alGenAuxiliaryEffectSlots" => alGenAuxiliaryEffectSlots as *mut c_void,
+		b"alDeleteAuxiliaryEffectSlots" => alDeleteAuxiliaryEffectSlots as *mut c_void,
+		b"alIsAuxiliaryEffectSlot" => alIsAuxiliaryEffectSlot as *mut c_void,
+		b"alAuxiliaryEffectSloti" => alAuxiliaryEffectSloti as *mut c_void,
+		b"alAuxiliaryEffectSlotiv" => alAuxiliaryEffectSlotiv as *mut c_void,
+		b"alAuxiliaryEffectSlotf" => alAuxiliaryEffectSlotf as *mut c_void,
+		b"alAuxiliaryEffectSlotfv" => alAuxiliaryEffectSlotfv as *mut c_void,
+		b"alGetAuxiliaryEffectSloti" => alGetAuxiliaryEffectSloti as *mut c_void,
+		b"alGetAuxiliaryEffectSlotiv" => alGetAuxiliaryEffectSlotiv as *mut c_void,
+		b"alGetAuxiliaryEffectSlotf" => alGetAuxiliaryEffectSlotf as *mut c_void,
+		b"alGetAuxiliaryEffectSlotfv" => alGetAuxiliaryEffectSlotfv as *mut c_void,
+		b"alGenEffects" => alGenEffects as *mut c_void,
+		b"alDeleteEffects" => alDeleteEffects as *mut c_void,
+		b"alIsEffect" => alIsEffect as *mut c_void,
+		b"alEffecti" => alEffecti as *mut c_void,
+		b"alEffectiv" => alEffectiv as *mut c_void,
+		b"alEffectf" => alEffectf as *mut c_void,
+		b"alEffectfv" => alEffectfv as *mut c_void,
+		b"alGetEffecti" => alGetEffecti as *mut c_void,
+		b"alGetEffectiv" => alGetEffectiv as *mut c_void,
+		b"alGetEffectf" => alGetEffectf as *mut c_void,
+		b"alGetEffectfv" => alGetEffectfv as *mut c_void,
+		b"alGenFilters" => alGenFilters as *mut c_void,
+		b"alDeleteFilters" => alDeleteFilters as *mut c_void,
+		b"alIsFilter" => alIsFilter as *mut c_void,
+		b"alFilteri" => alFilteri as *mut c_void,
+		b"alFilteriv" => alFilteriv as *mut c_void,
+		b"alFilterf" => alFilterf as *mut c_void,
+		b"alFilterfv" => alFilterfv as *mut c_void,
+		b"alGetFilteri" => alGetFilteri as *mut c_void,
+		b"alGetFilteriv" => alGetFilteriv as *mut c_void,
+		b"alGetFilterf" => alGetFilterf as *mut c_void,
+		b"alGetFilterfv" => alGetFilterfv as *mut c_void,
+		_ => ptr::null_mut(),
+	}
+}
+
+
+unsafe extern "C" fn alcGetEnumValue(_device: *mut ALCdevice, enumname: *const ALCchar) -> ALCenum {
+	if enumname == ptr::null() {
+		return 0;
+	}
+
+	let name = CStr::from_ptr(enumname).to_bytes();
+	ALC_ENUMS.iter().chain(EFX_ENUMS).find(|&&(n, _)| n == name).map(|&(_, e)| e).unwrap_or(0)
+}
+
+
+unsafe extern "C" fn alcGetString(device: *mut ALCdevice, param: ALCenum) -> *const ALCchar {
+	let s: &'static [u8] = match param {
+		ALC_NO_ERROR => b"No Error\0",
+		ALC_INVALID_DEVICE => b"Invalid Device\0",
+		ALC_INVALID_CONTEXT => b"Invalid Context\0",
+		ALC_INVALID_ENUM => b"Invalid Enum\0",
+		ALC_INVALID_VALUE => b"Invalid Value\0",
+		ALC_OUT_OF_MEMORY => b"Out of Memory\0",
+		ALC_DEFAULT_DEVICE_SPECIFIER | ALC_CAPTURE_DEFAULT_DEVICE_SPECIFIER => {
+			let list = with_state(|state| if param == ALC_DEFAULT_DEVICE_SPECIFIER { state.outputs } else { state.captures });
+			match find_device(list, ptr::null()) {
+				Some(spec) => spec,
+				None => return ptr::null(),
+			}
+		},
+		ALC_EXTENSIONS => with_state(|state| state.devices.get(&(device as usize)).map(|d| d.alc_extensions).unwrap_or(ALC_EXTENSIONS_STR)),
+		ALC_DEVICE_SPECIFIER | ALC_CAPTURE_DEVICE_SPECIFIER if device == ptr::null_mut() => {
+			with_state(|state| if param == ALC_DEVICE_SPECIFIER { state.outputs } else { state.captures })
+		},
+		ALC_DEVICE_SPECIFIER | ALC_CAPTURE_DEVICE_SPECIFIER => {
+			match with_state(|state| state.devices.get(&(device as usize)).map(|d| d.spec)) {
+				Some(spec) => spec,
+				None => {
+					with_state(|state| state.set_error(ALC_INVALID_DEVICE));
+					return ptr::null();
+				},
+			}
+		},
+		_ => {
+			with_state(|state| match state.devices.get_mut(&(device as usize)) {
+				Some(dev) => dev.set_error(ALC_INVALID_ENUM),
+				None => state.set_error(ALC_INVALID_ENUM),
+			});
+			return ptr::null();
+		},
+	};
+
+	s.as_ptr() as *const ALCchar
+}
+
+
+unsafe extern "C" fn alcGetIntegerv(device: *mut ALCdevice, param: ALCenum, size: ALCsizei, values: *mut ALCint) {
+	if values == ptr::null_mut() || size <= 0 {
+		with_state(|state| match state.devices.get_mut(&(device as usize)) {
+			Some(dev) => dev.set_error(ALC_INVALID_VALUE),
+			None => state.set_error(ALC_INVALID_VALUE),
+		});
+		return;
+	}
+
+	match param {
+		ALC_MAJOR_VERSION | ALC_MINOR_VERSION => {
+			*values = 1;
+			return;
+		},
+		_ => (),
+	}
+
+	with_device(device, &[DeviceKind::Output, DeviceKind::Loopback, DeviceKind::Capture], (), |state, dev| {
+		let dev = state.devices.get_mut(&dev).unwrap();
+		let mut attrs = vec![
+			ALC_FREQUENCY, dev.frequency,
+			ALC_REFRESH, dev.refresh,
+			ALC_SYNC, ALC_FALSE as ALCint,
+			ALC_MONO_SOURCES, dev.mono_sources,
+			ALC_STEREO_SOURCES, dev.stereo_sources,
+		];
+		// Only loopback devices report their sample format.
+		if dev.kind == DeviceKind::Loopback {
+			attrs.extend_from_slice(&[ALC_FORMAT_CHANNELS_SOFT, dev.channels, ALC_FORMAT_TYPE_SOFT, dev.sample_type]);
+		}
+		let output_mode = if dev.channels == ALC_STEREO_SOFT { ALC_STEREO_BASIC_SOFT } else { dev.channels };
+		if dev.kind != DeviceKind::Capture {
+			attrs.extend_from_slice(&[ALC_OUTPUT_MODE_SOFT, output_mode, ALC_MAX_AUXILIARY_SENDS, MAX_AUXILIARY_SENDS as ALCint]);
+		}
+		attrs.push(0);
+
+		let value = match param {
+			ALC_ATTRIBUTES_SIZE => attrs.len() as ALCint,
+			ALC_ALL_ATTRIBUTES => {
+				if (size as usize) < attrs.len() {
+					return Err(ALC_INVALID_VALUE);
+				}
+				ptr::copy_nonoverlapping(attrs.as_ptr(), values, attrs.len());
+				return Ok(());
+			},
+			ALC_FREQUENCY => dev.frequency,
+			ALC_REFRESH => dev.refresh,
+			ALC_SYNC => ALC_FALSE as ALCint,
+			ALC_MONO_SOURCES => dev.mono_sources,
+			ALC_STEREO_SOURCES => dev.stereo_sources,
+			ALC_FORMAT_CHANNELS_SOFT if dev.kind == DeviceKind::Loopback => dev.channels,
+			ALC_FORMAT_TYPE_SOFT if dev.kind == DeviceKind::Loopback => dev.sample_type,
+			ALC_OUTPUT_MODE_SOFT if dev.kind != DeviceKind::Capture => output_mode,
+			ALC_MAX_AUXILIARY_SENDS if dev.kind != DeviceKind::Capture => MAX_AUXILIARY_SENDS as ALCint,
+			ALC_EFX_MAJOR_VERSION => 1,
+			ALC_EFX_MINOR_VERSION => 0,
+			ALC_CONNECTED => dev.connected as ALCint,
+			ALC_CAPTURE_SAMPLES if dev.kind == DeviceKind::Capture => {
+				let frequency = dev.frequency;
+				let cap = dev.capture.as_mut().unwrap();
+				cap.update(frequency);
+				cap.available as ALCint
+			},
+			_ => return Err(ALC_INVALID_ENUM),
+		};
+
+		*values = value;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alcGetInteger64vSOFT(device: *mut ALCdevice, param: ALCenum, size: ALCsizei, values: *mut i64) {
+	match param {
+		ALC_DEVICE_CLOCK_SOFT | ALC_DEVICE_LATENCY_SOFT | ALC_DEVICE_CLOCK_LATENCY_SOFT => (),
+		_ => {
+			// Everything else is an ordinary integer query.
+			if values == ptr::null_mut() {
+				return alcGetIntegerv(device, param, size, ptr::null_mut());
+			}
+			let mut ints = vec![0; cmp::max(size, 0) as usize];
+			alcGetIntegerv(device, param, size, ints.as_mut_ptr());
+			for (i, &v) in ints.iter().enumerate() {
+				*values.offset(i as isize) = v as i64;
+			}
+			return;
+		},
+	}
+
+	with_device(device, &[DeviceKind::Output, DeviceKind::Loopback], (), |state, dev| {
+		let dev = &state.devices[&dev];
+		// Output devices mix one update ahead of the wall clock; loopback devices hand their mix straight back.
+		let latency = if dev.kind == DeviceKind::Output { 1_000_000_000 / dev.refresh as i64 } else { 0 };
+
+		match param {
+			_ if values == ptr::null_mut() || size < 1 => return Err(ALC_INVALID_VALUE),
+			ALC_DEVICE_CLOCK_SOFT => *values = dev.time as i64,
+			ALC_DEVICE_LATENCY_SOFT => *values = latency,
+			_ if size < 2 => return Err(ALC_INVALID_VALUE),
+			_ => {
+				*values = dev.time as i64;
+				*values.offset(1) = latency;
+			},
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alcCaptureOpenDevice(devicename: *const ALCchar, frequency: ALCuint, format: ALCenum, buffersize: ALCsizei) -> *mut ALCdevice {
+	with_state(|state| {
+		let spec = match find_device(state.captures, devicename) {
+			Some(spec) => spec,
+			None => {
+				state.set_error(ALC_INVALID_VALUE);
+				return ptr::null_mut();
+			},
+		};
+
+		let (channels, bits) = match buffer_format(format) {
+			Some(cb) if frequency > 0 && frequency <= ALCint::max_value() as ALCuint && buffersize > 0 => cb,
+			_ => {
+				state.set_error(ALC_INVALID_VALUE);
+				return ptr::null_mut();
+			},
+		};
+
+		let mut dev = Device::new(DeviceKind::Capture, spec);
+		dev.frequency = frequency as ALCint;
+		dev.capture = Some(CaptureState{
+			frame_size: channels * bits as usize / 8,
+			silence: if bits == 8 { 0x80 } else { 0 },
+			capacity: buffersize as usize,
+			available: 0,
+			running: false,
+			clock: Instant::now(),
+			counted: 0,
+		});
+		state.add_device(dev)
+	})
+}
+
+
+unsafe extern "C" fn alcCaptureCloseDevice(device: *mut ALCdevice) -> ALCboolean {
+	with_state(|state| {
+		match state.devices.get(&(device as usize)).map(|d| d.kind) {
+			Some(DeviceKind::Capture) => {
+				state.devices.remove(&(device as usize));
+				ALC_TRUE
+			},
+			_ => {
+				state.set_error(ALC_INVALID_DEVICE);
+				ALC_FALSE
+			},
+		}
+	})
+}
+
+
+unsafe extern "C" fn alcCaptureStart(device: *mut ALCdevice) {
+	with_device(device, &[DeviceKind::Capture], (), |state, dev| {
+		let cap = state.devices.get_mut(&dev).unwrap().capture.as_mut().unwrap();
+		if !cap.running {
+			cap.running = true;
+			cap.clock = Instant::now();
+			cap.counted = 0;
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alcCaptureStop(device: *mut ALCdevice) {
+	with_device(device, &[DeviceKind::Capture], (), |state, dev| {
+		let dev = state.devices.get_mut(&dev).unwrap();
+		let frequency = dev.frequency;
+		let cap = dev.capture.as_mut().unwrap();
+		cap.update(frequency);
+		cap.running = false;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alcCaptureSamples(device: *mut ALCdevice, buffer: *mut ALCvoid, samples: ALCsizei) {
+	with_device(device, &[DeviceKind::Capture], (), |state, dev| {
+		let dev = state.devices.get_mut(&dev).unwrap();
+		let frequency = dev.frequency;
+		let cap = dev.capture.as_mut().unwrap();
+		cap.update(frequency);
+
+		if samples < 0 || samples as usize > cap.available || (buffer == ptr::null_mut() && samples > 0) {
+			return Err(ALC_INVALID_VALUE);
+		}
+
+		if samples > 0 {
+			ptr::write_bytes(buffer as *mut u8, cap.silence, samples as usize * cap.frame_size);
+		}
+		cap.available -= samples as usize;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alcLoopbackOpenDeviceSOFT(deviceName: *const ALCchar) -> *mut ALCdevice {
+	with_state(|state| {
+		if deviceName != ptr::null() && !name_eq(deviceName, &OUTPUT_NAME[.. OUTPUT_NAME.len() - 1]) {
+			state.set_error(ALC_INVALID_VALUE);
+			return ptr::null_mut();
+		}
+
+		state.add_device(Device::new(DeviceKind::Loopback, OUTPUT_NAME))
+	})
+}
+
+
+unsafe extern "C" fn alcIsRenderFormatSupportedSOFT(device: *mut ALCdevice, frequency: ALCsizei, channels: ALCenum, type_: ALCenum) -> ALCboolean {
+	with_device(device, &[DeviceKind::Loopback], ALC_FALSE, |_, _| {
+		if frequency <= 0 {
+			return Err(ALC_INVALID_VALUE);
+		}
+
+		Ok(if channel_count(channels).is_some() && sample_type_supported(type_) { ALC_TRUE } else { ALC_FALSE })
+	})
+}
+
+
+unsafe extern "C" fn alcRenderSamplesSOFT(device: *mut ALCdevice, buffer: *mut ALvoid, samples: ALCsizei) {
+	let format = with_device(device, &[DeviceKind::Loopback], None, |state, dev| {
+		let dev = &state.devices[&dev];
+		match channel_count(dev.channels) {
+			Some(c) if samples >= 0 && (buffer != ptr::null_mut() || samples == 0) => Ok(Some((c, dev.sample_type))),
+			_ => Err(ALC_INVALID_VALUE),
+		}
+	});
+
+	if let Some((channels, sample_type)) = format {
+		let mut mix = vec![0.0f32; samples as usize * channels];
+		render(device as usize, Some(samples as usize), Some(&mut mix));
+
+		match sample_type {
+			ALC_BYTE_SOFT => write_mix(buffer, &mix, |s| (clamp(s) * 127.0) as i8),
+			ALC_UNSIGNED_BYTE_SOFT => write_mix(buffer, &mix, |s| (clamp(s) * 127.0 + 128.0) as u8),
+			ALC_SHORT_SOFT => write_mix(buffer, &mix, |s| (clamp(s) * 32767.0) as i16),
+			ALC_UNSIGNED_SHORT_SOFT => write_mix(buffer, &mix, |s| (clamp(s) * 32767.0 + 32768.0) as u16),
+			ALC_INT_SOFT => write_mix(buffer, &mix, |s| (clamp(s) * 2147483647.0) as i32),
+			ALC_UNSIGNED_INT_SOFT => write_mix(buffer, &mix, |s| (clamp(s) * 2147483647.0 + 2147483648.0) as u32),
+			_ => write_mix(buffer, &mix, |s| s),
+		}
+	}
+}
+
+
+unsafe extern "C" fn alDopplerFactor(value: ALfloat) {
+	with_context((), |ctx, _| {
+		ctx.doppler_factor = range(value as f64, 0.0, f32::MAX as f64)?;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alDopplerVelocity(value: ALfloat) {
+	with_context((), |ctx, _| {
+		if !(value > 0.0) {
+			return Err(AL_INVALID_VALUE);
+		}
+		ctx.doppler_velocity = value;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alSpeedOfSound(value: ALfloat) {
+	with_context((), |ctx, _| {
+		if !(value > 0.0) {
+			return Err(AL_INVALID_VALUE);
+		}
+		ctx.speed_of_sound = value;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alDistanceModel(distanceModel: ALenum) {
+	with_context((), |ctx, _| {
+		match distanceModel {
+			AL_NONE | AL_INVERSE_DISTANCE | AL_INVERSE_DISTANCE_CLAMPED | AL_LINEAR_DISTANCE | AL_LINEAR_DISTANCE_CLAMPED
+				| AL_EXPONENT_DISTANCE | AL_EXPONENT_DISTANCE_CLAMPED => ctx.distance_model = distanceModel,
+			_ => return Err(AL_INVALID_VALUE),
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alEnable(_capability: ALenum) {
+	set_al_error(AL_INVALID_ENUM)
+}
+
+
+unsafe extern "C" fn alDisable(_capability: ALenum) {
+	set_al_error(AL_INVALID_ENUM)
+}
+
+
+unsafe extern "C" fn alIsEnabled(_capability: ALenum) -> ALboolean {
+	set_al_error(AL_INVALID_ENUM);
+	AL_FALSE
+}
+
+
+unsafe extern "C" fn alGetString(param: ALenum) -> *const ALchar {
+	let s: &'static [u8] = match param {
+		AL_NO_ERROR => b"No Error\0",
+		AL_INVALID_NAME => b"Invalid Name\0",
+		AL_INVALID_ENUM => b"Invalid Enum\0",
+		AL_INVALID_VALUE => b"Invalid Value\0",
+		AL_INVALID_OPERATION => b"Invalid Operation\0",
+		AL_OUT_OF_MEMORY => b"Out of Memory\0",
+		AL_VENDOR => b"alto\0",
+		AL_VERSION => b"1.1 alto software\0",
+		AL_RENDERER => b"Software\0",
+		AL_EXTENSIONS => with_context(AL_EXTENSIONS_STR, |_, dev| Ok(dev.al_extensions)),
+		_ => {
+			set_al_error(AL_INVALID_ENUM);
+			return ptr::null();
+		},
+	};
+
+	s.as_ptr() as *const ALchar
+}
+
+
+fn get_global(param: ALenum) -> Option<f64> {
+	with_context(None, |ctx, _| ctx.get(param).map(Some))
+}
+
+
+unsafe extern "C" fn alGetBooleanv(param: ALenum, values: *mut ALboolean) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = get_global(param) { *values = as_bool(v); }
+}
+
+
+unsafe extern "C" fn alGetIntegerv(param: ALenum, values: *mut ALint) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = get_global(param) { *values = v as ALint; }
+}
+
+
+unsafe extern "C" fn alGetFloatv(param: ALenum, values: *mut ALfloat) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = get_global(param) { *values = v as ALfloat; }
+}
+
+
+unsafe extern "C" fn alGetDoublev(param: ALenum, values: *mut ALdouble) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = get_global(param) { *values = v; }
+}
+
+
+unsafe extern "C" fn alGetBoolean(param: ALenum) -> ALboolean {
+	get_global(param).map(as_bool).unwrap_or(AL_FALSE)
+}
+
+
+unsafe extern "C" fn alGetInteger(param: ALenum) -> ALint {
+	get_global(param).map(|v| v as ALint).unwrap_or(0)
+}
+
+
+unsafe extern "C" fn alGetFloat(param: ALenum) -> ALfloat {
+	get_global(param).map(|v| v as ALfloat).unwrap_or(0.0)
+}
+
+
+unsafe extern "C" fn alGetDouble(param: ALenum) -> ALdouble {
+	get_global(param).unwrap_or(0.0)
+}
+
+
+unsafe extern "C" fn alGetError() -> ALenum {
+	with_state(|state| match state.current_context().and_then(|c| state.contexts.get_mut(&c)) {
+		Some(ctx) => {
+			let e = ctx.error;
+			ctx.error = AL_NO_ERROR;
+			e
+		},
+		None => AL_INVALID_OPERATION,
+	})
+}
+
+
+unsafe extern "C" fn alIsExtensionPresent(extname: *const ALchar) -> ALboolean {
+	with_context(AL_FALSE, |_, dev| {
+		if extname == ptr::null() {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		Ok(if find_extension(dev.al_extensions, extname) { AL_TRUE } else { AL_FALSE })
+	})
+}
+
+
+unsafe extern "C" fn alGetProcAddress(fname: *const ALchar) -> *mut c_void {
+	if fname == ptr::null() {
+		return ptr::null_mut();
+	}
+
+	match CStr::from_ptr(fname).to_bytes() {
+		b"alBufferSubDataSOFT" => alBufferSubDataSOFT as *mut c_void,
+		b"alBufferCallbackSOFT" => alBufferCallbackSOFT as *mut c_void,
+		b"alGetBufferPtrSOFT" => alGetBufferPtrSOFT as *mut c_void,
+		b"alGetBuffer3PtrSOFT" => alGetBuffer3PtrSOFT as *mut c_void,
+		b"alGetBufferPtrvSOFT" => alGetBufferPtrvSOFT as *mut c_void,
+		b"alEventControlSOFT" => alEventControlSOFT as *mut c_void,
+		b"alEventCallbackSOFT" => alEventCallbackSOFT as *mut c_void,
+		b"alGetPointerSOFT" => alGetPointerSOFT as *mut c_void,
+		b"alGetPointervSOFT" => alGetPointervSOFT as *mut c_void,
+		b"alSourcePlayAtTimeSOFT" => alSourcePlayAtTimeSOFT as *mut c_void,
+		b"alSourcePlayAtTimevSOFT" => alSourcePlayAtTimevSOFT as *mut c_void,
+		_ => ptr::null_mut(),
+	}
+}
+
+
+unsafe extern "C" fn alGetEnumValue(ename: *const ALchar) -> ALenum {
+	if ename == ptr::null() {
+		return 0;
+	}
+
+	let name = CStr::from_ptr(ename).to_bytes();
+	AL_ENUMS.iter().chain(EFX_ENUMS).find(|&&(n, _)| n == name).map(|&(_, e)| e).unwrap_or(0)
+}
+
+
+fn listener_set(param: ALenum, values: &[f64]) {
+	with_context((), |ctx, _| ctx.listener.set(param, values))
+}
+
+
+fn listener_get(param: ALenum, arity: Option<usize>) -> Option<Vec<f64>> {
+	with_context(None, |ctx, _| {
+		let values = ctx.listener.get(param)?;
+		if arity.map(|n| n != values.len()).unwrap_or(false) {
+			return Err(AL_INVALID_ENUM);
+		}
+		Ok(Some(values))
+	})
+}
+
+
+unsafe extern "C" fn alListenerf(param: ALenum, value: ALfloat) {
+	listener_set(param, &[value as f64])
+}
+
+
+unsafe extern "C" fn alListener3f(param: ALenum, value1: ALfloat, value2: ALfloat, value3: ALfloat) {
+	listener_set(param, &[value1 as f64, value2 as f64, value3 as f64])
+}
+
+
+unsafe extern "C" fn alListenerfv(param: ALenum, values: *const ALfloat) {
+	if values == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	listener_set(param, &read_values(values, listener_arity(param)))
+}
+
+
+unsafe extern "C" fn alListeneri(param: ALenum, value: ALint) {
+	listener_set(param, &[value as f64])
+}
+
+
+unsafe extern "C" fn alListener3i(param: ALenum, value1: ALint, value2: ALint, value3: ALint) {
+	listener_set(param, &[value1 as f64, value2 as f64, value3 as f64])
+}
+
+
+unsafe extern "C" fn alListeneriv(param: ALenum, values: *const ALint) {
+	if values == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	listener_set(param, &read_values(values, listener_arity(param)))
+}
+
+
+unsafe extern "C" fn alGetListenerf(param: ALenum, value: *mut ALfloat) {
+	if value == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = listener_get(param, Some(1)) { *value = v[0] as ALfloat; }
+}
+
+
+unsafe extern "C" fn alGetListener3f(param: ALenum, value1: *mut ALfloat, value2: *mut ALfloat, value3: *mut ALfloat) {
+	if value1 == ptr::null_mut() || value2 == ptr::null_mut() || value3 == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = listener_get(param, Some(3)) {
+		*value1 = v[0] as ALfloat;
+		*value2 = v[1] as ALfloat;
+		*value3 = v[2] as ALfloat;
+	}
+}
+
+
+unsafe extern "C" fn alGetListenerfv(param: ALenum, values: *mut ALfloat) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = listener_get(param, None) { write_values(values, &v, |v| v as ALfloat); }
+}
+
+
+unsafe extern "C" fn alGetListeneri(param: ALenum, value: *mut ALint) {
+	if value == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = listener_get(param, Some(1)) { *value = v[0] as ALint; }
+}
+
+
+unsafe extern "C" fn alGetListener3i(param: ALenum, value1: *mut ALint, value2: *mut ALint, value3: *mut ALint) {
+	if value1 == ptr::null_mut() || value2 == ptr::null_mut() || value3 == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = listener_get(param, Some(3)) {
+		*value1 = v[0] as ALint;
+		*value2 = v[1] as ALint;
+		*value3 = v[2] as ALint;
+	}
+}
+
+
+unsafe extern "C" fn alGetListeneriv(param: ALenum, values: *mut ALint) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = listener_get(param, None) { write_values(values, &v, |v| v as ALint); }
+}
+
+
+unsafe extern "C" fn alGenSources(n: ALsizei, sources: *mut ALuint) {
+	with_context((), |ctx, _| {
+		if n < 0 || (sources == ptr::null_mut() && n > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		for i in 0 .. n as isize {
+			ctx.next_source += 1;
+			ctx.sources.insert(ctx.next_source, Source::new());
+			*sources.offset(i) = ctx.next_source;
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alDeleteSources(n: ALsizei, sources: *const ALuint) {
+	with_context((), |ctx, _| {
+		if n < 0 || (sources == ptr::null() && n > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		let sources = raw_slice(sources, n);
+		if sources.iter().any(|s| !ctx.sources.contains_key(s)) {
+			return Err(AL_INVALID_NAME);
+		}
+		for s in sources {
+			ctx.sources.remove(s);
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alIsSource(source: ALuint) -> ALboolean {
+	with_context(AL_FALSE, |ctx, _| Ok(if ctx.sources.contains_key(&source) { AL_TRUE } else { AL_FALSE }))
+}
+
+
+fn source_set(source: ALuint, param: ALenum, values: &[f64]) {
+	with_context((), |ctx, dev| match param {
+		AL_DIRECT_FILTER | AL_AUXILIARY_SEND_FILTER => ctx.set_source_filter(&dev.filters, source, param, values),
+		_ => ctx.sources.get_mut(&source).ok_or(AL_INVALID_NAME)?.set(&dev.buffers, param, values),
+	})
+}
+
+
+fn source_get(source: ALuint, param: ALenum, arity: Option<usize>) -> Option<Vec<f64>> {
+	with_context(None, |ctx, dev| {
+		let values = ctx.sources.get(&source).ok_or(AL_INVALID_NAME)?.get(&dev.buffers, param)?;
+		if arity.map(|n| n != values.len()).unwrap_or(false) {
+			return Err(AL_INVALID_ENUM);
+		}
+		Ok(Some(values))
+	})
+}
+
+
+unsafe extern "C" fn alSourcef(source: ALuint, param: ALenum, value: ALfloat) {
+	source_set(source, param, &[value as f64])
+}
+
+
+unsafe extern "C" fn alSource3f(source: ALuint, param: ALenum, value1: ALfloat, value2: ALfloat, value3: ALfloat) {
+	source_set(source, param, &[value1 as f64, value2 as f64, value3 as f64])
+}
+
+
+unsafe extern "C" fn alSourcefv(source: ALuint, param: ALenum, values: *const ALfloat) {
+	if values == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	source_set(source, param, &read_values(values, source_arity(param)))
+}
+
+
+unsafe extern "C" fn alSourcei(source: ALuint, param: ALenum, value: ALint) {
+	// Buffer names are unsigned, but are passed through the signed parameter.
+	let value = if param == AL_BUFFER { value as ALuint as f64 } else { value as f64 };
+	source_set(source, param, &[value])
+}
+
+
+unsafe extern "C" fn alSource3i(source: ALuint, param: ALenum, value1: ALint, value2: ALint, value3: ALint) {
+	source_set(source, param, &[value1 as f64, value2 as f64, value3 as f64])
+}
+
+
+unsafe extern "C" fn alSourceiv(source: ALuint, param: ALenum, values: *const ALint) {
+	if values == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	if param == AL_BUFFER {
+		alSourcei(source, param, *values)
+	} else {
+		source_set(source, param, &read_values(values, source_arity(param)))
+	}
+}
+
+
+unsafe extern "C" fn alGetSourcef(source: ALuint, param: ALenum, value: *mut ALfloat) {
+	if value == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = source_get(source, param, Some(1)) { *value = v[0] as ALfloat; }
+}
+
+
+unsafe extern "C" fn alGetSource3f(source: ALuint, param: ALenum, value1: *mut ALfloat, value2: *mut ALfloat, value3: *mut ALfloat) {
+	if value1 == ptr::null_mut() || value2 == ptr::null_mut() || value3 == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = source_get(source, param, Some(3)) {
+		*value1 = v[0] as ALfloat;
+		*value2 = v[1] as ALfloat;
+		*value3 = v[2] as ALfloat;
+	}
+}
+
+
+unsafe extern "C" fn alGetSourcefv(source: ALuint, param: ALenum, values: *mut ALfloat) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = source_get(source, param, None) { write_values(values, &v, |v| v as ALfloat); }
+}
+
+
+unsafe extern "C" fn alGetSourcei(source: ALuint, param: ALenum, value: *mut ALint) {
+	if value == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = source_get(source, param, Some(1)) { *value = as_int(v[0]); }
+}
+
+
+unsafe extern "C" fn alGetSource3i(source: ALuint, param: ALenum, value1: *mut ALint, value2: *mut ALint, value3: *mut ALint) {
+	if value1 == ptr::null_mut() || value2 == ptr::null_mut() || value3 == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = source_get(source, param, Some(3)) {
+		*value1 = v[0] as ALint;
+		*value2 = v[1] as ALint;
+		*value3 = v[2] as ALint;
+	}
+}
+
+
+unsafe extern "C" fn alGetSourceiv(source: ALuint, param: ALenum, values: *mut ALint) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = source_get(source, param, None) { write_values(values, &v, as_int); }
+}
+
+
+unsafe fn source_control<F: Fn(&mut Source, &Device)>(n: ALsizei, sources: *const ALuint, f: F) {
+	with_context((), |ctx, dev| {
+		if n < 0 || (sources == ptr::null() && n > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		let sources = raw_slice(sources, n);
+		if sources.iter().any(|s| !ctx.sources.contains_key(s)) {
+			return Err(AL_INVALID_NAME);
+		}
+		for s in sources {
+			f(ctx.sources.get_mut(s).unwrap(), dev);
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alSourcePlayv(n: ALsizei, sources: *const ALuint) {
+	source_control(n, sources, |s, d| s.play(d))
+}
+
+
+unsafe extern "C" fn alSourceStopv(n: ALsizei, sources: *const ALuint) {
+	source_control(n, sources, |s, _| s.stop())
+}
+
+
+unsafe extern "C" fn alSourceRewindv(n: ALsizei, sources: *const ALuint) {
+	source_control(n, sources, |s, _| s.rewind())
+}
+
+
+unsafe extern "C" fn alSourcePausev(n: ALsizei, sources: *const ALuint) {
+	source_control(n, sources, |s, _| s.pause())
+}
+
+
+unsafe extern "C" fn alSourcePlayAtTimevSOFT(n: ALsizei, sources: *const ALuint, start_time: i64) {
+	if start_time <= 0 {
+		return set_al_error(AL_INVALID_VALUE);
+	}
+	source_control(n, sources, |s, d| {
+		s.play(d);
+		if s.state == AL_PLAYING {
+			s.start_time = Some(start_time as u64);
+		}
+	})
+}
+
+
+unsafe extern "C" fn alSourcePlayAtTimeSOFT(source: ALuint, start_time: i64) {
+	alSourcePlayAtTimevSOFT(1, &source, start_time)
+}
+
+
+unsafe extern "C" fn alSourcePlay(source: ALuint) {
+	alSourcePlayv(1, &source)
+}
+
+
+unsafe extern "C" fn alSourceStop(source: ALuint) {
+	alSourceStopv(1, &source)
+}
+
+
+unsafe extern "C" fn alSourceRewind(source: ALuint) {
+	alSourceRewindv(1, &source)
+}
+
+
+unsafe extern "C" fn alSourcePause(source: ALuint) {
+	alSourcePausev(1, &source)
+}
+
+
+unsafe extern "C" fn alSourceQueueBuffers(source: ALuint, nb: ALsizei, buffers: *const ALuint) {
+	with_context((), |ctx, dev| {
+		let src = ctx.sources.get_mut(&source).ok_or(AL_INVALID_NAME)?;
+		if nb < 0 || (buffers == ptr::null() && nb > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+		if src.kind == AL_STATIC {
+			return Err(AL_INVALID_OPERATION);
+		}
+
+		let buffers = raw_slice(buffers, nb);
+		if buffers.iter().any(|b| !dev.buffers.contains_key(b)) {
+			return Err(AL_INVALID_NAME);
+		}
+
+		// Every buffer in a queue must share the same format.
+		let format = |b: &ALuint| { let b = &dev.buffers[b]; (b.channels, b.bits, b.frequency) };
+		if let Some(first) = src.queue.first().or(buffers.first()).map(&format) {
+			if buffers.iter().any(|b| format(b) != first) {
+				return Err(AL_INVALID_OPERATION);
+			}
+		}
+
+		src.queue.extend_from_slice(buffers);
+		src.kind = AL_STREAMING;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alSourceUnqueueBuffers(source: ALuint, nb: ALsizei, buffers: *mut ALuint) {
+	with_context((), |ctx, _| {
+		let src = ctx.sources.get_mut(&source).ok_or(AL_INVALID_NAME)?;
+		if nb < 0 || (buffers == ptr::null_mut() && nb > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+		if nb == 0 {
+			return Ok(());
+		}
+		if src.kind != AL_STREAMING || src.looping || nb as usize > src.processed() {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		for (i, b) in src.queue.drain(.. nb as usize).enumerate() {
+			*buffers.offset(i as isize) = b;
+		}
+		src.current = src.current.saturating_sub(nb as usize);
+		Ok(())
+	})
+}
+
+
+fn buffer_in_use(state: &State, dev: usize, buffer: ALuint) -> bool {
+	state.contexts.values()
+		.filter(|c| c.device == dev)
+		.flat_map(|c| c.sources.values())
+		.any(|s| s.queue.contains(&buffer))
+}
+
+
+unsafe extern "C" fn alGenBuffers(n: ALsizei, buffers: *mut ALuint) {
+	with_context((), |_, dev| {
+		if n < 0 || (buffers == ptr::null_mut() && n > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		for i in 0 .. n as isize {
+			dev.next_buffer += 1;
+			dev.buffers.insert(dev.next_buffer, Buffer::new());
+			*buffers.offset(i) = dev.next_buffer;
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alDeleteBuffers(n: ALsizei, buffers: *const ALuint) {
+	with_current((), |state, _, dev| {
+		if n < 0 || (buffers == ptr::null() && n > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		let buffers = raw_slice(buffers, n);
+		if buffers.iter().any(|b| *b != 0 && !state.devices[&dev].buffers.contains_key(b)) {
+			return Err(AL_INVALID_NAME);
+		}
+		if buffers.iter().any(|&b| buffer_in_use(state, dev, b)) {
+			return Err(AL_INVALID_OPERATION);
+		}
+
+		let dev = state.devices.get_mut(&dev).unwrap();
+		for b in buffers {
+			dev.buffers.remove(b);
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alIsBuffer(buffer: ALuint) -> ALboolean {
+	with_context(AL_FALSE, |_, dev| Ok(if buffer == 0 || dev.buffers.contains_key(&buffer) { AL_TRUE } else { AL_FALSE }))
+}
+
+
+unsafe extern "C" fn alBufferData(buffer: ALuint, format: ALenum, data: *const ALvoid, size: ALsizei, freq: ALsizei) {
+	with_current((), |state, _, dev| {
+		if !state.devices[&dev].buffers.contains_key(&buffer) {
+			return Err(AL_INVALID_NAME);
+		}
+		if buffer_in_use(state, dev, buffer) {
+			return Err(AL_INVALID_OPERATION);
+		}
+		let (channels, bits) = buffer_format(format).ok_or(AL_INVALID_ENUM)?;
+		if freq <= 0 || size < 0 || (data == ptr::null() && size > 0) || size as usize % (channels * bits as usize / 8) != 0 {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		let bytes = raw_slice(data as *const u8, size);
+		let buf = state.devices.get_mut(&dev).unwrap().buffers.get_mut(&buffer).unwrap();
+		*buf = Buffer{
+			channels: channels,
+			bits: bits,
+			frequency: freq,
+			size: size,
+			samples: decode(bits, bytes),
+			loop_points: None,
+			callback: None,
+		};
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alBufferSubDataSOFT(buffer: ALuint, format: ALenum, data: *const ALvoid, offset: ALsizei, length: ALsizei) {
+	with_current((), |state, _, dev| {
+		let buf = state.devices.get_mut(&dev).unwrap().buffers.get_mut(&buffer).ok_or(AL_INVALID_NAME)?;
+		let (channels, bits) = buffer_format(format).ok_or(AL_INVALID_ENUM)?;
+		if channels != buf.channels || bits != buf.bits {
+			return Err(AL_INVALID_ENUM);
+		}
+		let frame = (channels * bits as usize / 8) as ALsizei;
+		if offset < 0 || length < 0 || offset % frame != 0 || length % frame != 0 || offset > buf.size - length || (data == ptr::null() && length > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		let start = (offset / (bits / 8)) as usize;
+		let samples = decode(bits, raw_slice(data as *const u8, length));
+		buf.samples[start .. start + samples.len()].copy_from_slice(&samples);
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alBufferCallbackSOFT(buffer: ALuint, format: ALenum, freq: ALsizei, callback: Option<BufferCallbackProc>, user_param: *mut c_void) {
+	with_current((), |state, _, dev| {
+		if !state.devices[&dev].buffers.contains_key(&buffer) {
+			return Err(AL_INVALID_NAME);
+		}
+		if buffer_in_use(state, dev, buffer) {
+			return Err(AL_INVALID_OPERATION);
+		}
+		let (channels, bits) = buffer_format(format).ok_or(AL_INVALID_ENUM)?;
+		let callback = callback.ok_or(AL_INVALID_VALUE)?;
+		if freq <= 0 {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		let buf = state.devices.get_mut(&dev).unwrap().buffers.get_mut(&buffer).unwrap();
+		*buf = Buffer{
+			channels: channels,
+			bits: bits,
+			frequency: freq,
+			size: 0,
+			samples: Vec::new(),
+			loop_points: None,
+			callback: Some(BufferCallback{callback: callback, param: user_param as usize, ended: false}),
+		};
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alGetBufferPtrSOFT(buffer: ALuint, param: ALenum, value: *mut *mut c_void) {
+	with_context((), |_, dev| {
+		let buf = dev.buffers.get(&buffer).ok_or(AL_INVALID_NAME)?;
+		if value == ptr::null_mut() {
+			return Err(AL_INVALID_VALUE);
+		}
+
+		*value = match (param, &buf.callback) {
+			(AL_BUFFER_CALLBACK_FUNCTION_SOFT, &Some(ref cb)) => cb.callback as *mut c_void,
+			(AL_BUFFER_CALLBACK_USER_PARAM_SOFT, &Some(ref cb)) => cb.param as *mut c_void,
+			(AL_BUFFER_CALLBACK_FUNCTION_SOFT, &None) | (AL_BUFFER_CALLBACK_USER_PARAM_SOFT, &None) => ptr::null_mut(),
+			_ => return Err(AL_INVALID_ENUM),
+		};
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alGetBuffer3PtrSOFT(buffer: ALuint, _param: ALenum, _value1: *mut *mut c_void, _value2: *mut *mut c_void, _value3: *mut *mut c_void) {
+	with_context((), |_, dev| {
+		dev.buffers.get(&buffer).ok_or(AL_INVALID_NAME)?;
+		Err(AL_INVALID_ENUM)
+	})
+}
+
+
+unsafe extern "C" fn alGetBufferPtrvSOFT(buffer: ALuint, param: ALenum, values: *mut *mut c_void) {
+	alGetBufferPtrSOFT(buffer, param, values)
+}
+
+
+unsafe extern "C" fn alEventControlSOFT(count: ALsizei, types: *const ALenum, enable: ALboolean) {
+	with_context((), |ctx, _| {
+		if count < 0 || (types == ptr::null() && count > 0) {
+			return Err(AL_INVALID_VALUE);
+		}
+		let types = raw_slice(types, count);
+		if types.iter().any(|&t| t != AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT && t != AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT && t != AL_EVENT_TYPE_DISCONNECTED_SOFT) {
+			return Err(AL_INVALID_ENUM);
+		}
+
+		ctx.events_enabled.retain(|t| !types.contains(t));
+		if enable != AL_FALSE {
+			ctx.events_enabled.extend_from_slice(types);
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alEventCallbackSOFT(callback: Option<EventProc>, user_param: *mut c_void) {
+	with_context((), |ctx, _| {
+		ctx.event_callback = callback;
+		ctx.event_param = user_param as usize;
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alGetPointerSOFT(pname: ALenum) -> *mut c_void {
+	with_context(ptr::null_mut(), |ctx, _| match pname {
+		AL_EVENT_CALLBACK_FUNCTION_SOFT => Ok(ctx.event_callback.map(|c| c as *mut c_void).unwrap_or(ptr::null_mut())),
+		AL_EVENT_CALLBACK_USER_PARAM_SOFT => Ok(ctx.event_param as *mut c_void),
+		_ => Err(AL_INVALID_ENUM),
+	})
+}
+
+
+unsafe extern "C" fn alGetPointervSOFT(pname: ALenum, values: *mut *mut c_void) {
+	if values == ptr::null_mut() {
+		return set_al_error(AL_INVALID_VALUE);
+	}
+	*values = alGetPointerSOFT(pname);
+}
+
+
+fn buffer_set(buffer: ALuint) {
+	with_context((), |_, dev| {
+		if !dev.buffers.contains_key(&buffer) {
+			return Err(AL_INVALID_NAME);
+		}
+		// None of the core buffer properties are writable.
+		Err(AL_INVALID_ENUM)
+	})
+}
+
+
+fn buffer_get(buffer: ALuint, param: ALenum, arity: usize) -> Option<ALint> {
+	with_context(None, |_, dev| {
+		let buf = dev.buffers.get(&buffer).ok_or(AL_INVALID_NAME)?;
+		if arity == 0 || arity != buffer_arity(param) {
+			return Err(AL_INVALID_ENUM);
+		}
+
+		Ok(Some(match param {
+			AL_FREQUENCY => buf.frequency,
+			AL_BITS => buf.bits,
+			AL_CHANNELS => buf.channels as ALint,
+			_ => buf.size,
+		}))
+	})
+}
+
+
+unsafe extern "C" fn alBufferf(buffer: ALuint, _param: ALenum, _value: ALfloat) {
+	buffer_set(buffer)
+}
+
+
+unsafe extern "C" fn alBuffer3f(buffer: ALuint, _param: ALenum, _value1: ALfloat, _value2: ALfloat, _value3: ALfloat) {
+	buffer_set(buffer)
+}
+
+
+unsafe extern "C" fn alBufferfv(buffer: ALuint, _param: ALenum, values: *const ALfloat) {
+	if values == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	buffer_set(buffer)
+}
+
+
+unsafe extern "C" fn alBufferi(buffer: ALuint, _param: ALenum, _value: ALint) {
+	buffer_set(buffer)
+}
+
+
+unsafe extern "C" fn alBuffer3i(buffer: ALuint, _param: ALenum, _value1: ALint, _value2: ALint, _value3: ALint) {
+	buffer_set(buffer)
+}
+
+
+unsafe extern "C" fn alBufferiv(buffer: ALuint, param: ALenum, values: *const ALint) {
+	if values == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	if param != AL_LOOP_POINTS_SOFT { return buffer_set(buffer); }
+
+	with_current((), |state, _, dev| {
+		if !state.devices[&dev].buffers.contains_key(&buffer) {
+			return Err(AL_INVALID_NAME);
+		}
+		if buffer_in_use(state, dev, buffer) {
+			return Err(AL_INVALID_OPERATION);
+		}
+
+		let buf = state.devices.get_mut(&dev).unwrap().buffers.get_mut(&buffer).unwrap();
+		let (start, end) = (*values, *values.offset(1));
+		if buf.callback.is_some() || start < 0 || start >= end || end as usize > buf.frames() {
+			return Err(AL_INVALID_VALUE);
+		}
+		buf.loop_points = Some((start as usize, end as usize));
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alGetBufferf(buffer: ALuint, param: ALenum, value: *mut ALfloat) {
+	if value == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	// All of the core buffer properties are integers.
+	buffer_get(buffer, param, 0);
+}
+
+
+unsafe extern "C" fn alGetBuffer3f(buffer: ALuint, param: ALenum, value1: *mut ALfloat, value2: *mut ALfloat, value3: *mut ALfloat) {
+	if value1 == ptr::null_mut() || value2 == ptr::null_mut() || value3 == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	buffer_get(buffer, param, 3);
+}
+
+
+unsafe extern "C" fn alGetBufferfv(buffer: ALuint, param: ALenum, values: *mut ALfloat) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	buffer_get(buffer, param, 0);
+}
+
+
+unsafe extern "C" fn alGetBufferi(buffer: ALuint, param: ALenum, value: *mut ALint) {
+	if value == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = buffer_get(buffer, param, 1) { *value = v; }
+}
+
+
+unsafe extern "C" fn alGetBuffer3i(buffer: ALuint, param: ALenum, value1: *mut ALint, value2: *mut ALint, value3: *mut ALint) {
+	if value1 == ptr::null_mut() || value2 == ptr::null_mut() || value3 == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	buffer_get(buffer, param, 3);
+}
+
+
+unsafe extern "C" fn alGetBufferiv(buffer: ALuint, param: ALenum, values: *mut ALint) {
+	if values == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if param == AL_LOOP_POINTS_SOFT {
+		return with_context((), |_, dev| {
+			let buf = dev.buffers.get(&buffer).ok_or(AL_INVALID_NAME)?;
+			let (start, end) = buf.loop_points.unwrap_or((0, buf.frames()));
+			*values = start as ALint;
+			*values.offset(1) = end as ALint;
+			Ok(())
+		});
+	}
+	if let Some(v) = buffer_get(buffer, param, 1) { *values = v; }
+}
+
+
+/// Create `n` objects with `new`, writing their names to `names`.
+unsafe fn gen_objects<T, F: Fn() -> T>(objects: &mut HashMap<ALuint, T>, next: &mut ALuint, n: ALsizei, names: *mut ALuint, new: F) -> Result<(), ALenum> {
+	if n < 0 || (names == ptr::null_mut() && n > 0) {
+		return Err(AL_INVALID_VALUE);
+	}
+
+	for i in 0 .. n as isize {
+		*next += 1;
+		objects.insert(*next, new());
+		*names.offset(i) = *next;
+	}
+	Ok(())
+}
+
+
+/// Delete the named objects, ignoring 0, if they all exist.
+unsafe fn delete_objects<T>(objects: &mut HashMap<ALuint, T>, n: ALsizei, names: *const ALuint) -> Result<(), ALenum> {
+	if n < 0 || (names == ptr::null() && n > 0) {
+		return Err(AL_INVALID_VALUE);
+	}
+
+	let names = raw_slice(names, n);
+	if names.iter().any(|o| *o != 0 && !objects.contains_key(o)) {
+		return Err(AL_INVALID_NAME);
+	}
+	for o in names {
+		objects.remove(o);
+	}
+	Ok(())
+}
+
+
+unsafe extern "C" fn alGenAuxiliaryEffectSlots(n: ALsizei, auxiliaryeffectslots: *mut ALuint) {
+	with_context((), |ctx, _| gen_objects(&mut ctx.slots, &mut ctx.next_slot, n, auxiliaryeffectslots, AuxEffectSlot::new))
+}
+
+
+unsafe extern "C" fn alDeleteAuxiliaryEffectSlots(n: ALsizei, auxiliaryeffectslots: *const ALuint) {
+	with_context((), |ctx, _| {
+		delete_objects(&mut ctx.slots, n, auxiliaryeffectslots)?;
+
+		// Rather than refusing to delete a slot that is still fed by a source, the sends are cleared.
+		let slots = raw_slice(auxiliaryeffectslots, n);
+		for send in ctx.sources.values_mut().flat_map(|s| s.sends.iter_mut()) {
+			if send.as_ref().map(|&(slot, _)| slots.contains(&slot)).unwrap_or(false) {
+				*send = None;
+			}
+		}
+		Ok(())
+	})
+}
+
+
+unsafe extern "C" fn alIsAuxiliaryEffectSlot(auxiliaryeffectslot: ALuint) -> ALboolean {
+	with_context(AL_FALSE, |ctx, _| Ok(if ctx.slots.contains_key(&auxiliaryeffectslot) { AL_TRUE } else { AL_FALSE }))
+}
+
+
+fn slot_set(slot: ALuint, param: ALenum, value: f64) {
+	with_context((), |ctx, dev| ctx.slots.get_mut(&slot).ok_or(AL_INVALID_NAME)?.set(&dev.effects, param, value))
+}
+
+
+fn slot_get(slot: ALuint, param: ALenum) -> Option<f64> {
+	with_context(None, |ctx, _| ctx.slots.get(&slot).ok_or(AL_INVALID_NAME)?.get(param).map(Some))
+}
+
+
+unsafe extern "C" fn alAuxiliaryEffectSloti(auxiliaryeffectslot: ALuint, param: ALenum, iValue: ALint) {
+	// Effect names are unsigned, but are passed through the signed parameter.
+	let value = if param == AL_EFFECTSLOT_EFFECT { iValue as ALuint as f64 } else { iValue as f64 };
+	slot_set(auxiliaryeffectslot, param, value)
+}
+
+
+unsafe extern "C" fn alAuxiliaryEffectSlotiv(auxiliaryeffectslot: ALuint, param: ALenum, piValues: *const ALint) {
+	if piValues == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	alAuxiliaryEffectSloti(auxiliaryeffectslot, param, *piValues)
+}
+
+
+unsafe extern "C" fn alAuxiliaryEffectSlotf(auxiliaryeffectslot: ALuint, param: ALenum, flValue: ALfloat) {
+	// The loaded effect is a name, so only integer setters can change it.
+	if param == AL_EFFECTSLOT_EFFECT { return set_al_error(AL_INVALID_ENUM); }
+	slot_set(auxiliaryeffectslot, param, flValue as f64)
+}
+
+
+unsafe extern "C" fn alAuxiliaryEffectSlotfv(auxiliaryeffectslot: ALuint, param: ALenum, pflValues: *const ALfloat) {
+	if pflValues == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	alAuxiliaryEffectSlotf(auxiliaryeffectslot, param, *pflValues)
+}
+
+
+unsafe extern "C" fn alGetAuxiliaryEffectSloti(auxiliaryeffectslot: ALuint, param: ALenum, piValue: *mut ALint) {
+	if piValue == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = slot_get(auxiliaryeffectslot, param) { *piValue = as_int(v); }
+}
+
+
+unsafe extern "C" fn alGetAuxiliaryEffectSlotiv(auxiliaryeffectslot: ALuint, param: ALenum, piValues: *mut ALint) {
+	alGetAuxiliaryEffectSloti(auxiliaryeffectslot, param, piValues)
+}
+
+
+unsafe extern "C" fn alGetAuxiliaryEffectSlotf(auxiliaryeffectslot: ALuint, param: ALenum, pflValue: *mut ALfloat) {
+	if pflValue == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if param == AL_EFFECTSLOT_EFFECT { return set_al_error(AL_INVALID_ENUM); }
+	if let Some(v) = slot_get(auxiliaryeffectslot, param) { *pflValue = v as ALfloat; }
+}
+
+
+unsafe extern "C" fn alGetAuxiliaryEffectSlotfv(auxiliaryeffectslot: ALuint, param: ALenum, pflValues: *mut ALfloat) {
+	alGetAuxiliaryEffectSlotf(auxiliaryeffectslot, param, pflValues)
+}
+
+
+/// Run `f` against the effects or filters of the current device.
+fn with_efx_objects<R, F>(filters: bool, default: R, f: F) -> R where
+	F: FnOnce(&mut HashMap<ALuint, EfxObject>, &mut ALuint) -> Result<R, ALenum>,
+{
+	with_context(default, |_, dev| f(if filters { &mut dev.filters } else { &mut dev.effects }, &mut dev.next_efx_object))
+}
+
+
+/// Set a property of an effect or filter, reading as many values from `values` as the property has.
+fn efx_object_set<V: FnOnce(usize) -> Vec<f64>>(filters: bool, object: ALuint, param: ALenum, values: V) {
+	with_efx_objects(filters, (), |objects, _| {
+		let object = objects.get_mut(&object).ok_or(AL_INVALID_NAME)?;
+		let values = values(object.arity(param));
+		object.set(if filters { FILTER_TYPES } else { EFFECT_TYPES }, param, &values)
+	})
+}
+
+
+fn efx_object_get(filters: bool, object: ALuint, param: ALenum, arity: Option<usize>) -> Option<Vec<f64>> {
+	with_efx_objects(filters, None, |objects, _| {
+		let values = objects.get(&object).ok_or(AL_INVALID_NAME)?.get(param)?;
+		if arity.map(|n| n != values.len()).unwrap_or(false) {
+			return Err(AL_INVALID_ENUM);
+		}
+		Ok(Some(values))
+	})
+}
+
+
+unsafe extern "C" fn alGenEffects(n: ALsizei, effects: *mut ALuint) {
+	with_efx_objects(false, (), |objects, next| gen_objects(objects, next, n, effects, EfxObject::new))
+}
+
+
+unsafe extern "C" fn alDeleteEffects(n: ALsizei, effects: *const ALuint) {
+	with_efx_objects(false, (), |objects, _| delete_objects(objects, n, effects))
+}
+
+
+unsafe extern "C" fn alIsEffect(effect: ALuint) -> ALboolean {
+	with_efx_objects(false, AL_FALSE, |objects, _| Ok(if objects.contains_key(&effect) { AL_TRUE } else { AL_FALSE }))
+}
+
+
+unsafe extern "C" fn alEffecti(effect: ALuint, param: ALenum, iValue: ALint) {
+	efx_object_set(false, effect, param, |_| vec![iValue as f64])
+}
+
+
+unsafe extern "C" fn alEffectiv(effect: ALuint, param: ALenum, piValues: *const ALint) {
+	if piValues == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	efx_object_set(false, effect, param, |n| read_values(piValues, n))
+}
+
+
+unsafe extern "C" fn alEffectf(effect: ALuint, param: ALenum, flValue: ALfloat) {
+	efx_object_set(false, effect, param, |_| vec![flValue as f64])
+}
+
+
+unsafe extern "C" fn alEffectfv(effect: ALuint, param: ALenum, pflValues: *const ALfloat) {
+	if pflValues == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	efx_object_set(false, effect, param, |n| read_values(pflValues, n))
+}
+
+
+unsafe extern "C" fn alGetEffecti(effect: ALuint, param: ALenum, piValue: *mut ALint) {
+	if piValue == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(false, effect, param, Some(1)) { *piValue = as_int(v[0]); }
+}
+
+
+unsafe extern "C" fn alGetEffectiv(effect: ALuint, param: ALenum, piValues: *mut ALint) {
+	if piValues == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(false, effect, param, None) { write_values(piValues, &v, as_int); }
+}
+
+
+unsafe extern "C" fn alGetEffectf(effect: ALuint, param: ALenum, pflValue: *mut ALfloat) {
+	if pflValue == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(false, effect, param, Some(1)) { *pflValue = v[0] as ALfloat; }
+}
+
+
+unsafe extern "C" fn alGetEffectfv(effect: ALuint, param: ALenum, pflValues: *mut ALfloat) {
+	if pflValues == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(false, effect, param, None) { write_values(pflValues, &v, |v| v as ALfloat); }
+}
+
+
+unsafe extern "C" fn alGenFilters(n: ALsizei, filters: *mut ALuint) {
+	with_efx_objects(true, (), |objects, next| gen_objects(objects, next, n, filters, EfxObject::new))
+}
+
+
+unsafe extern "C" fn alDeleteFilters(n: ALsizei, filters: *const ALuint) {
+	with_efx_objects(true, (), |objects, _| delete_objects(objects, n, filters))
+}
+
+
+unsafe extern "C" fn alIsFilter(filter: ALuint) -> ALboolean {
+	with_efx_objects(true, AL_FALSE, |objects, _| Ok(if objects.contains_key(&filter) { AL_TRUE } else { AL_FALSE }))
+}
+
+
+unsafe extern "C" fn alFilteri(filter: ALuint, param: ALenum, iValue: ALint) {
+	efx_object_set(true, filter, param, |_| vec![iValue as f64])
+}
+
+
+unsafe extern "C" fn alFilteriv(filter: ALuint, param: ALenum, piValues: *const ALint) {
+	if piValues == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	efx_object_set(true, filter, param, |n| read_values(piValues, n))
+}
+
+
+unsafe extern "C" fn alFilterf(filter: ALuint, param: ALenum, flValue: ALfloat) {
+	efx_object_set(true, filter, param, |_| vec![flValue as f64])
+}
+
+
+unsafe extern "C" fn alFilterfv(filter: ALuint, param: ALenum, pflValues: *const ALfloat) {
+	if pflValues == ptr::null() { return set_al_error(AL_INVALID_VALUE); }
+	efx_object_set(true, filter, param, |n| read_values(pflValues, n))
+}
+
+
+unsafe extern "C" fn alGetFilteri(filter: ALuint, param: ALenum, piValue: *mut ALint) {
+	if piValue == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(true, filter, param, Some(1)) { *piValue = as_int(v[0]); }
+}
+
+
+unsafe extern "C" fn alGetFilteriv(filter: ALuint, param: ALenum, piValues: *mut ALint) {
+	if piValues == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(true, filter, param, None) { write_values(piValues, &v, as_int); }
+}
+
+
+unsafe extern "C" fn alGetFilterf(filter: ALuint, param: ALenum, pflValue: *mut ALfloat) {
+	if pflValue == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(true, filter, param, Some(1)) { *pflValue = v[0] as ALfloat; }
+}
+
+
+unsafe extern "C" fn alGetFilterfv(filter: ALuint, param: ALenum, pflValues: *mut ALfloat) {
+	if pflValues == ptr::null_mut() { return set_al_error(AL_INVALID_VALUE); }
+	if let Some(v) = efx_object_get(true, filter, param, None) { write_values(pflValues, &v, |v| v as ALfloat); }
+}
@@ -852,6 +852,21 @@ unsafe impl<F> AsBufferData<F> for [f32] where F: SampleFrame<Sample = f32> {
 		(self.as_ptr() as *const _, self.len() * mem::size_of::<f32>())
 	}
 }
+unsafe impl<F> AsBufferData<F> for [f64] where F: SampleFrame<Sample = f64> {
+	fn as_buffer_data(&self) -> (*const sys::ALvoid, usize) {
+		(self.as_ptr() as *const _, self.len() * mem::size_of::<f64>())
+	}
+}
+unsafe impl<F> AsBufferData<F> for [ALawSample] where F: SampleFrame<Sample = ALawSample> {
+	fn as_buffer_data(&self) -> (*const sys::ALvoid, usize) {
+		(self.as_ptr() as *const _, self.len() * mem::size_of::<ALawSample>())
+	}
+}
+unsafe impl<F> AsBufferData<F> for [MuLawSample] where F: SampleFrame<Sample = MuLawSample> {
+	fn as_buffer_data(&self) -> (*const sys::ALvoid, usize) {
+		(self.as_ptr() as *const _, self.len() * mem::size_of::<MuLawSample>())
+	}
+}
 unsafe impl<F, T> AsBufferData<F> for T where
 	F: SampleFrame,
 	T: Deref,
@@ -881,6 +896,21 @@ unsafe impl<F> AsBufferDataMut<F> for [f32] where F: SampleFrame<Sample = f32> {
 		(self.as_mut_ptr() as *mut _, self.len() * mem::size_of::<f32>())
 	}
 }
+unsafe impl<F> AsBufferDataMut<F> for [f64] where F: SampleFrame<Sample = f64> {
+	fn as_buffer_data_mut(&mut self) -> (*mut sys::ALvoid, usize) {
+		(self.as_mut_ptr() as *mut _, self.len() * mem::size_of::<f64>())
+	}
+}
+unsafe impl<F> AsBufferDataMut<F> for [ALawSample] where F: SampleFrame<Sample = ALawSample> {
+	fn as_buffer_data_mut(&mut self) -> (*mut sys::ALvoid, usize) {
+		(self.as_mut_ptr() as *mut _, self.len() * mem::size_of::<ALawSample>())
+	}
+}
+unsafe impl<F> AsBufferDataMut<F> for [MuLawSample] where F: SampleFrame<Sample = MuLawSample> {
+	fn as_buffer_data_mut(&mut self) -> (*mut sys::ALvoid, usize) {
+		(self.as_mut_ptr() as *mut _, self.len() * mem::size_of::<MuLawSample>())
+	}
+}
 unsafe impl<F, T> AsBufferDataMut<F> for T where
 	F: SampleFrame,
 	T: DerefMut,
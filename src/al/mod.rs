@@ -1,11 +1,15 @@
 use std::ops::Deref;
 use std::iter;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::collections::VecDeque;
 use std::mem;
 use std::ptr;
+use std::slice;
+use std::panic;
+use std::os::raw::c_void;
 use std::hash::{Hash, Hasher};
+use std::time::Duration;
 use std::ffi::{CString, CStr};
 use parking_lot::{Mutex, MutexGuard};
 
@@ -14,10 +18,13 @@ use sys;
 use alc::*;
 use efx::*;
 use ext;
+use convert::{self, ConvertFrame, Conversion};
 
 
 mod format;
 pub use self::format::*;
+mod orientation;
+pub use self::orientation::*;
 
 
 lazy_static! {
@@ -70,6 +77,10 @@ pub(crate) struct ContextInner {
 	pub ctx: *mut sys::ALCcontext,
 	pub exts: ext::AlCache,
 	defer_rc: Arc<AtomicUsize>,
+	/// Whether an `EventListener` currently owns the context's `alEventCallbackSOFT`.
+	pub(crate) native_events: AtomicBool,
+	/// Every source created from the context, pruned of dropped ones as new sources are added.
+	sources: Mutex<Vec<Weak<SourceInner>>>,
 }
 
 
@@ -85,6 +96,27 @@ pub struct Buffer {
 	ctx: Context,
 	buf: sys::ALuint, 
 	len: sys::ALsizei,
+	format: Option<Format>,
+	callback: Option<BufferCallback>,
+}
+
+
+/// A closure installed with `alBufferCallbackSOFT`, owned by the buffer it feeds.
+struct BufferCallback {
+	data: *mut c_void,
+	drop: unsafe fn(*mut c_void),
+}
+
+
+mod sealed {
+	use ::AltoResult;
+	use super::SourceGroup;
+
+
+	/// Sources whose shared state a `SourceGroup` can hold on to. Only implemented by the sources of this crate.
+	pub trait GroupMember {
+		fn add_to_group(&self, group: &mut SourceGroup) -> AltoResult<()>;
+	}
 }
 
 
@@ -336,6 +368,15 @@ pub struct StreamingSource {
 }
 
 
+/// A set of sources from one context, which are played, paused, stopped, and rewound together.
+/// Each operation is a single `alSource*v()` call, so every member changes state on the same mixer tick.
+/// The group does not keep its members alive; dropped sources are simply left out.
+pub struct SourceGroup {
+	ctx: Context,
+	srcs: Vec<Weak<SourceInner>>,
+}
+
+
 impl Context {
 	pub(crate) unsafe fn new(dev: Device, ctx: *mut sys::ALCcontext) -> Context {
 		let exts = {
@@ -348,6 +389,8 @@ impl Context {
 			ctx: ctx,
 			exts: exts,
 			defer_rc: Arc::new(AtomicUsize::new(0)),
+			native_events: AtomicBool::new(false),
+			sources: Mutex::new(Vec::new()),
 		}))
 	}
 
@@ -372,13 +415,16 @@ impl Context {
 			ext::Al::MuLawMcFormats => self.0.exts.AL_EXT_MULAW_MCFORMATS().is_ok(),
 			ext::Al::SoftBlockAlignment => self.0.exts.AL_SOFT_block_alignment().is_ok(),
 //			ext::Al::SoftBufferSamples => self.0.ext.AL_SOFT_buffer_samples().is_ok(),
-//			ext::Al::SoftBufferSubData => self.0.ext.AL_SOFT_buffer_sub_data().is_ok(),
+			ext::Al::SoftBufferSubData => self.0.exts.AL_SOFT_buffer_sub_data().is_ok(),
+			ext::Al::SoftCallbackBuffer => self.0.exts.AL_SOFT_callback_buffer().is_ok(),
 			ext::Al::SoftDeferredUpdates => self.0.exts.AL_SOFT_deferred_updates().is_ok(),
+			ext::Al::SoftEvents => self.0.exts.AL_SOFT_events().is_ok(),
 			ext::Al::SoftDirectChannels => self.0.exts.AL_SOFT_direct_channels().is_ok(),
 			ext::Al::SoftLoopPoints => self.0.exts.AL_SOFT_loop_points().is_ok(),
 			ext::Al::SoftMsadpcm => self.0.exts.AL_SOFT_MSADPCM().is_ok(),
 			ext::Al::SoftSourceLatency => self.0.exts.AL_SOFT_source_latency().is_ok(),
 			ext::Al::SoftSourceLength => self.0.exts.AL_SOFT_source_length().is_ok(),
+			ext::Al::SoftSourceStartDelay => self.0.exts.AL_SOFT_source_start_delay().is_ok(),
 			ext::Al::SourceDistanceModel => self.0.exts.AL_EXT_source_distance_model().is_ok(),
 			ext::Al::SoftSourceSpatialize => self.0.exts.AL_SOFT_source_spatialize().is_ok(),
 			ext::Al::SoftSourceResampler => self.0.exts.AL_SOFT_source_resampler().is_ok(),
@@ -389,6 +435,34 @@ impl Context {
 	}
 
 
+	/// `alGetString(AL_VENDOR)`
+	pub fn vendor(&self) -> Option<CString> { self.get_string(sys::AL_VENDOR) }
+	/// `alGetString(AL_RENDERER)`
+	pub fn renderer(&self) -> Option<CString> { self.get_string(sys::AL_RENDERER) }
+	/// `alGetString(AL_VERSION)`
+	pub fn version(&self) -> Option<CString> { self.get_string(sys::AL_VERSION) }
+
+
+	/// `alGetString(AL_EXTENSIONS)`
+	/// Every extension the context reports, including those alto doesn't recognize.
+	pub fn extensions(&self) -> Vec<CString> {
+		self.get_string(sys::AL_EXTENSIONS)
+			.map(|exts| exts.as_bytes().split(|&b| b == b' ').filter(|n| !n.is_empty()).map(|n| CString::new(n).unwrap()).collect())
+			.unwrap_or_default()
+	}
+
+
+	fn get_string(&self, param: sys::ALenum) -> Option<CString> {
+		let _lock = self.make_current(true);
+		let s = unsafe { self.0.dev.0.alto.0.api.alGetString(param) };
+		if s == ptr::null() {
+			None
+		} else {
+			unsafe { Some(CStr::from_ptr(s).to_owned()) }
+		}
+	}
+
+
 	/// `alGetInteger(AL_DISTANCE_MODEL)`
 	pub fn distance_model(&self) -> DistanceModel {
 		let _lock = self.make_current(true);
@@ -523,9 +597,11 @@ impl Context {
 		(value[0].into(), value[1].into())
 	}
 	/// `alListenerfv(AL_ORIENTATION)`
-	pub fn set_orientation<V: Into<[f32; 3]>>(&self, value: (V, V)) -> AltoResult<()> {
+	/// Accepts a pair of `at` and `up` vectors, or any rotation convertible to an `Orientation`.
+	pub fn set_orientation<O: Into<Orientation>>(&self, value: O) -> AltoResult<()> {
 		let _lock = self.make_current(true);
-		let value = [value.0.into(), value.1.into()];
+		let value = value.into();
+		let value = [value.at, value.up];
 		unsafe { self.0.dev.0.alto.0.api.alListenerfv(sys::AL_ORIENTATION, &value as *const [[f32; 3]; 2] as *const sys::ALfloat); }
 		self.get_error()
 	}
@@ -551,7 +627,7 @@ impl Context {
 
 			for i in 0 .. value {
 				unsafe {
-					let name = assr.alGetStringiSOFT?(assr.AL_RESAMPLER_NAME_SOFT?, i) as *mut _;
+					let name = self.0.dev.0.alto.0.api.alGetStringiSOFT(assr.alGetStringiSOFT?, assr.AL_RESAMPLER_NAME_SOFT?, i) as *mut _;
 					name_vec.push(CStr::from_ptr(name).to_owned());
 				}
 			}
@@ -591,6 +667,25 @@ impl Context {
 	}
 
 
+	/// `alGenBuffers()`, `alBufferCallbackSOFT()`
+	/// Create a buffer whose sample frames are pulled from `callback` as the mixer needs them.
+	/// See `Buffer::set_callback`.
+	/// Requires `AL_SOFT_callback_buffer`
+	pub fn new_callback_buffer<F, C>(&self, freq: sys::ALint, callback: C) -> AltoResult<Buffer> where
+		F: SampleFrame,
+		C: FnMut(&mut [F]) -> usize + Send + 'static,
+	{
+		Buffer::new_callback(self.clone(), freq, callback)
+	}
+
+
+	/// `alGenBuffers()`
+	/// Like `new_buffer`, but if the format of `data` is not supported, it is converted to the closest supported format.
+	pub fn new_buffer_best_effort<F: ConvertFrame>(&self, data: &[F], freq: sys::ALint) -> AltoResult<(Buffer, Conversion)> {
+		Buffer::new_best_effort(self.clone(), data, freq)
+	}
+
+
 	/// `alGenSources()`
 	pub fn new_static_source(&self) -> AltoResult<StaticSource> {
 		StaticSource::new(self.clone())
@@ -603,6 +698,25 @@ impl Context {
 	}
 
 
+	fn add_source(&self, src: &Arc<SourceInner>) {
+		let mut srcs = self.0.sources.lock();
+		srcs.retain(|s| s.strong_count() > 0);
+		srcs.push(Arc::downgrade(src));
+	}
+
+
+	/// The sources of this context that have not yet been dropped.
+	pub(crate) fn live_sources(&self) -> Vec<Arc<SourceInner>> {
+		self.0.sources.lock().iter().filter_map(|s| s.upgrade()).collect()
+	}
+
+
+	/// Create an empty group of sources from this context.
+	pub fn new_source_group(&self) -> SourceGroup {
+		SourceGroup{ctx: self.clone(), srcs: Vec::new()}
+	}
+
+
 	/// `alDeferUpdatesSOFT()`
 	/// Requires `AL_SOFT_deferred_updates`
 	pub fn defer_updates(&self) -> DeferLock {
@@ -638,7 +752,7 @@ impl Context {
 
 	fn make_raw_current(dev: &Device, ctx: *mut sys::ALCcontext) -> Option<MutexGuard<()>> {
 		if let Ok(&ext::ALC_EXT_thread_local_context{alcSetThreadContext: Ok(astc), ..}) = dev.0.alto.0.exts.ALC_EXT_thread_local_context() {
-			unsafe { astc(ctx); }
+			unsafe { dev.0.alto.0.api.alcSetThreadContext(astc, ctx); }
 			None
 		} else {
 			unsafe { dev.0.alto.0.api.alcMakeContextCurrent(ctx); }
@@ -691,7 +805,7 @@ impl<'c> DeferLock<'c> {
 			let old = ctx.0.defer_rc.fetch_add(1, Ordering::SeqCst);
 			if old == 0 {
 				let _lock = ctx.make_current(true);
-				unsafe { adus(); }
+				unsafe { ctx.0.dev.0.alto.0.api.alDeferUpdatesSOFT(adus); }
 			}
 
 			Ok(())
@@ -716,7 +830,7 @@ impl<'c> Drop for DeferLock<'c> {
 			let old = self.0.defer_rc.fetch_sub(1, Ordering::SeqCst);
 			if old == 1 {
 				let _lock = self.ctx.make_current(true);
-				unsafe { apus(); }
+				unsafe { self.0.dev.0.alto.0.api.alProcessUpdatesSOFT(apus); }
 			}
 
 			Ok(())
@@ -733,11 +847,55 @@ impl Buffer {
 			unsafe { ctx.0.dev.0.alto.0.api.alGenBuffers(1, &mut buf as *mut sys::ALuint); }
 			ctx.get_error()?;
 		}
-		let mut buf = Buffer{ctx: ctx, buf: buf, len: 0};
+		let mut buf = Buffer{ctx: ctx, buf: buf, len: 0, format: None, callback: None};
 		buf.set_data(data, freq).map(|_| buf)
 	}
 
 
+	pub(crate) fn new_callback<F, C>(ctx: Context, freq: sys::ALint, callback: C) -> AltoResult<Buffer> where
+		F: SampleFrame,
+		C: FnMut(&mut [F]) -> usize + Send + 'static,
+	{
+		ctx.0.exts.AL_SOFT_callback_buffer()?;
+
+		let mut buf = 0;
+		{
+			let _lock = ctx.make_current(true);
+			unsafe { ctx.0.dev.0.alto.0.api.alGenBuffers(1, &mut buf as *mut sys::ALuint); }
+			ctx.get_error()?;
+		}
+		let mut buf = Buffer{ctx: ctx, buf: buf, len: 0, format: None, callback: None};
+		buf.set_callback(freq, callback).map(|_| buf)
+	}
+
+
+	pub(crate) fn new_best_effort<F: ConvertFrame>(ctx: Context, data: &[F], freq: sys::ALint) -> AltoResult<(Buffer, Conversion)> {
+		let mut buf = 0;
+		{
+			let _lock = ctx.make_current(true);
+			unsafe { ctx.0.dev.0.alto.0.api.alGenBuffers(1, &mut buf as *mut sys::ALuint); }
+			ctx.get_error()?;
+		}
+		let mut buf = Buffer{ctx: ctx, buf: buf, len: 0, format: None, callback: None};
+		buf.set_data_best_effort(data, freq).map(|conv| (buf, conv))
+	}
+
+
+	pub(crate) fn new_raw(ctx: Context, format: Format, data: &[u8], len: sys::ALsizei, freq: sys::ALint, block_align: Option<sys::ALint>) -> AltoResult<Buffer> {
+		let mut buf = 0;
+		{
+			let _lock = ctx.make_current(true);
+			unsafe { ctx.0.dev.0.alto.0.api.alGenBuffers(1, &mut buf as *mut sys::ALuint); }
+			ctx.get_error()?;
+		}
+		let mut buf = Buffer{ctx: ctx, buf: buf, len: 0, format: None, callback: None};
+		if let Some(block_align) = block_align {
+			buf.set_soft_unpack_block_alignment(block_align)?;
+		}
+		buf.set_raw_data(format, data, len, freq).map(|_| buf)
+	}
+
+
 	/// Context from which this buffer was created.
 	pub fn context(&self) -> &Context { &self.ctx }
 	/// Raw handle as provided by OpenAL.
@@ -762,6 +920,95 @@ impl Buffer {
 		self.ctx.get_error()?;
 
 		self.len = (size as usize / mem::size_of::<F::Sample>() / F::len()) as sys::ALsizei;
+		self.format = Some(F::format());
+		self.callback = None;
+		Ok(())
+	}
+
+
+	/// `alBufferCallbackSOFT()`
+	/// Replace the contents of the buffer with sample frames pulled from `callback` as the mixer needs them.
+	/// The callback fills the slice it is given and returns the number of frames written;
+	/// returning fewer frames than requested marks the end of the buffer.
+	/// It runs on the mixer thread, so it should be fast and must not call into OpenAL.
+	/// The callback is owned by the buffer, and is dropped when the buffer is dropped or given new data.
+	/// Requires `AL_SOFT_callback_buffer`
+	pub fn set_callback<F, C>(&mut self, freq: sys::ALint, callback: C) -> AltoResult<()> where
+		F: SampleFrame,
+		C: FnMut(&mut [F]) -> usize + Send + 'static,
+	{
+		let abcb = self.ctx.0.exts.AL_SOFT_callback_buffer()?.alBufferCallbackSOFT?;
+		let format = F::format().into_raw(Some(&self.ctx))?;
+		let callback = BufferCallback::new(callback);
+
+		let _lock = self.ctx.make_current(true);
+		unsafe { self.ctx.0.dev.0.alto.0.api.alBufferCallbackSOFT(abcb, self.buf, format, freq, Some(buffer_callback::<F, C>), callback.data); }
+		self.ctx.get_error()?;
+
+		self.len = 0;
+		self.format = None;
+		self.callback = Some(callback);
+		Ok(())
+	}
+
+
+	/// `alBufferSubDataSOFT()`
+	/// Overwrite the sample frames starting at frame `offset` with `data`, which must be in the format the buffer was last filled with.
+	/// Unlike `set_data`, this is allowed while the buffer is attached to a source.
+	/// Requires `AL_SOFT_buffer_sub_data`
+	pub fn sub_data<F: SampleFrame, B: AsBufferData<F>>(&self, offset: usize, data: B) -> AltoResult<()> {
+		let absd = self.ctx.0.exts.AL_SOFT_buffer_sub_data()?.alBufferSubDataSOFT?;
+		let (data, size) = data.as_buffer_data();
+		if self.format != Some(F::format()) { return Err(AltoError::InvalidValue) }
+
+		let frame_size = mem::size_of::<F::Sample>() * F::len();
+		let len = size / frame_size;
+		if offset > self.len as usize || len > self.len as usize - offset { return Err(AltoError::InvalidValue) }
+
+		let _lock = self.ctx.make_current(true);
+		unsafe {
+			self.ctx.0.dev.0.alto.0.api.alBufferSubDataSOFT(
+				absd,
+				self.buf,
+				F::format().into_raw(Some(&self.ctx))?,
+				data,
+				(offset * frame_size) as sys::ALsizei,
+				size as sys::ALsizei,
+			);
+		}
+		self.ctx.get_error()
+	}
+
+
+	/// `alBufferData()`
+	/// Like `set_data`, but if the format of `data` is not supported, it is converted to the closest supported format.
+	/// Float samples fall back to 16 bits, A-law and mu-law samples are decoded, and multichannel data is mixed down to stereo.
+	/// The returned `Conversion` reports the format that was actually uploaded.
+	pub fn set_data_best_effort<F: ConvertFrame>(&mut self, data: &[F], freq: sys::ALint) -> AltoResult<Conversion> {
+		convert::set_data_best_effort(self, data, freq)
+	}
+
+
+	/// `alBufferData()` for data that has no `SampleFrame` representation, such as block compressed formats.
+	/// `len` is the number of sample frames the data decodes to.
+	pub(crate) fn set_raw_data(&mut self, format: Format, data: &[u8], len: sys::ALsizei, freq: sys::ALint) -> AltoResult<()> {
+		if (sys::ALsizei::max_value() as usize) < data.len() { return Err(AltoError::InvalidValue) }
+
+		let _lock = self.ctx.make_current(true);
+		unsafe {
+			self.ctx.0.dev.0.alto.0.api.alBufferData(
+				self.buf,
+				format.into_raw(Some(&self.ctx))?,
+				data.as_ptr() as *const sys::ALvoid,
+				data.len() as sys::ALsizei,
+				freq,
+			);
+		}
+		self.ctx.get_error()?;
+
+		self.len = len;
+		self.format = Some(format);
+		self.callback = None;
 		Ok(())
 	}
 
@@ -802,6 +1049,25 @@ impl Buffer {
 	}
 
 
+	/// `alGetBufferi(AL_UNPACK_BLOCK_ALIGNMENT_SOFT)`
+	/// Requires `AL_SOFT_block_alignment`
+	pub fn soft_unpack_block_alignment(&self) -> AltoResult<sys::ALint> {
+		let assba = self.ctx.0.exts.AL_SOFT_block_alignment()?;
+		let _lock = self.ctx.make_current(true);
+		let mut value = 0;
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetBufferi(self.buf, assba.AL_UNPACK_BLOCK_ALIGNMENT_SOFT?, &mut value); }
+		self.ctx.get_error().map(|_| value)
+	}
+	/// `alBufferi(AL_UNPACK_BLOCK_ALIGNMENT_SOFT)`
+	/// Requires `AL_SOFT_block_alignment`
+	pub fn set_soft_unpack_block_alignment(&mut self, value: sys::ALint) -> AltoResult<()> {
+		let assba = self.ctx.0.exts.AL_SOFT_block_alignment()?;
+		let _lock = self.ctx.make_current(true);
+		unsafe { self.ctx.0.dev.0.alto.0.api.alBufferi(self.buf, assba.AL_UNPACK_BLOCK_ALIGNMENT_SOFT?, value); }
+		self.ctx.get_error()
+	}
+
+
 	/// `alGetBufferiv(AL_LOOP_POINTS_SOFT)`
 	/// Requires `AL_SOFT_loop_points`
 	pub fn soft_loop_points(&self) -> (sys::ALint, sys::ALint) {
@@ -830,19 +1096,53 @@ impl Drop for Buffer {
 
 		let _lock = self.ctx.make_current(true);
 		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteBuffers(1, &mut self.buf as *mut sys::ALuint); }
+		if self.callback.is_some() && self.ctx.get_error().is_err() {
+			// The buffer still exists, so the mixer may still call the callback.
+			mem::forget(self.callback.take());
+		}
 	}
 }
 
 
-impl SourceInner {
-	fn context(&self) -> &Context { &self.ctx }
-	pub fn as_raw(&self) -> sys::ALuint { self.src }
+impl BufferCallback {
+	fn new<F: SampleFrame, C: FnMut(&mut [F]) -> usize + Send + 'static>(callback: C) -> BufferCallback {
+		unsafe fn drop_callback<C>(data: *mut c_void) {
+			drop(Box::from_raw(data as *mut C));
+		}
 
+		BufferCallback{
+			data: Box::into_raw(Box::new(callback)) as *mut c_void,
+			drop: drop_callback::<C>,
+		}
+	}
+}
 
-	fn state(&self) -> SourceState {
-		let _lock = self.ctx.make_current(true);
-		let mut value = 0;
-		unsafe { self.ctx.0.dev.0.alto.0.api.alGetSourcei(self.src, sys::AL_SOURCE_STATE, &mut value); }
+
+impl Drop for BufferCallback {
+	fn drop(&mut self) {
+		unsafe { (self.drop)(self.data); }
+	}
+}
+
+
+unsafe impl Send for BufferCallback { }
+unsafe impl Sync for BufferCallback { }
+
+
+unsafe extern "C" fn buffer_callback<F: SampleFrame, C: FnMut(&mut [F]) -> usize>(data: *mut c_void, samples: *mut c_void, len: sys::ALsizei) -> sys::ALsizei {
+	let callback = &mut *(data as *mut C);
+	let frames = slice::from_raw_parts_mut(samples as *mut F, len.max(0) as usize / mem::size_of::<F>());
+
+	// Unwinding into the mixer is undefined, so a panic ends the buffer instead.
+	match panic::catch_unwind(panic::AssertUnwindSafe(|| callback(&mut *frames))) {
+		Ok(written) => (written.min(frames.len()) * mem::size_of::<F>()) as sys::ALsizei,
+		Err(_) => 0,
+	}
+}
+
+
+impl SourceState {
+	pub(crate) fn from_raw(value: sys::ALint) -> SourceState {
 		match value {
 			sys::AL_INITIAL => SourceState::Initial,
 			sys::AL_PLAYING => SourceState::Playing,
@@ -851,6 +1151,20 @@ impl SourceInner {
 			ss => SourceState::Unknown(ss),
 		}
 	}
+}
+
+
+impl SourceInner {
+	fn context(&self) -> &Context { &self.ctx }
+	pub fn as_raw(&self) -> sys::ALuint { self.src }
+
+
+	fn state(&self) -> SourceState {
+		let _lock = self.ctx.make_current(true);
+		let mut value = 0;
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetSourcei(self.src, sys::AL_SOURCE_STATE, &mut value); }
+		SourceState::from_raw(value)
+	}
 	fn play(&self) {
 		let _lock = self.ctx.make_current(true);
 		unsafe { self.ctx.0.dev.0.alto.0.api.alSourcePlay(self.src); }
@@ -867,6 +1181,12 @@ impl SourceInner {
 		let _lock = self.ctx.make_current(true);
 		unsafe { self.ctx.0.dev.0.alto.0.api.alSourceRewind(self.src); }
 	}
+	fn play_at(&self, time: Duration) -> AltoResult<()> {
+		let apat = self.ctx.0.exts.AL_SOFT_source_start_delay()?.alSourcePlayAtTimeSOFT?;
+		let _lock = self.ctx.make_current(true);
+		unsafe { self.ctx.0.dev.0.alto.0.api.alSourcePlayAtTimeSOFT(apat, self.src, duration_to_nanos(time)); }
+		self.ctx.get_error()
+	}
 
 
 	fn relative(&self) -> bool {
@@ -1096,7 +1416,7 @@ impl SourceInner {
 		let assl = self.ctx.0.exts.AL_SOFT_source_latency()?;
 		let _lock = self.ctx.make_current(true);
 		let mut value = [0.0, 0.0];
-		unsafe { assl.alGetSourcedvSOFT?(self.src, assl.AL_SEC_OFFSET_LATENCY_SOFT?, &mut value as *mut [f64; 2] as *mut f64); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetSourcedvSOFT(assl.alGetSourcedvSOFT?, self.src, assl.AL_SEC_OFFSET_LATENCY_SOFT?, &mut value as *mut [f64; 2] as *mut f64); }
 		self.ctx.get_error().map(|_| (value[0], value[1]))
 	}
 
@@ -1105,7 +1425,7 @@ impl SourceInner {
 		let assl = self.ctx.0.exts.AL_SOFT_source_latency()?;
 		let _lock = self.ctx.make_current(true);
 		let mut value = [0, 0];
-		unsafe { assl.alGetSourcei64vSOFT?(self.src, assl.AL_SAMPLE_OFFSET_LATENCY_SOFT?, &mut value as *mut [i64; 2] as *mut i64); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetSourcei64vSOFT(assl.alGetSourcei64vSOFT?, self.src, assl.AL_SAMPLE_OFFSET_LATENCY_SOFT?, &mut value as *mut [i64; 2] as *mut i64); }
 		self.ctx.get_error().map(|_| ((value[0] >> 32) as i32, value[0] as i32, value[1]))
 	}
 
@@ -1443,7 +1763,9 @@ impl StaticSource {
 			ctx.get_error()?;
 		}
 		let sends = iter::repeat(0).take(ctx.0.dev.0.max_aux_sends() as usize).collect();
-		Ok(StaticSource{src: Arc::new(SourceInner{ctx: ctx, src: src, sends: Mutex::new(sends)}), buf: None})
+		let src = Arc::new(SourceInner{ctx: ctx, src: src, sends: Mutex::new(sends)});
+		src.ctx.add_source(&src);
+		Ok(StaticSource{src: src, buf: None})
 	}
 
 
@@ -1487,6 +1809,21 @@ impl StaticSource {
 		let _lock = self.src.ctx.make_current(true);
 		unsafe { self.src.ctx.0.dev.0.alto.0.api.alSourcei(self.src.src, sys::AL_LOOPING, if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
 	}
+
+
+	/// `alSourcePlayAtTimeSOFT()`
+	/// Start playing once the device clock, as reported by `OutputDevice::clock`, reaches `time`.
+	/// The source is playing from the moment of the call, but stays silent until then.
+	/// Without the extension this returns `ExtensionNotPresent` and leaves the source untouched;
+	/// callers that can tolerate a less precise start should fall back to `play`.
+	/// To start several sources on the same mixer tick, add them to a `SourceGroup` and use `SourceGroup::play_at`.
+	/// Requires `AL_SOFT_source_start_delay`
+	pub fn play_at(&mut self, time: Duration) -> AltoResult<()> { self.src.play_at(time) }
+}
+
+
+impl sealed::GroupMember for StaticSource {
+	#[inline] fn add_to_group(&self, group: &mut SourceGroup) -> AltoResult<()> { group.add_member(&self.src) }
 }
 
 
@@ -1617,7 +1954,9 @@ impl StreamingSource {
 			ctx.get_error()?;
 		}
 		let sends = iter::repeat(0).take(ctx.0.dev.0.max_aux_sends() as usize).collect();
-		Ok(StreamingSource{src: Arc::new(SourceInner{ctx: ctx, src: src, sends: Mutex::new(sends)}), bufs: VecDeque::new() })
+		let src = Arc::new(SourceInner{ctx: ctx, src: src, sends: Mutex::new(sends)});
+		src.ctx.add_source(&src);
+		Ok(StreamingSource{src: src, bufs: VecDeque::new() })
 	}
 
 
@@ -1663,6 +2002,17 @@ impl StreamingSource {
 
 		Ok(self.bufs.pop_front().unwrap())
 	}
+
+
+	/// `alSourcePlayAtTimeSOFT()`
+	/// Start playing the queue once the device clock reaches `time`. See `StaticSource::play_at`.
+	/// Requires `AL_SOFT_source_start_delay`
+	pub fn play_at(&mut self, time: Duration) -> AltoResult<()> { self.src.play_at(time) }
+}
+
+
+impl sealed::GroupMember for StreamingSource {
+	#[inline] fn add_to_group(&self, group: &mut SourceGroup) -> AltoResult<()> { group.add_member(&self.src) }
 }
 
 
@@ -1782,3 +2132,97 @@ impl PartialEq for StreamingSource {
 	}
 }
 impl Eq for StreamingSource { }
+
+
+impl SourceGroup {
+	/// Context from which the members were created.
+	pub fn context(&self) -> &Context { &self.ctx }
+
+
+	/// Add `src` to the group. Adding a source that is already a member has no effect.
+	pub fn add<S: Source + sealed::GroupMember>(&mut self, src: &S) -> AltoResult<()> {
+		src.add_to_group(self)
+	}
+
+
+	pub(crate) fn add_member(&mut self, src: &Arc<SourceInner>) -> AltoResult<()> {
+		if src.ctx != self.ctx {
+			return Err(AltoError::WrongContext);
+		}
+
+		self.srcs.retain(|s| s.upgrade().is_some());
+		if !self.srcs.iter().any(|s| s.upgrade().map(|s| s.src == src.src).unwrap_or(false)) {
+			self.srcs.push(Arc::downgrade(src));
+		}
+		Ok(())
+	}
+
+
+	/// Remove `src` from the group, if it is a member.
+	pub fn remove<S: Source>(&mut self, src: &S) {
+		let raw = src.as_raw();
+		self.srcs.retain(|s| s.upgrade().map(|s| s.src != raw).unwrap_or(false));
+	}
+
+
+	/// Remove every member from the group.
+	pub fn clear(&mut self) {
+		self.srcs.clear();
+	}
+
+
+	/// Number of members that are still alive.
+	pub fn len(&self) -> usize {
+		self.srcs.iter().filter(|s| s.upgrade().is_some()).count()
+	}
+
+
+	/// Whether the group has no live members.
+	pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+
+	/// `alSourcePlayv()`
+	pub fn play(&mut self) {
+		let _ = self.with_sources(|api, n, srcs| unsafe { api.alSourcePlayv(n, srcs) });
+	}
+	/// `alSourcePausev()`
+	pub fn pause(&mut self) {
+		let _ = self.with_sources(|api, n, srcs| unsafe { api.alSourcePausev(n, srcs) });
+	}
+	/// `alSourceStopv()`
+	pub fn stop(&mut self) {
+		let _ = self.with_sources(|api, n, srcs| unsafe { api.alSourceStopv(n, srcs) });
+	}
+	/// `alSourceRewindv()`
+	pub fn rewind(&mut self) {
+		let _ = self.with_sources(|api, n, srcs| unsafe { api.alSourceRewindv(n, srcs) });
+	}
+
+
+	/// `alSourcePlayAtTimevSOFT()`
+	/// Start playing every member once the device clock reaches `time`. See `StaticSource::play_at`.
+	/// Requires `AL_SOFT_source_start_delay`
+	pub fn play_at(&mut self, time: Duration) -> AltoResult<()> {
+		let apatv = self.ctx.0.exts.AL_SOFT_source_start_delay()?.alSourcePlayAtTimevSOFT?;
+		self.with_sources(|api, n, srcs| unsafe { api.alSourcePlayAtTimevSOFT(apatv, n, srcs, duration_to_nanos(time)) })
+	}
+
+
+	/// Call `f` with the names of the live members under a single context lock.
+	fn with_sources<F: FnOnce(&sys::AlApi, sys::ALsizei, *const sys::ALuint)>(&mut self, f: F) -> AltoResult<()> {
+		// Holding strong references keeps every name valid until the call returns.
+		let srcs: Vec<_> = self.srcs.iter().filter_map(|s| s.upgrade()).collect();
+		self.srcs = srcs.iter().map(Arc::downgrade).collect();
+		let names: Vec<_> = srcs.iter().map(|s| s.src).collect();
+
+		let _lock = self.ctx.make_current(true);
+		f(&self.ctx.0.dev.0.alto.0.api, names.len() as sys::ALsizei, names.as_ptr());
+		self.ctx.get_error()
+	}
+}
+
+
+/// Device clock time in nanoseconds, as expected by `AL_SOFT_source_start_delay`.
+fn duration_to_nanos(time: Duration) -> ext::ALint64SOFT {
+	time.as_nanos().min(ext::ALint64SOFT::MAX as u128) as ext::ALint64SOFT
+}
@@ -0,0 +1,204 @@
+//! Conversions from the vector and rotation types of common math libraries.
+//!
+//! Positions, velocities, and directions are accepted as anything that is `Into<[f32; 3]>`,
+//! which the `f32` vector types of mint, cgmath, nalgebra, and glam already satisfy.
+//! The conversions here build an `Orientation` from rotations and transforms, assuming the
+//! OpenAL convention of a right-handed coordinate system where an unrotated listener faces -Z with +Y up.
+
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "glam")]
+extern crate glam;
+
+
+/// The orientation of the listener, as set with `alListenerfv(AL_ORIENTATION)`.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Orientation {
+	/// The direction the listener is facing.
+	pub at: [f32; 3],
+	/// The direction that is up relative to the listener.
+	pub up: [f32; 3],
+}
+
+
+impl Orientation {
+	/// Orientation of a listener rotated by the unit quaternion `xyz + w`.
+	pub fn from_quaternion(xyz: [f32; 3], w: f32) -> Orientation {
+		Orientation{
+			at: rotate(xyz, w, [0.0, 0.0, -1.0]),
+			up: rotate(xyz, w, [0.0, 1.0, 0.0]),
+		}
+	}
+
+
+	/// Orientation of a listener rotated by the column-major matrix `m`.
+	/// The columns need not be normalized, so the upper 3x3 of a scaled transform is accepted.
+	pub fn from_matrix(m: [[f32; 3]; 3]) -> Orientation {
+		Orientation{
+			at: [-m[2][0], -m[2][1], -m[2][2]],
+			up: m[1],
+		}
+	}
+}
+
+
+impl<V: Into<[f32; 3]>> From<(V, V)> for Orientation {
+	fn from(value: (V, V)) -> Orientation {
+		Orientation{at: value.0.into(), up: value.1.into()}
+	}
+}
+
+
+impl From<[[f32; 3]; 2]> for Orientation {
+	fn from(value: [[f32; 3]; 2]) -> Orientation {
+		Orientation{at: value[0], up: value[1]}
+	}
+}
+
+
+impl From<Orientation> for ([f32; 3], [f32; 3]) {
+	fn from(value: Orientation) -> ([f32; 3], [f32; 3]) {
+		(value.at, value.up)
+	}
+}
+
+
+/// Rotate `v` by the unit quaternion `q + w`.
+fn rotate(q: [f32; 3], w: f32, v: [f32; 3]) -> [f32; 3] {
+	// v + 2w(q x v) + 2q x (q x v)
+	let t = cross(q, v);
+	let t = [2.0 * t[0], 2.0 * t[1], 2.0 * t[2]];
+	let u = cross(q, t);
+	[v[0] + w * t[0] + u[0], v[1] + w * t[1] + u[1], v[2] + w * t[2] + u[2]]
+}
+
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+	[a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+	use super::mint;
+	use super::Orientation;
+
+	impl From<mint::Quaternion<f32>> for Orientation {
+		fn from(q: mint::Quaternion<f32>) -> Orientation {
+			Orientation::from_quaternion(q.v.into(), q.s)
+		}
+	}
+	impl From<mint::ColumnMatrix3<f32>> for Orientation {
+		fn from(m: mint::ColumnMatrix3<f32>) -> Orientation {
+			Orientation::from_matrix([m.x.into(), m.y.into(), m.z.into()])
+		}
+	}
+	impl From<mint::ColumnMatrix4<f32>> for Orientation {
+		fn from(m: mint::ColumnMatrix4<f32>) -> Orientation {
+			Orientation::from_matrix([[m.x.x, m.x.y, m.x.z], [m.y.x, m.y.y, m.y.z], [m.z.x, m.z.y, m.z.z]])
+		}
+	}
+	impl From<mint::RowMatrix3<f32>> for Orientation {
+		fn from(m: mint::RowMatrix3<f32>) -> Orientation {
+			Orientation::from(mint::ColumnMatrix3::from(m))
+		}
+	}
+	impl From<mint::RowMatrix4<f32>> for Orientation {
+		fn from(m: mint::RowMatrix4<f32>) -> Orientation {
+			Orientation::from(mint::ColumnMatrix4::from(m))
+		}
+	}
+}
+
+
+#[cfg(feature = "cgmath")]
+mod cgmath_impls {
+	use super::cgmath;
+	use super::Orientation;
+
+	impl From<cgmath::Quaternion<f32>> for Orientation {
+		fn from(q: cgmath::Quaternion<f32>) -> Orientation {
+			Orientation::from_quaternion(q.v.into(), q.s)
+		}
+	}
+	impl From<cgmath::Basis3<f32>> for Orientation {
+		fn from(b: cgmath::Basis3<f32>) -> Orientation {
+			Orientation::from(*b.as_ref())
+		}
+	}
+	impl From<cgmath::Matrix3<f32>> for Orientation {
+		fn from(m: cgmath::Matrix3<f32>) -> Orientation {
+			Orientation::from_matrix([m.x.into(), m.y.into(), m.z.into()])
+		}
+	}
+	impl From<cgmath::Matrix4<f32>> for Orientation {
+		fn from(m: cgmath::Matrix4<f32>) -> Orientation {
+			Orientation::from_matrix([m.x.truncate().into(), m.y.truncate().into(), m.z.truncate().into()])
+		}
+	}
+}
+
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+	use super::nalgebra;
+	use super::Orientation;
+
+	impl From<nalgebra::UnitQuaternion<f32>> for Orientation {
+		fn from(q: nalgebra::UnitQuaternion<f32>) -> Orientation {
+			Orientation::from_quaternion([q.i, q.j, q.k], q.w)
+		}
+	}
+	impl From<nalgebra::Rotation3<f32>> for Orientation {
+		fn from(r: nalgebra::Rotation3<f32>) -> Orientation {
+			Orientation::from(*r.matrix())
+		}
+	}
+	impl From<nalgebra::Isometry3<f32>> for Orientation {
+		fn from(i: nalgebra::Isometry3<f32>) -> Orientation {
+			Orientation::from(i.rotation)
+		}
+	}
+	impl From<nalgebra::Matrix3<f32>> for Orientation {
+		fn from(m: nalgebra::Matrix3<f32>) -> Orientation {
+			Orientation::from_matrix([[m[(0, 0)], m[(1, 0)], m[(2, 0)]], [m[(0, 1)], m[(1, 1)], m[(2, 1)]], [m[(0, 2)], m[(1, 2)], m[(2, 2)]]])
+		}
+	}
+	impl From<nalgebra::Matrix4<f32>> for Orientation {
+		fn from(m: nalgebra::Matrix4<f32>) -> Orientation {
+			Orientation::from_matrix([[m[(0, 0)], m[(1, 0)], m[(2, 0)]], [m[(0, 1)], m[(1, 1)], m[(2, 1)]], [m[(0, 2)], m[(1, 2)], m[(2, 2)]]])
+		}
+	}
+}
+
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+	use super::glam;
+	use super::Orientation;
+
+	impl From<glam::Quat> for Orientation {
+		fn from(q: glam::Quat) -> Orientation {
+			Orientation::from_quaternion([q.x, q.y, q.z], q.w)
+		}
+	}
+	impl From<glam::Mat3> for Orientation {
+		fn from(m: glam::Mat3) -> Orientation {
+			Orientation::from_matrix([m.x_axis.into(), m.y_axis.into(), m.z_axis.into()])
+		}
+	}
+	impl From<glam::Mat4> for Orientation {
+		fn from(m: glam::Mat4) -> Orientation {
+			Orientation::from_matrix([m.x_axis.truncate().into(), m.y_axis.truncate().into(), m.z_axis.truncate().into()])
+		}
+	}
+	impl From<glam::Affine3A> for Orientation {
+		fn from(a: glam::Affine3A) -> Orientation {
+			Orientation::from_matrix([a.matrix3.x_axis.into(), a.matrix3.y_axis.into(), a.matrix3.z_axis.into()])
+		}
+	}
+}
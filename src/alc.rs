@@ -1,4 +1,5 @@
 use std::cmp;
+use std::fmt;
 use std::any::Any;
 use std::ptr;
 use std::mem;
@@ -6,6 +7,8 @@ use std::ffi::{CString, CStr};
 use std::sync::Arc;
 use std::path::Path;
 use std::marker::PhantomData;
+use std::time::Duration;
+use parking_lot::Mutex;
 
 use ::{AltoError, AltoResult};
 use sys;
@@ -36,6 +39,23 @@ pub struct ContextAttrs {
 	/// `ALC_MAX_AUXILIARY_SENDS`
 	/// Requires `ALC_EXT_EFX`
 	pub max_aux_sends: Option<sys::ALCint>,
+	/// `ALC_SYNC`
+	pub sync: Option<bool>,
+	/// `ALC_OUTPUT_MODE_SOFT`
+	/// Requires `ALC_SOFT_output_mode`
+	pub soft_output_mode: Option<SoftOutputMode>,
+	/// `ALC_AMBISONIC_LAYOUT_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_layout: Option<SoftAmbisonicLayout>,
+	/// `ALC_AMBISONIC_SCALING_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_scaling: Option<SoftAmbisonicScaling>,
+	/// `ALC_AMBISONIC_ORDER_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_order: Option<sys::ALCint>,
+	/// `ALC_CONTEXT_FLAGS_EXT`, a bitmask such as `ALC_CONTEXT_DEBUG_BIT_EXT`
+	/// Requires `ALC_EXT_debug`
+	pub context_flags: Option<sys::ALCint>,
 }
 
 
@@ -59,6 +79,118 @@ pub struct LoopbackAttrs {
 	/// `ALC_MAX_AUXILIARY_SENDS`
 	/// Requires `ALC_EXT_EFX`
 	pub max_aux_sends: Option<sys::ALCint>,
+	/// `ALC_AMBISONIC_LAYOUT_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_layout: Option<SoftAmbisonicLayout>,
+	/// `ALC_AMBISONIC_SCALING_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_scaling: Option<SoftAmbisonicScaling>,
+	/// `ALC_AMBISONIC_ORDER_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_order: Option<sys::ALCint>,
+	/// `ALC_CONTEXT_FLAGS_EXT`, a bitmask such as `ALC_CONTEXT_DEBUG_BIT_EXT`
+	/// Requires `ALC_EXT_debug`
+	pub context_flags: Option<sys::ALCint>,
+}
+
+
+/// Attributes in effect on a device, as granted by the implementation rather than as requested.
+/// Attributes the implementation doesn't report are `None`.
+#[derive(Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct DeviceAttrs {
+	/// `ALC_FREQUENCY`
+	pub frequency: Option<sys::ALCint>,
+	/// `ALC_REFRESH`
+	pub refresh: Option<sys::ALCint>,
+	/// `ALC_SYNC`
+	pub sync: Option<bool>,
+	/// `ALC_MONO_SOURCES`
+	pub mono_sources: Option<sys::ALCint>,
+	/// `ALC_STEREO_SOURCES`
+	pub stereo_sources: Option<sys::ALCint>,
+	/// `ALC_HRTF_SOFT`
+	/// Requires `ALC_SOFT_HRTF`
+	pub soft_hrtf: Option<bool>,
+	/// `ALC_HRTF_ID_SOFT`
+	/// Requires `ALC_SOFT_HRTF`
+	pub soft_hrtf_id: Option<sys::ALCint>,
+	/// `ALC_OUTPUT_LIMITER_SOFT`
+	/// Requires `ALC_SOFT_output_limiter`
+	pub soft_output_limiter: Option<bool>,
+	/// `ALC_MAX_AUXILIARY_SENDS`
+	/// Requires `ALC_EXT_EFX`
+	pub max_aux_sends: Option<sys::ALCint>,
+	/// `ALC_OUTPUT_MODE_SOFT`
+	/// Requires `ALC_SOFT_output_mode`
+	pub soft_output_mode: Option<SoftOutputMode>,
+	/// `ALC_AMBISONIC_LAYOUT_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_layout: Option<SoftAmbisonicLayout>,
+	/// `ALC_AMBISONIC_SCALING_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_scaling: Option<SoftAmbisonicScaling>,
+	/// `ALC_AMBISONIC_ORDER_SOFT`
+	/// Requires `ALC_SOFT_loopback_bformat`
+	pub soft_ambisonic_order: Option<sys::ALCint>,
+	/// `ALC_CONTEXT_FLAGS_EXT`
+	/// Requires `ALC_EXT_debug`
+	pub context_flags: Option<sys::ALCint>,
+	/// Every other attribute reported, such as the format of a loopback device, as raw key and value pairs.
+	pub other: Vec<(sys::ALCenum, sys::ALCint)>,
+}
+
+
+/// Output mode of a device, which may be requested at context creation.
+/// Requires `ALC_SOFT_output_mode`
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SoftOutputMode {
+	/// `ALC_ANY_SOFT`
+	Any,
+	/// `ALC_MONO_SOFT`
+	Mono,
+	/// `ALC_STEREO_SOFT`
+	Stereo,
+	/// `ALC_STEREO_BASIC_SOFT`
+	StereoBasic,
+	/// `ALC_STEREO_UHJ_SOFT`
+	StereoUhj,
+	/// `ALC_STEREO_HRTF_SOFT`
+	StereoHrtf,
+	/// `ALC_QUAD_SOFT`
+	Quad,
+	/// `ALC_SURROUND_5_1_SOFT`
+	Surround51,
+	/// `ALC_SURROUND_6_1_SOFT`
+	Surround61,
+	/// `ALC_SURROUND_7_1_SOFT`
+	Surround71,
+	/// A raw value reported by the implementation that alto doesn't recognize.
+	/// It is passed through unchanged when requested at context creation.
+	Unknown(sys::ALCint),
+}
+
+
+/// Channel ordering of ambisonic output.
+/// Requires `ALC_SOFT_loopback_bformat`
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SoftAmbisonicLayout {
+	/// `ALC_FUMA_SOFT`
+	FuMa,
+	/// `ALC_ACN_SOFT`
+	Acn,
+}
+
+
+/// Normalization of ambisonic output.
+/// Requires `ALC_SOFT_loopback_bformat`
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SoftAmbisonicScaling {
+	/// `ALC_FUMA_SOFT`
+	FuMa,
+	/// `ALC_SN3D_SOFT`
+	Sn3d,
+	/// `ALC_N3D_SOFT`
+	N3d,
 }
 
 
@@ -130,12 +262,17 @@ pub struct Alto(pub(crate) Arc<AltoInner>);
 pub unsafe trait DeviceObject: Any {
 	/// AltoInner instance from which this device was opened.
 	fn alto(&self) -> &Alto;
-	/// Specifier string used to open this device.
-	fn specifier(&self) -> Option<&CStr>;
+	/// Specifier string used to open this device, or the output it was most recently reopened on.
+	fn specifier(&self) -> Option<CString>;
 	/// Raw handle as exposed by OpenAL.
 	fn as_raw(&self) -> *mut sys::ALCdevice;
 	/// `alcIsExtensionPresent()`
 	fn is_extension_present(&self, ext::Alc) -> bool;
+	/// `alcGetIntegerv(ALC_MAJOR_VERSION)`, `alcGetIntegerv(ALC_MINOR_VERSION)`
+	fn alc_version(&self) -> (sys::ALCint, sys::ALCint);
+	/// `alcGetString(ALC_EXTENSIONS)`
+	/// Every extension the device reports, including those alto doesn't recognize.
+	fn alc_extensions(&self) -> Vec<CString>;
 	/// `alcGetIntegerv(ALC_CONNECTED)`
 	/// Requires `ALC_EXT_disconnect`
 	fn connected(&self) -> AltoResult<bool>;
@@ -145,6 +282,13 @@ pub unsafe trait DeviceObject: Any {
 	/// `alcGetIntegerv(ALC_HRTF_STATUS_SOFT)`
 	/// Requires `ALC_SOFT_HRTF`
 	fn soft_hrtf_status(&self) -> SoftHrtfStatus;
+	/// `alcGetIntegerv(ALC_OUTPUT_MODE_SOFT)`
+	/// The output mode the device actually selected, which may differ from the one requested.
+	/// Requires `ALC_SOFT_output_mode`
+	fn soft_output_mode(&self) -> AltoResult<SoftOutputMode>;
+	/// `alcGetIntegerv(ALC_ALL_ATTRIBUTES)`
+	/// The attributes currently in effect, reflecting the most recent context creation or reset.
+	fn attributes(&self) -> AltoResult<DeviceAttrs>;
 	/// `alcGetIntegerv(ALC_OUTPUT_LIMITER_SOFT)`
 	/// Requires `ALC_SOFT_output_limiter`
 	fn soft_output_limiter(&self) -> bool;
@@ -158,7 +302,8 @@ pub unsafe trait DeviceObject: Any {
 
 pub(crate) struct DeviceInner {
 	pub(crate) alto: Alto,
-	spec: Option<CString>,
+	/// Specifier of the output the device is currently open on.
+	spec: Mutex<Option<CString>>,
 	pub(crate) dev: *mut sys::ALCdevice,
 	pub(crate) exts: ext::AlcCache,
 }
@@ -190,26 +335,104 @@ pub struct Capture<F: StandardFrame> {
 	alto: Alto,
 	spec: Option<CString>,
 	dev: *mut sys::ALCdevice,
+	len: sys::ALCsizei,
 	marker: PhantomData<F>,
 }
 
 
+impl SoftOutputMode {
+	fn into_raw(self, asom: &ext::ALC_SOFT_output_mode) -> AltoResult<sys::ALCint> {
+		Ok(match self {
+			SoftOutputMode::Any => asom.ALC_ANY_SOFT?,
+			SoftOutputMode::Mono => asom.ALC_MONO_SOFT?,
+			SoftOutputMode::Stereo => asom.ALC_STEREO_SOFT?,
+			SoftOutputMode::StereoBasic => asom.ALC_STEREO_BASIC_SOFT?,
+			SoftOutputMode::StereoUhj => asom.ALC_STEREO_UHJ_SOFT?,
+			SoftOutputMode::StereoHrtf => asom.ALC_STEREO_HRTF_SOFT?,
+			SoftOutputMode::Quad => asom.ALC_QUAD_SOFT?,
+			SoftOutputMode::Surround51 => asom.ALC_SURROUND_5_1_SOFT?,
+			SoftOutputMode::Surround61 => asom.ALC_SURROUND_6_1_SOFT?,
+			SoftOutputMode::Surround71 => asom.ALC_SURROUND_7_1_SOFT?,
+			SoftOutputMode::Unknown(m) => m,
+		})
+	}
+
+
+	fn from_raw(asom: &ext::ALC_SOFT_output_mode, value: sys::ALCint) -> AltoResult<SoftOutputMode> {
+		Ok(match value {
+			m if m == asom.ALC_ANY_SOFT? => SoftOutputMode::Any,
+			m if m == asom.ALC_MONO_SOFT? => SoftOutputMode::Mono,
+			m if m == asom.ALC_STEREO_SOFT? => SoftOutputMode::Stereo,
+			m if m == asom.ALC_STEREO_BASIC_SOFT? => SoftOutputMode::StereoBasic,
+			m if m == asom.ALC_STEREO_UHJ_SOFT? => SoftOutputMode::StereoUhj,
+			m if m == asom.ALC_STEREO_HRTF_SOFT? => SoftOutputMode::StereoHrtf,
+			m if m == asom.ALC_QUAD_SOFT? => SoftOutputMode::Quad,
+			m if m == asom.ALC_SURROUND_5_1_SOFT? => SoftOutputMode::Surround51,
+			m if m == asom.ALC_SURROUND_6_1_SOFT? => SoftOutputMode::Surround61,
+			m if m == asom.ALC_SURROUND_7_1_SOFT? => SoftOutputMode::Surround71,
+			m => SoftOutputMode::Unknown(m),
+		})
+	}
+}
+
+
+// `ALC_FUMA_SOFT` is zero, which `alcGetEnumValue` can't distinguish from an unknown enum, so it's used directly.
+impl SoftAmbisonicLayout {
+	fn into_raw(self, aslb: &ext::ALC_SOFT_loopback_bformat) -> AltoResult<sys::ALCint> {
+		match self {
+			SoftAmbisonicLayout::FuMa => Ok(0),
+			SoftAmbisonicLayout::Acn => Ok(aslb.ALC_ACN_SOFT?),
+		}
+	}
+
+
+	fn from_raw(aslb: &ext::ALC_SOFT_loopback_bformat, value: sys::ALCint) -> AltoResult<SoftAmbisonicLayout> {
+		match value {
+			0 => Ok(SoftAmbisonicLayout::FuMa),
+			l if l == aslb.ALC_ACN_SOFT? => Ok(SoftAmbisonicLayout::Acn),
+			_ => Err(AltoError::InvalidEnum),
+		}
+	}
+}
+
+
+impl SoftAmbisonicScaling {
+	fn into_raw(self, aslb: &ext::ALC_SOFT_loopback_bformat) -> AltoResult<sys::ALCint> {
+		match self {
+			SoftAmbisonicScaling::FuMa => Ok(0),
+			SoftAmbisonicScaling::Sn3d => Ok(aslb.ALC_SN3D_SOFT?),
+			SoftAmbisonicScaling::N3d => Ok(aslb.ALC_N3D_SOFT?),
+		}
+	}
+
+
+	fn from_raw(aslb: &ext::ALC_SOFT_loopback_bformat, value: sys::ALCint) -> AltoResult<SoftAmbisonicScaling> {
+		match value {
+			0 => Ok(SoftAmbisonicScaling::FuMa),
+			s if s == aslb.ALC_SN3D_SOFT? => Ok(SoftAmbisonicScaling::Sn3d),
+			s if s == aslb.ALC_N3D_SOFT? => Ok(SoftAmbisonicScaling::N3d),
+			_ => Err(AltoError::InvalidEnum),
+		}
+	}
+}
+
+
 impl Alto {
 	/// Load the default OpenAL implementation for the platform.
 	/// This will prefer OpenAL-Soft if it is present, otherwise it will search for a generic implementation.
 	pub fn load_default() -> AltoResult<Alto> {
-		let api = sys::AlApi::load_default()?;
-		let exts = unsafe { ext::AlcNullCache::new(&api, ptr::null_mut()) };
-		Ok(Alto(Arc::new(AltoInner{
-			api: api,
-			exts: exts,
-		}))).and_then(|a| a.check_version(ptr::null_mut()).map(|_| a))
+		Alto::from_api(sys::AlApi::load_default()?)
 	}
 
 
 	/// Loads a specific OpenAL implementation from a specififed path.
 	pub fn load<P: AsRef<Path>>(path: P) -> AltoResult<Alto> {
-		let api = sys::AlApi::load(path)?;
+		Alto::from_api(sys::AlApi::load(path)?)
+	}
+
+
+	/// Wraps an already constructed set of entry points, such as those of `AlApi::software()` with the `software` feature.
+	pub fn from_api(api: sys::AlApi) -> AltoResult<Alto> {
 		let exts = unsafe { ext::AlcNullCache::new(&api, ptr::null_mut()) };
 		Ok(Alto(Arc::new(AltoInner{
 			api: api,
@@ -291,6 +514,11 @@ impl Alto {
 			return specs;
 		}
 
+		// An empty list is just two nuls.
+		if unsafe { ptr::read(spec) == 0 } {
+			return specs;
+		}
+
 		let mut i = 0;
 		loop {
 			if unsafe { ptr::read(spec.offset(i)) == 0 && ptr::read(spec.offset(i + 1)) == 0 } {
@@ -316,7 +544,7 @@ impl Alto {
 		} else {
 			let dev = OutputDevice(Arc::new(DeviceInner{
 				alto: Alto(self.0.clone()),
-				spec: spec,
+				spec: Mutex::new(spec),
 				dev: dev,
 				exts: unsafe { ext::AlcCache::new(&self.0.api, dev) },
 			}));
@@ -332,7 +560,7 @@ impl Alto {
 		asl.alcRenderSamplesSOFT?;
 
 		let spec = spec.map(|s| s.to_owned());//.or_else(|| self.default_output());
-		let dev = unsafe { asl.alcLoopbackOpenDeviceSOFT?(spec.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null())) };
+		let dev = unsafe { self.0.api.alcLoopbackOpenDeviceSOFT(asl.alcLoopbackOpenDeviceSOFT?, spec.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null())) };
 
 		if dev == ptr::null_mut() {
 			Err(AltoError::InvalidDevice)
@@ -340,7 +568,7 @@ impl Alto {
 			let dev = LoopbackDevice(
 				Arc::new(DeviceInner{
 					alto: Alto(self.0.clone()),
-					spec: spec,
+					spec: Mutex::new(spec),
 					dev: dev,
 					exts: unsafe { ext::AlcCache::new(&self.0.api, dev) },
 				}),
@@ -359,7 +587,7 @@ impl Alto {
 		if dev == ptr::null_mut() {
 			Err(AltoError::InvalidDevice)
 		} else {
-			let dev = Capture{alto: Alto(self.0.clone()), spec: spec, dev: dev, marker: PhantomData};
+			let dev = Capture{alto: Alto(self.0.clone()), spec: spec, dev: dev, len: len, marker: PhantomData};
 			//self.check_version(dev.dev).map(|_| dev)
 			Ok(dev)
 		}
@@ -383,10 +611,27 @@ impl Clone for Alto {
 
 impl DeviceInner {
 	#[inline] fn alto(&self) -> &Alto { &self.alto }
-	#[inline] fn specifier(&self) -> Option<&CStr> { self.spec.as_ref().map(|s| s.as_ref()) }
+	fn specifier(&self) -> Option<CString> { self.spec.lock().clone() }
 	#[inline] fn as_raw(&self) -> *mut sys::ALCdevice { self.dev }
 
 
+	/// `alcGetString(ALC_ALL_DEVICES_SPECIFIER)`
+	/// Name of the output the device is actually open on, as reported by the implementation.
+	fn current_specifier(&self) -> Option<CString> {
+		let spec = if let Ok(ext::ALC_ENUMERATE_ALL_EXT{ALC_ALL_DEVICES_SPECIFIER: Ok(ads), ..}) = self.alto.0.exts.ALC_ENUMERATE_ALL_EXT {
+			unsafe { self.alto.0.api.alcGetString(self.dev, ads) }
+		} else {
+			unsafe { self.alto.0.api.alcGetString(self.dev, sys::ALC_DEVICE_SPECIFIER) }
+		};
+
+		if spec == ptr::null() {
+			None
+		} else {
+			unsafe { Some(CStr::from_ptr(spec).to_owned()) }
+		}
+	}
+
+
 	/// `alcIsExtensionPresent()`
 	pub fn is_extension_present(&self, ext: ext::Alc) -> bool {
 		match ext {
@@ -396,7 +641,34 @@ impl DeviceInner {
 			ext::Alc::SoftHrtf => self.exts.ALC_SOFT_HRTF().is_ok(),
 			ext::Alc::SoftOutputLimiter => self.exts.ALC_SOFT_output_limiter().is_ok(),
 			ext::Alc::SoftPauseDevice => self.exts.ALC_SOFT_pause_device().is_ok(),
+			ext::Alc::SoftDeviceClock => self.exts.ALC_SOFT_device_clock().is_ok(),
+			ext::Alc::SoftReopenDevice => self.exts.ALC_SOFT_reopen_device().is_ok(),
+			ext::Alc::SoftOutputMode => self.exts.ALC_SOFT_output_mode().is_ok(),
+			ext::Alc::SoftLoopbackBformat => self.exts.ALC_SOFT_loopback_bformat().is_ok(),
+			ext::Alc::Debug => self.exts.ALC_EXT_debug().is_ok(),
+		}
+	}
+
+
+	/// `alcGetIntegerv(ALC_MAJOR_VERSION)`, `alcGetIntegerv(ALC_MINOR_VERSION)`
+	pub fn alc_version(&self) -> (sys::ALCint, sys::ALCint) {
+		let mut major = 0;
+		unsafe { self.alto.0.api.alcGetIntegerv(self.dev, sys::ALC_MAJOR_VERSION, 1, &mut major); }
+		let mut minor = 0;
+		unsafe { self.alto.0.api.alcGetIntegerv(self.dev, sys::ALC_MINOR_VERSION, 1, &mut minor); }
+		(major, minor)
+	}
+
+
+	/// `alcGetString(ALC_EXTENSIONS)`
+	pub fn alc_extensions(&self) -> Vec<CString> {
+		let exts = unsafe { self.alto.0.api.alcGetString(self.dev, sys::ALC_EXTENSIONS) };
+		if exts == ptr::null() {
+			return Vec::new();
 		}
+
+		let exts = unsafe { CStr::from_ptr(exts) };
+		exts.to_bytes().split(|&b| b == b' ').filter(|n| !n.is_empty()).map(|n| CString::new(n).unwrap()).collect()
 	}
 
 
@@ -421,7 +693,7 @@ impl DeviceInner {
 
 			for i in 0 .. value {
 				unsafe {
-					let spec = ash.alcGetStringiSOFT?(self.dev, ash.ALC_HRTF_SPECIFIER_SOFT?, i) as *mut _;
+					let spec = self.alto.0.api.alcGetStringiSOFT(ash.alcGetStringiSOFT?, self.dev, ash.ALC_HRTF_SPECIFIER_SOFT?, i) as *mut _;
 					spec_vec.push(self.alto.get_error(self.dev).map(|_| CStr::from_ptr(spec).to_owned())?);
 				}
 			}
@@ -454,6 +726,87 @@ impl DeviceInner {
 	}
 
 
+	/// `alcGetIntegerv(ALC_OUTPUT_MODE_SOFT)`
+	/// Requires `ALC_SOFT_output_mode`
+	pub fn soft_output_mode(&self) -> AltoResult<SoftOutputMode> {
+		let asom = self.exts.ALC_SOFT_output_mode()?;
+
+		let mut value = 0;
+		unsafe { self.alto.0.api.alcGetIntegerv(self.dev, asom.ALC_OUTPUT_MODE_SOFT?, 1, &mut value); }
+		self.alto.get_error(self.dev)?;
+		SoftOutputMode::from_raw(asom, value)
+	}
+
+
+	/// `alcGetIntegerv(ALC_ATTRIBUTES_SIZE)`, `alcGetIntegerv(ALC_ALL_ATTRIBUTES)`
+	pub fn attributes(&self) -> AltoResult<DeviceAttrs> {
+		let mut size = 0;
+		unsafe { self.alto.0.api.alcGetIntegerv(self.dev, sys::ALC_ATTRIBUTES_SIZE, 1, &mut size); }
+		self.alto.get_error(self.dev)?;
+
+		let mut values = vec![0; size.max(0) as usize];
+		if !values.is_empty() {
+			unsafe { self.alto.0.api.alcGetIntegerv(self.dev, sys::ALC_ALL_ATTRIBUTES, size, values.as_mut_ptr()); }
+			self.alto.get_error(self.dev)?;
+		}
+
+		let ash = self.exts.ALC_SOFT_HRTF().ok();
+		let asol = self.exts.ALC_SOFT_output_limiter().ok();
+		let efx = self.exts.ALC_EXT_EFX().ok();
+		let asom = self.exts.ALC_SOFT_output_mode().ok();
+		let aslb = self.exts.ALC_SOFT_loopback_bformat().ok();
+		let aed = self.exts.ALC_EXT_debug().ok();
+		let is = |key: sys::ALCenum, name: Option<ext::ExtResult<sys::ALCenum>>| name.and_then(|n| n.ok()) == Some(key);
+
+		let mut attrs = DeviceAttrs::default();
+		for pair in values.chunks(2).take_while(|p| p.len() == 2 && p[0] != 0) {
+			let (key, value) = (pair[0], pair[1]);
+			match key {
+				sys::ALC_FREQUENCY => attrs.frequency = Some(value),
+				sys::ALC_REFRESH => attrs.refresh = Some(value),
+				sys::ALC_SYNC => attrs.sync = Some(value != sys::ALC_FALSE as sys::ALCint),
+				sys::ALC_MONO_SOURCES => attrs.mono_sources = Some(value),
+				sys::ALC_STEREO_SOURCES => attrs.stereo_sources = Some(value),
+				_ if is(key, ash.map(|e| e.ALC_HRTF_SOFT)) => attrs.soft_hrtf = Some(value != sys::ALC_FALSE as sys::ALCint),
+				_ if is(key, ash.map(|e| e.ALC_HRTF_ID_SOFT)) => attrs.soft_hrtf_id = Some(value),
+				_ if is(key, asol.map(|e| e.ALC_OUTPUT_LIMITER_SOFT)) => attrs.soft_output_limiter = Some(value != sys::ALC_FALSE as sys::ALCint),
+				_ if is(key, efx.map(|e| e.ALC_MAX_AUXILIARY_SENDS)) => attrs.max_aux_sends = Some(value),
+				_ if is(key, asom.map(|e| e.ALC_OUTPUT_MODE_SOFT)) => attrs.soft_output_mode = asom.and_then(|e| SoftOutputMode::from_raw(e, value).ok()),
+				_ if is(key, aslb.map(|e| e.ALC_AMBISONIC_LAYOUT_SOFT)) => attrs.soft_ambisonic_layout = aslb.and_then(|e| SoftAmbisonicLayout::from_raw(e, value).ok()),
+				_ if is(key, aslb.map(|e| e.ALC_AMBISONIC_SCALING_SOFT)) => attrs.soft_ambisonic_scaling = aslb.and_then(|e| SoftAmbisonicScaling::from_raw(e, value).ok()),
+				_ if is(key, aslb.map(|e| e.ALC_AMBISONIC_ORDER_SOFT)) => attrs.soft_ambisonic_order = Some(value),
+				_ if is(key, aed.map(|e| e.ALC_CONTEXT_FLAGS_EXT)) => attrs.context_flags = Some(value),
+				_ => attrs.other.push((key, value)),
+			}
+		}
+		Ok(attrs)
+	}
+
+
+	/// Append the extension attributes shared by regular and loopback contexts.
+	fn extend_attrs_vec(&self, attrs_vec: &mut Vec<sys::ALCint>, layout: Option<SoftAmbisonicLayout>, scaling: Option<SoftAmbisonicScaling>, order: Option<sys::ALCint>, flags: Option<sys::ALCint>) -> AltoResult<()> {
+		if let Ok(aslb) = self.exts.ALC_SOFT_loopback_bformat() {
+			if let Some(layout) = layout {
+				attrs_vec.extend(&[aslb.ALC_AMBISONIC_LAYOUT_SOFT?, layout.into_raw(aslb)?]);
+			}
+			if let Some(scaling) = scaling {
+				attrs_vec.extend(&[aslb.ALC_AMBISONIC_SCALING_SOFT?, scaling.into_raw(aslb)?]);
+			}
+			if let Some(order) = order {
+				attrs_vec.extend(&[aslb.ALC_AMBISONIC_ORDER_SOFT?, order]);
+			}
+		}
+
+		if let Ok(aed) = self.exts.ALC_EXT_debug() {
+			if let Some(flags) = flags {
+				attrs_vec.extend(&[aed.ALC_CONTEXT_FLAGS_EXT?, flags]);
+			}
+		}
+
+		Ok(())
+	}
+
+
 	/// `alcGetIntegerv(ALC_OUTPUT_LIMITER_SOFT)`
 	/// Requires `ALC_SOFT_output_limiter`
 	pub fn soft_output_limiter(&self) -> bool {
@@ -503,6 +856,15 @@ impl OutputDevice {
 			if let Some(stereo) = attrs.stereo_sources {
 				attrs_vec.extend(&[sys::ALC_STEREO_SOURCES, stereo]);
 			}
+			if let Some(sync) = attrs.sync {
+				attrs_vec.extend(&[sys::ALC_SYNC, if sync { sys::ALC_TRUE } else { sys::ALC_FALSE } as sys::ALCint]);
+			}
+
+			if let Ok(asom) = self.0.exts.ALC_SOFT_output_mode() {
+				if let Some(mode) = attrs.soft_output_mode {
+					attrs_vec.extend(&[asom.ALC_OUTPUT_MODE_SOFT?, mode.into_raw(asom)?]);
+				}
+			}
 
 			if let Ok(ash) = self.0.exts.ALC_SOFT_HRTF() {
 				if let Some(hrtf) = attrs.soft_hrtf {
@@ -525,6 +887,7 @@ impl OutputDevice {
 				}
 			}
 
+			self.0.extend_attrs_vec(&mut attrs_vec, attrs.soft_ambisonic_layout, attrs.soft_ambisonic_scaling, attrs.soft_ambisonic_order, attrs.context_flags)?;
 			attrs_vec.push(0);
 			Ok(Some(attrs_vec))
 		} else {
@@ -536,7 +899,7 @@ impl OutputDevice {
 	/// `alcCreateContext()`
 	pub fn new_context(&self, attrs: Option<ContextAttrs>) -> AltoResult<Context> {
 		let attrs_vec = self.make_attrs_vec(attrs)?;
-		let ctx = unsafe { self.0.alto.0.api.alcCreateContext(self.0.dev, attrs_vec.map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
+		let ctx = unsafe { self.0.alto.0.api.alcCreateContext(self.0.dev, attrs_vec.as_ref().map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
 		if ctx == ptr::null_mut() {
 			match self.0.alto.get_error(self.0.dev) {
 				Ok(..) => Err(AltoError::NullError),
@@ -553,7 +916,7 @@ impl OutputDevice {
 	pub fn soft_pause(&self) -> AltoResult<()> {
 		let adps = self.0.exts.ALC_SOFT_pause_device()?.alcDevicePauseSOFT?;
 
-		unsafe { adps(self.0.dev) }
+		unsafe { self.0.alto.0.api.alcDevicePauseSOFT(adps, self.0.dev) }
 		if let Err(e) = self.0.alto.get_error(self.0.dev) {
 			return Err(e);
 		}
@@ -567,7 +930,7 @@ impl OutputDevice {
 	pub fn soft_resume(&self) {
 		if let Ok(aspd) = self.0.exts.ALC_SOFT_pause_device() {
 			if let Ok(adrs) = aspd.alcDeviceResumeSOFT {
-				unsafe { adrs(self.0.dev); }
+				unsafe { self.0.alto.0.api.alcDeviceResumeSOFT(adrs, self.0.dev); }
 			}
 		}
 	}
@@ -578,21 +941,95 @@ impl OutputDevice {
 	pub fn soft_reset(&self, attrs: Option<ContextAttrs>) -> AltoResult<()> {
 		let ards = self.0.exts.ALC_SOFT_HRTF()?.alcResetDeviceSOFT?;
 		let attrs_vec = self.make_attrs_vec(attrs.into())?;
-		unsafe { ards(self.0.dev, attrs_vec.map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
+		unsafe { self.0.alto.0.api.alcResetDeviceSOFT(ards, self.0.dev, attrs_vec.as_ref().map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
+		self.0.alto.get_error(self.0.dev)
+	}
+
+
+	/// `alcReopenDeviceSOFT()`
+	/// Move the device, along with all of its contexts and objects, to the output named by `spec`.
+	/// With no `spec`, the implementation picks the default output, and may keep following it as the default changes.
+	/// Playback continues on the new output with every buffer, source, and effect intact.
+	/// If reopening fails, the device stays on its current output.
+	/// For implementations without the extension, `ReopenableDevice` can rebuild objects on a new device instead.
+	/// Requires `ALC_SOFT_reopen_device`
+	pub fn reopen(&self, spec: Option<&CStr>, attrs: Option<ContextAttrs>) -> AltoResult<()> {
+		let ards = self.0.exts.ALC_SOFT_reopen_device()?.alcReopenDeviceSOFT?;
+		let attrs_vec = self.make_attrs_vec(attrs)?;
+
+		let res = unsafe { self.0.alto.0.api.alcReopenDeviceSOFT(ards, self.0.dev, spec.map(|s| s.as_ptr()).unwrap_or(ptr::null()), attrs_vec.as_ref().map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
+		self.0.alto.get_error(self.0.dev)?;
+		if res == sys::ALC_FALSE {
+			return Err(AltoError::InvalidDevice);
+		}
+
+		*self.0.spec.lock() = match spec {
+			Some(spec) => Some(spec.to_owned()),
+			None => self.0.current_specifier(),
+		};
+		Ok(())
+	}
+
+
+	/// `alcGetInteger64vSOFT(ALC_DEVICE_CLOCK_SOFT)`
+	/// Time on the device's clock, which advances monotonically as audio is mixed.
+	/// Requires `ALC_SOFT_device_clock`
+	pub fn clock(&self) -> AltoResult<Duration> {
+		let asdc = self.0.exts.ALC_SOFT_device_clock()?;
+		let mut value = [0];
+		self.get_integer64v(asdc.ALC_DEVICE_CLOCK_SOFT?, &mut value)?;
+		Ok(nanos_to_duration(value[0]))
+	}
+
+
+	/// `alcGetInteger64vSOFT(ALC_DEVICE_LATENCY_SOFT)`
+	/// Time until audio mixed now is heard from the device.
+	/// Requires `ALC_SOFT_device_clock`
+	pub fn latency(&self) -> AltoResult<Duration> {
+		let asdc = self.0.exts.ALC_SOFT_device_clock()?;
+		let mut value = [0];
+		self.get_integer64v(asdc.ALC_DEVICE_LATENCY_SOFT?, &mut value)?;
+		Ok(nanos_to_duration(value[0]))
+	}
+
+
+	/// `alcGetInteger64vSOFT(ALC_DEVICE_CLOCK_LATENCY_SOFT)`
+	/// Clock and latency of the device, sampled at the same instant.
+	/// Requires `ALC_SOFT_device_clock`
+	pub fn clock_latency(&self) -> AltoResult<(Duration, Duration)> {
+		let asdc = self.0.exts.ALC_SOFT_device_clock()?;
+		let mut value = [0, 0];
+		self.get_integer64v(asdc.ALC_DEVICE_CLOCK_LATENCY_SOFT?, &mut value)?;
+		Ok((nanos_to_duration(value[0]), nanos_to_duration(value[1])))
+	}
+
+
+	fn get_integer64v(&self, param: sys::ALCenum, values: &mut [ext::ALCint64SOFT]) -> AltoResult<()> {
+		let agi64v = self.0.exts.ALC_SOFT_device_clock()?.alcGetInteger64vSOFT?;
+		unsafe { self.0.alto.0.api.alcGetInteger64vSOFT(agi64v, self.0.dev, param, values.len() as sys::ALsizei, values.as_mut_ptr()); }
 		self.0.alto.get_error(self.0.dev)
 	}
 }
 
 
+fn nanos_to_duration(nanos: ext::ALCint64SOFT) -> Duration {
+	Duration::from_nanos(nanos.max(0) as u64)
+}
+
+
 unsafe impl DeviceObject for OutputDevice {
 	#[inline] fn alto(&self) -> &Alto { self.0.alto() }
-	#[inline] fn specifier(&self) -> Option<&CStr> { self.0.specifier() }
+	#[inline] fn specifier(&self) -> Option<CString> { self.0.specifier() }
 	#[inline] fn as_raw(&self) -> *mut sys::ALCdevice { self.0.as_raw() }
 	#[inline] fn connected(&self) -> AltoResult<bool> { self.0.connected() }
 
 	#[inline] fn is_extension_present(&self, ext: ext::Alc) -> bool { self.0.is_extension_present(ext) }
+	#[inline] fn alc_version(&self) -> (sys::ALCint, sys::ALCint) { self.0.alc_version() }
+	#[inline] fn alc_extensions(&self) -> Vec<CString> { self.0.alc_extensions() }
 	#[inline] fn enumerate_soft_hrtfs(&self) -> Vec<CString> { self.0.enumerate_soft_hrtfs() }
 	#[inline] fn soft_hrtf_status(&self) -> SoftHrtfStatus { self.0.soft_hrtf_status() }
+	#[inline] fn soft_output_mode(&self) -> AltoResult<SoftOutputMode> { self.0.soft_output_mode() }
+	#[inline] fn attributes(&self) -> AltoResult<DeviceAttrs> { self.0.attributes() }
 	#[inline] fn soft_output_limiter(&self) -> bool { self.0.soft_output_limiter() }
 	#[inline] fn max_aux_sends(&self) -> sys::ALCint { self.0.max_aux_sends() }
 	#[inline] fn to_device(&self) -> Device { Device(self.0.clone()) }
@@ -612,7 +1049,7 @@ unsafe impl Sync for OutputDevice { }
 
 
 impl<F: LoopbackFrame> LoopbackDevice<F> {
-	fn make_attrs_vec(&self, freq: sys::ALCint, attrs: Option<LoopbackAttrs>) -> AltoResult<Vec<sys::ALCint>> {
+	pub(crate) fn make_attrs_vec(&self, freq: sys::ALCint, attrs: Option<LoopbackAttrs>) -> AltoResult<Vec<sys::ALCint>> {
 		let asl = self.0.alto.0.exts.ALC_SOFT_loopback()?;
 
 		let mut attrs_vec = Vec::with_capacity(19);
@@ -647,6 +1084,8 @@ impl<F: LoopbackFrame> LoopbackDevice<F> {
 					attrs_vec.extend(&[efx.ALC_MAX_AUXILIARY_SENDS?, max_sends]);
 				}
 			}
+
+			self.0.extend_attrs_vec(&mut attrs_vec, attrs.soft_ambisonic_layout, attrs.soft_ambisonic_scaling, attrs.soft_ambisonic_order, attrs.context_flags)?;
 		}
 		attrs_vec.push(0);
 		Ok(attrs_vec)
@@ -679,7 +1118,7 @@ impl<F: LoopbackFrame> LoopbackDevice<F> {
 
 		let asl = self.0.alto.0.exts.ALC_SOFT_loopback().unwrap();
 
-		unsafe { asl.alcRenderSamplesSOFT.unwrap()(self.0.dev, data, len as sys::ALCsizei); }
+		unsafe { self.0.alto.0.api.alcRenderSamplesSOFT(asl.alcRenderSamplesSOFT.unwrap(), self.0.dev, data, len as sys::ALCsizei); }
 
 		len as usize
 	}
@@ -691,7 +1130,7 @@ impl<F: LoopbackFrame> LoopbackDevice<F> {
 		let ards = self.0.exts.ALC_SOFT_HRTF()?.alcResetDeviceSOFT?;
 
 		let attrs_vec = self.make_attrs_vec(freq, attrs.into());
-		unsafe { ards(self.0.dev, attrs_vec.map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
+		unsafe { self.0.alto.0.api.alcResetDeviceSOFT(ards, self.0.dev, attrs_vec.as_ref().map(|a| a.as_slice().as_ptr()).unwrap_or(ptr::null())) };
 		self.0.alto.get_error(self.0.dev)
 	}
 }
@@ -699,13 +1138,17 @@ impl<F: LoopbackFrame> LoopbackDevice<F> {
 
 unsafe impl<F: LoopbackFrame> DeviceObject for LoopbackDevice<F> {
 	#[inline] fn alto(&self) -> &Alto { self.0.alto() }
-	#[inline] fn specifier(&self) -> Option<&CStr> { self.0.specifier() }
+	#[inline] fn specifier(&self) -> Option<CString> { self.0.specifier() }
 	#[inline] fn as_raw(&self) -> *mut sys::ALCdevice { self.0.as_raw() }
 	#[inline] fn connected(&self) -> AltoResult<bool> { self.0.connected() }
 
 	#[inline] fn is_extension_present(&self, ext: ext::Alc) -> bool { self.0.is_extension_present(ext) }
+	#[inline] fn alc_version(&self) -> (sys::ALCint, sys::ALCint) { self.0.alc_version() }
+	#[inline] fn alc_extensions(&self) -> Vec<CString> { self.0.alc_extensions() }
 	#[inline] fn enumerate_soft_hrtfs(&self) -> Vec<CString> { self.0.enumerate_soft_hrtfs() }
 	#[inline] fn soft_hrtf_status(&self) -> SoftHrtfStatus { self.0.soft_hrtf_status() }
+	#[inline] fn soft_output_mode(&self) -> AltoResult<SoftOutputMode> { self.0.soft_output_mode() }
+	#[inline] fn attributes(&self) -> AltoResult<DeviceAttrs> { self.0.attributes() }
 	#[inline] fn soft_output_limiter(&self) -> bool { self.0.soft_output_limiter() }
 	#[inline] fn max_aux_sends(&self) -> sys::ALCint { self.0.max_aux_sends() }
 	#[inline] fn to_device(&self) -> Device { Device(self.0.clone()) }
@@ -726,19 +1169,34 @@ unsafe impl<F: LoopbackFrame> Sync for LoopbackDevice<F> { }
 
 unsafe impl DeviceObject for Device {
 	#[inline] fn alto(&self) -> &Alto { self.0.alto() }
-	#[inline] fn specifier(&self) -> Option<&CStr> { self.0.specifier() }
+	#[inline] fn specifier(&self) -> Option<CString> { self.0.specifier() }
 	#[inline] fn as_raw(&self) -> *mut sys::ALCdevice { self.0.as_raw() }
 	#[inline] fn connected(&self) -> AltoResult<bool> { self.0.connected() }
 
 	#[inline] fn is_extension_present(&self, ext: ext::Alc) -> bool { self.0.is_extension_present(ext) }
+	#[inline] fn alc_version(&self) -> (sys::ALCint, sys::ALCint) { self.0.alc_version() }
+	#[inline] fn alc_extensions(&self) -> Vec<CString> { self.0.alc_extensions() }
 	#[inline] fn enumerate_soft_hrtfs(&self) -> Vec<CString> { self.0.enumerate_soft_hrtfs() }
 	#[inline] fn soft_hrtf_status(&self) -> SoftHrtfStatus { self.0.soft_hrtf_status() }
+	#[inline] fn soft_output_mode(&self) -> AltoResult<SoftOutputMode> { self.0.soft_output_mode() }
+	#[inline] fn attributes(&self) -> AltoResult<DeviceAttrs> { self.0.attributes() }
 	#[inline] fn soft_output_limiter(&self) -> bool { self.0.soft_output_limiter() }
 	#[inline] fn max_aux_sends(&self) -> sys::ALCint { self.0.max_aux_sends() }
 	#[inline] fn to_device(&self) -> Device { Device(self.0.clone()) }
 }
 
 
+unsafe impl Send for Device { }
+unsafe impl Sync for Device { }
+
+
+impl fmt::Debug for Device {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("Device").field(&self.as_raw()).finish()
+	}
+}
+
+
 impl PartialEq for DeviceObject {
 	fn eq(&self, other: &DeviceObject) -> bool {
 		self.as_raw() == other.as_raw()
@@ -754,6 +1212,8 @@ impl<F: StandardFrame> Capture<F> {
 	#[inline] pub fn specifier(&self) -> Option<&CStr> { self.spec.as_ref().map(|s| s.as_ref()) }
 	/// Raw device handle as reported by OpenAL.
 	#[inline] pub fn as_raw(&self) -> *mut sys::ALCdevice { self.dev }
+	/// Capacity of the device's internal buffer in sample frames, as requested when opening the device.
+	#[inline] pub fn buffer_len(&self) -> sys::ALCsizei { self.len }
 
 
 	/// `alcCaptureStart()`
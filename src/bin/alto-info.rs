@@ -0,0 +1,157 @@
+//! Prints the devices and capabilities of an OpenAL implementation, for diagnosing audio setups.
+//!
+//! Usage: `alto-info [--json] [--lib <path>]`
+
+
+extern crate alto;
+extern crate serde;
+extern crate serde_json;
+
+use std::env;
+use std::ffi::CString;
+use std::process::exit;
+
+use serde::Serialize;
+
+use alto::{Alto, AltoResult, DeviceObject};
+use alto::report::CapabilityReport;
+
+
+#[derive(Serialize)]
+struct Info {
+	library: Option<String>,
+	default_output: Option<String>,
+	default_capture: Option<String>,
+	outputs: Vec<String>,
+	captures: Vec<String>,
+	devices: Vec<DeviceInfo>,
+}
+
+
+#[derive(Serialize)]
+struct DeviceInfo {
+	specifier: String,
+	/// Why the device or a context on it could not be opened, in which case nothing else is known.
+	error: Option<String>,
+	hrtf_status: Option<String>,
+	output_limiter: Option<bool>,
+	capabilities: Option<CapabilityReport>,
+}
+
+
+impl DeviceInfo {
+	fn new(alto: &Alto, spec: &CString) -> DeviceInfo {
+		let mut info = DeviceInfo{
+			specifier: lossy(spec),
+			error: None,
+			hrtf_status: None,
+			output_limiter: None,
+			capabilities: None,
+		};
+
+		let res = (|| -> AltoResult<()> {
+			let dev = alto.open(Some(spec))?;
+			let ctx = dev.new_context(None)?;
+			info.hrtf_status = Some(format!("{:?}", dev.soft_hrtf_status()));
+			info.output_limiter = Some(dev.soft_output_limiter());
+			info.capabilities = Some(CapabilityReport::new(&ctx));
+			Ok(())
+		})();
+		if let Err(e) = res {
+			info.error = Some(e.to_string());
+		}
+
+		info
+	}
+}
+
+
+fn lossy(s: &CString) -> String { s.to_string_lossy().into_owned() }
+
+
+fn print_text(info: &Info) {
+	fn opt(s: &Option<String>) -> &str { s.as_ref().map(|s| s.as_str()).unwrap_or("(none)") }
+
+	if let Some(ref lib) = info.library {
+		println!("Library: {}", lib);
+	}
+	println!("Default output: {}", opt(&info.default_output));
+	println!("Default capture: {}", opt(&info.default_capture));
+	println!("Output devices ({}):", info.outputs.len());
+	for spec in &info.outputs {
+		println!("    {}", spec);
+	}
+	println!("Capture devices ({}):", info.captures.len());
+	for spec in &info.captures {
+		println!("    {}", spec);
+	}
+
+	for dev in &info.devices {
+		println!();
+		println!("== {} ==", dev.specifier);
+		if let Some(ref e) = dev.error {
+			println!("Failed to open: {}", e);
+			continue;
+		}
+		if let Some(ref caps) = dev.capabilities {
+			print!("{}", caps);
+		}
+		println!("HRTF status: {}", opt(&dev.hrtf_status));
+		if let Some(limiter) = dev.output_limiter {
+			println!("Output limiter: {}", limiter);
+		}
+	}
+}
+
+
+fn usage() -> ! {
+	eprintln!("Usage: alto-info [--json] [--lib <path>]");
+	exit(2);
+}
+
+
+fn main() {
+	let mut json = false;
+	let mut lib = None;
+
+	let mut args = env::args().skip(1);
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--json" => json = true,
+			"--lib" => lib = Some(args.next().unwrap_or_else(|| usage())),
+			"-h" | "--help" => {
+				println!("Usage: alto-info [--json] [--lib <path>]");
+				return;
+			},
+			_ => usage(),
+		}
+	}
+
+	let alto = match lib {
+		Some(ref path) => Alto::load(path),
+		None => Alto::load_default(),
+	};
+	let alto = match alto {
+		Ok(alto) => alto,
+		Err(e) => {
+			eprintln!("Failed to load OpenAL: {}", e);
+			exit(1);
+		},
+	};
+
+	let outputs = alto.enumerate_outputs();
+	let info = Info{
+		library: lib,
+		default_output: alto.default_output().as_ref().map(lossy),
+		default_capture: alto.default_capture().as_ref().map(lossy),
+		devices: outputs.iter().map(|spec| DeviceInfo::new(&alto, spec)).collect(),
+		outputs: outputs.iter().map(lossy).collect(),
+		captures: alto.enumerate_captures().iter().map(lossy).collect(),
+	};
+
+	if json {
+		println!("{}", serde_json::to_string_pretty(&info).unwrap());
+	} else {
+		print_text(&info);
+	}
+}
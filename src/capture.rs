@@ -0,0 +1,241 @@
+use std::cell::UnsafeCell;
+use std::io;
+use std::mem;
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use parking_lot::Mutex;
+
+use ::{AltoError, AltoResult};
+use alc::*;
+use al::*;
+
+
+/// Counters describing the health of a `CaptureStream`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CaptureStats {
+	/// Sample frames read from the device.
+	pub captured: u64,
+	/// Sample frames read from the device that were discarded because the ring buffer was full.
+	pub dropped: u64,
+	/// Number of polls that found the device's internal buffer full, meaning the device may have discarded audio.
+	pub device_overruns: u64,
+}
+
+
+/// A single producer, single consumer queue of sample frames with a fixed capacity.
+struct Ring<F> {
+	buf: Box<[UnsafeCell<F>]>,
+	/// Total frames ever written. Only modified by the producer.
+	head: AtomicUsize,
+	/// Total frames ever read. Only modified by the consumer.
+	tail: AtomicUsize,
+}
+
+
+struct Shared<F> {
+	ring: Ring<F>,
+	stop: AtomicBool,
+	running: AtomicBool,
+	captured: AtomicU64,
+	dropped: AtomicU64,
+	device_overruns: AtomicU64,
+	error: Mutex<Option<AltoError>>,
+}
+
+
+/// Continuously drains a `Capture` device from a background thread.
+///
+/// Captured audio is either buffered in a lock-free ring buffer to be pulled with `read`,
+/// or handed to a callback on the background thread as soon as it arrives.
+/// The device is started when the stream is created, and stopped when the stream is dropped.
+pub struct CaptureStream<F: StandardFrame + Send> {
+	shared: Arc<Shared<F>>,
+	thread: Option<thread::JoinHandle<()>>,
+}
+
+
+impl<F: Copy> Ring<F> {
+	fn new(len: usize) -> Ring<F> {
+		Ring{
+			buf: (0 .. len).map(|_| UnsafeCell::new(unsafe { mem::zeroed() })).collect::<Vec<_>>().into_boxed_slice(),
+			head: AtomicUsize::new(0),
+			tail: AtomicUsize::new(0),
+		}
+	}
+
+
+	fn len(&self) -> usize {
+		self.head.load(Ordering::Acquire).wrapping_sub(self.tail.load(Ordering::Acquire))
+	}
+
+
+	/// Append as many frames as fit, returning the number appended. Must only be called by the producer.
+	fn push(&self, frames: &[F]) -> usize {
+		let head = self.head.load(Ordering::Relaxed);
+		let tail = self.tail.load(Ordering::Acquire);
+		let len = frames.len().min(self.buf.len() - head.wrapping_sub(tail));
+
+		for (i, &frame) in frames[.. len].iter().enumerate() {
+			unsafe { *self.buf[head.wrapping_add(i) % self.buf.len()].get() = frame; }
+		}
+		self.head.store(head.wrapping_add(len), Ordering::Release);
+		len
+	}
+
+
+	/// Remove frames into `out`, returning the number removed. Must only be called by the consumer.
+	fn pop(&self, out: &mut [F]) -> usize {
+		let tail = self.tail.load(Ordering::Relaxed);
+		let head = self.head.load(Ordering::Acquire);
+		let len = out.len().min(head.wrapping_sub(tail));
+
+		for (i, frame) in out[.. len].iter_mut().enumerate() {
+			*frame = unsafe { *self.buf[tail.wrapping_add(i) % self.buf.len()].get() };
+		}
+		self.tail.store(tail.wrapping_add(len), Ordering::Release);
+		len
+	}
+}
+
+
+unsafe impl<F: Send> Sync for Ring<F> { }
+
+
+impl<F: StandardFrame + Send> CaptureStream<F> {
+	/// Start `capture` and buffer up to `ring_len` sample frames of its output, to be pulled with `read`.
+	/// The device is polled every `period`, which should be well below the time it takes to fill its internal buffer.
+	pub fn new(capture: Capture<F>, ring_len: usize, period: Duration) -> AltoResult<CaptureStream<F>> {
+		if ring_len == 0 {
+			return Err(AltoError::InvalidValue);
+		}
+
+		CaptureStream::spawn(capture, ring_len, period, None::<fn(&[F])>)
+	}
+
+
+	/// Start `capture` and invoke `callback` from the background thread with each block of sample frames captured.
+	/// The device is polled every `period`, which should be well below the time it takes to fill its internal buffer.
+	pub fn with_callback<C>(capture: Capture<F>, period: Duration, callback: C) -> AltoResult<CaptureStream<F>> where
+		C: FnMut(&[F]) + Send + 'static,
+	{
+		CaptureStream::spawn(capture, 0, period, Some(callback))
+	}
+
+
+	fn spawn<C>(mut capture: Capture<F>, ring_len: usize, period: Duration, mut callback: Option<C>) -> AltoResult<CaptureStream<F>> where
+		C: FnMut(&[F]) + Send + 'static,
+	{
+		if capture.buffer_len() <= 0 {
+			return Err(AltoError::InvalidValue);
+		}
+
+		let shared = Arc::new(Shared{
+			ring: Ring::new(ring_len),
+			stop: AtomicBool::new(false),
+			running: AtomicBool::new(true),
+			captured: AtomicU64::new(0),
+			dropped: AtomicU64::new(0),
+			device_overruns: AtomicU64::new(0),
+			error: Mutex::new(None),
+		});
+
+		let thread_shared = shared.clone();
+		let thread = thread::Builder::new()
+			.name("alto-capture".into())
+			.spawn(move || {
+				let shared = thread_shared;
+				let mut scratch = vec![unsafe { mem::zeroed() }; capture.buffer_len() as usize];
+
+				capture.start();
+				while !shared.stop.load(Ordering::Acquire) {
+					// A panicking callback stops the stream like a device error, rather than leaving it marked as running.
+					let res = panic::catch_unwind(panic::AssertUnwindSafe(|| shared.poll(&mut capture, &mut scratch, &mut callback)))
+						.unwrap_or_else(|_| Err(AltoError::Io(io::Error::other("capture callback panicked"))));
+					if let Err(e) = res {
+						*shared.error.lock() = Some(e);
+						break;
+					}
+					thread::sleep(period);
+				}
+				capture.stop();
+				shared.running.store(false, Ordering::Release);
+			})?;
+
+		Ok(CaptureStream{
+			shared: shared,
+			thread: Some(thread),
+		})
+	}
+
+
+	/// Number of sample frames waiting in the ring buffer.
+	pub fn available(&self) -> usize { self.shared.ring.len() }
+
+
+	/// Move as many buffered sample frames as are available into `out`, without blocking.
+	/// Returns the number of sample frames read. Always returns 0 for a stream created with a callback.
+	pub fn read(&mut self, out: &mut [F]) -> usize {
+		self.shared.ring.pop(out)
+	}
+
+
+	/// A snapshot of the stream's counters.
+	pub fn stats(&self) -> CaptureStats {
+		CaptureStats{
+			captured: self.shared.captured.load(Ordering::Relaxed),
+			dropped: self.shared.dropped.load(Ordering::Relaxed),
+			device_overruns: self.shared.device_overruns.load(Ordering::Relaxed),
+		}
+	}
+
+
+	/// Whether the background thread is still capturing.
+	/// It stops after the device reports an error or the callback panics, which can be retrieved with `take_error`.
+	pub fn is_running(&self) -> bool {
+		self.shared.running.load(Ordering::Acquire)
+	}
+
+
+	/// Take the error that stopped the background thread, if any.
+	pub fn take_error(&self) -> Option<AltoError> {
+		self.shared.error.lock().take()
+	}
+}
+
+
+impl<F: StandardFrame + Send> Drop for CaptureStream<F> {
+	fn drop(&mut self) {
+		self.shared.stop.store(true, Ordering::Release);
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+
+impl<F: StandardFrame + Send> Shared<F> {
+	fn poll<C: FnMut(&[F])>(&self, capture: &mut Capture<F>, scratch: &mut [F], callback: &mut Option<C>) -> AltoResult<()> {
+		if capture.samples_len() >= capture.buffer_len() {
+			self.device_overruns.fetch_add(1, Ordering::Relaxed);
+		}
+
+		loop {
+			let len = capture.capture_samples(&mut *scratch)?;
+			if len == 0 {
+				return Ok(());
+			}
+			self.captured.fetch_add(len as u64, Ordering::Relaxed);
+
+			match *callback {
+				Some(ref mut callback) => callback(&scratch[.. len]),
+				None => {
+					let pushed = self.ring.push(&scratch[.. len]);
+					self.dropped.fetch_add((len - pushed) as u64, Ordering::Relaxed);
+				},
+			}
+		}
+	}
+}
@@ -0,0 +1,542 @@
+//! Conversion of sample frames between sample types and channel layouts.
+//!
+//! Samples are converted through `f32`, normalized to `[-1.0, 1.0]`. Integer samples are scaled by powers of two,
+//! so that conversions which don't lose precision are exact, and rounded with optional triangular dither when precision is lost.
+//!
+//! Channels are mixed with a matrix chosen from the source and destination layouts.
+//! Speakers present in both layouts are copied, and the rest are downmixed following ITU-R BS.775:
+//! the center and surround channels are folded into the front pair at -3dB, and the LFE channel is discarded.
+//! Mono is the average of left and right, so stereo audio can be collapsed for spatialization by a source.
+//! B-Format audio is encoded and decoded using the FuMa convention of `AL_EXT_BFORMAT`.
+
+use std::f32::consts::{FRAC_1_SQRT_2, SQRT_2};
+use std::marker::PhantomData;
+
+use ::{AltoError, AltoResult};
+use sys;
+use al::*;
+
+
+/// Arrangement of the channels of a sample frame.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Layout {
+	/// Center.
+	Mono,
+	/// Left, right.
+	Stereo,
+	/// Rear center.
+	Rear,
+	/// Front left, front right, back left, back right.
+	Quad,
+	/// Front left, front right, front center, low frequency, back left, back right.
+	Mc51Chn,
+	/// Front left, front right, front center, low frequency, back left, back right, back center.
+	Mc61Chn,
+	/// Front left, front right, front center, low frequency, back left, back right, side left, side right.
+	Mc71Chn,
+	/// Horizontal first order ambisonics: W, X, Y.
+	BFormat2D,
+	/// Full sphere first order ambisonics: W, X, Y, Z.
+	BFormat3D,
+}
+
+
+/// How to round samples when converting to a sample type with less precision.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Dither {
+	/// Round to the nearest value.
+	None,
+	/// Add triangular probability density noise of one least significant bit before rounding,
+	/// decorrelating the quantization error from the signal.
+	Triangular,
+}
+
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Speaker {
+	FrontLeft,
+	FrontRight,
+	FrontCenter,
+	LowFreq,
+	BackLeft,
+	BackRight,
+	BackCenter,
+	SideLeft,
+	SideRight,
+}
+
+
+/// A sample type that can be converted to and from normalized `f32`.
+pub trait ConvertSample: Copy {
+	/// Bits of precision, used to decide whether dither is needed.
+	const BITS: u32;
+
+
+	/// Convert to a value in `[-1.0, 1.0]`.
+	fn to_f32(self) -> f32;
+	/// Convert from a value in `[-1.0, 1.0]`, clamping values outside that range.
+	/// `noise` is added in units of the least significant bit before rounding, and is ignored by floating point types.
+	fn from_f32(value: f32, noise: f32) -> Self;
+}
+
+
+/// A sample frame whose channels can be read and written as normalized `f32` samples.
+pub trait ConvertFrame: SampleFrame {
+	/// Arrangement of the channels of this frame.
+	const LAYOUT: Layout;
+	/// Bits of precision of the sample type.
+	const BITS: u32;
+
+
+	/// Write each channel to `out`, which must be at least `Self::len()` long.
+	fn read(&self, out: &mut [f32]);
+	/// Build a frame from `samples`, calling `noise` for the dither of each sample of an integer type.
+	fn write<N: FnMut() -> f32>(samples: &[f32], noise: N) -> Self;
+}
+
+
+/// Converts slices of sample frames of type `S` into frames of type `D`.
+pub struct Converter<S: ConvertFrame, D: ConvertFrame> {
+	matrix: Vec<f32>,
+	dither: bool,
+	seed: u32,
+	marker: PhantomData<fn(S) -> D>,
+}
+
+
+impl Layout {
+	/// Number of channels in a frame of this layout.
+	pub fn channels(&self) -> usize {
+		match *self {
+			Layout::BFormat2D => 3,
+			Layout::BFormat3D => 4,
+			l => l.speakers().unwrap().len(),
+		}
+	}
+
+
+	fn speakers(&self) -> Option<&'static [Speaker]> {
+		use self::Speaker::*;
+
+		match *self {
+			Layout::Mono => Some(&[FrontCenter]),
+			Layout::Stereo => Some(&[FrontLeft, FrontRight]),
+			Layout::Rear => Some(&[BackCenter]),
+			Layout::Quad => Some(&[FrontLeft, FrontRight, BackLeft, BackRight]),
+			Layout::Mc51Chn => Some(&[FrontLeft, FrontRight, FrontCenter, LowFreq, BackLeft, BackRight]),
+			Layout::Mc61Chn => Some(&[FrontLeft, FrontRight, FrontCenter, LowFreq, BackLeft, BackRight, BackCenter]),
+			Layout::Mc71Chn => Some(&[FrontLeft, FrontRight, FrontCenter, LowFreq, BackLeft, BackRight, SideLeft, SideRight]),
+			Layout::BFormat2D | Layout::BFormat3D => None,
+		}
+	}
+}
+
+
+impl Speaker {
+	/// Direction of the speaker in radians counterclockwise from the front, or `None` if it is not directional.
+	fn azimuth(&self) -> Option<f32> {
+		let deg = match *self {
+			Speaker::FrontLeft => 30.0,
+			Speaker::FrontRight => -30.0,
+			Speaker::FrontCenter => 0.0,
+			Speaker::LowFreq => return None,
+			Speaker::BackLeft => 135.0,
+			Speaker::BackRight => -135.0,
+			Speaker::BackCenter => 180.0,
+			Speaker::SideLeft => 90.0,
+			Speaker::SideRight => -90.0,
+		};
+		Some(f32::to_radians(deg))
+	}
+
+
+	/// Contribution to the left and right channels of an ITU stereo downmix.
+	fn stereo(&self) -> (f32, f32) {
+		match *self {
+			Speaker::FrontLeft => (1.0, 0.0),
+			Speaker::FrontRight => (0.0, 1.0),
+			Speaker::FrontCenter => (FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+			Speaker::LowFreq => (0.0, 0.0),
+			Speaker::BackLeft | Speaker::SideLeft => (FRAC_1_SQRT_2, 0.0),
+			Speaker::BackRight | Speaker::SideRight => (0.0, FRAC_1_SQRT_2),
+			Speaker::BackCenter => (0.5, 0.5),
+		}
+	}
+}
+
+
+/// Build the matrix that mixes a frame of layout `from` into a frame of layout `to`.
+/// The result is in row-major order, with `to.channels()` rows of `from.channels()` gains.
+pub fn mix_matrix(from: Layout, to: Layout) -> Vec<f32> {
+	let (rows, cols) = (to.channels(), from.channels());
+	let mut m = vec![0.0; rows * cols];
+
+	match (from.speakers(), to.speakers()) {
+		(Some(src), Some(dst)) => {
+			let pos = |s: Speaker| dst.iter().position(|&d| d == s);
+			for (c, &s) in src.iter().enumerate() {
+				if let Some(r) = pos(s) {
+					m[r * cols + c] = 1.0;
+					continue;
+				}
+
+				// Fold surrounds into whichever surrounds the destination has, before falling back to stereo.
+				let surround = match s {
+					Speaker::SideLeft => pos(Speaker::BackLeft).map(|r| vec![(r, FRAC_1_SQRT_2)]),
+					Speaker::SideRight => pos(Speaker::BackRight).map(|r| vec![(r, FRAC_1_SQRT_2)]),
+					Speaker::BackCenter => match (pos(Speaker::BackLeft), pos(Speaker::BackRight)) {
+						(Some(l), Some(r)) => Some(vec![(l, FRAC_1_SQRT_2), (r, FRAC_1_SQRT_2)]),
+						_ => None,
+					},
+					_ => None,
+				};
+				if let Some(gains) = surround {
+					for (r, g) in gains {
+						m[r * cols + c] += g;
+					}
+					continue;
+				}
+
+				let (l, r) = s.stereo();
+				match (pos(Speaker::FrontLeft), pos(Speaker::FrontRight)) {
+					(Some(fl), Some(fr)) => {
+						m[fl * cols + c] += l;
+						m[fr * cols + c] += r;
+					},
+					// Mono and rear destinations have a single channel, which takes the average of the stereo downmix.
+					_ => m[c] += (l + r) * 0.5,
+				}
+			}
+		},
+		(Some(src), None) => {
+			for (c, s) in src.iter().enumerate() {
+				if let Some(az) = s.azimuth() {
+					m[c] = FRAC_1_SQRT_2;
+					m[cols + c] = az.cos();
+					m[2 * cols + c] = az.sin();
+				}
+			}
+		},
+		(None, Some(dst)) => {
+			if dst.len() == 1 {
+				m[0] = SQRT_2;
+			} else {
+				let n = dst.iter().filter(|s| s.azimuth().is_some()).count() as f32;
+				for (r, s) in dst.iter().enumerate() {
+					if let Some(az) = s.azimuth() {
+						m[r * cols] = SQRT_2 / n;
+						m[r * cols + 1] = 2.0 * az.cos() / n;
+						m[r * cols + 2] = 2.0 * az.sin() / n;
+					}
+				}
+			}
+		},
+		(None, None) => {
+			for i in 0 .. rows.min(cols) {
+				m[i * cols + i] = 1.0;
+			}
+		},
+	}
+
+	m
+}
+
+
+impl<S: ConvertFrame, D: ConvertFrame> Converter<S, D> {
+	/// A converter using the standard mix matrix for the layouts of `S` and `D`.
+	pub fn new(dither: Dither) -> Converter<S, D> {
+		Converter{
+			matrix: mix_matrix(S::LAYOUT, D::LAYOUT),
+			dither: dither == Dither::Triangular && D::BITS < S::BITS,
+			seed: 0x9E37_79B9,
+			marker: PhantomData,
+		}
+	}
+
+
+	/// A converter using a custom mix matrix, laid out as described by `mix_matrix`.
+	pub fn with_matrix(matrix: Vec<f32>, dither: Dither) -> AltoResult<Converter<S, D>> {
+		if matrix.len() != S::len() * D::len() {
+			return Err(AltoError::InvalidValue);
+		}
+
+		let mut conv = Converter::new(dither);
+		conv.matrix = matrix;
+		Ok(conv)
+	}
+
+
+	/// The mix matrix in use.
+	pub fn matrix(&self) -> &[f32] { &self.matrix }
+
+
+	/// Convert frames from `src` into `dst`, returning the number of frames converted,
+	/// which is the length of the shorter slice.
+	pub fn convert(&mut self, src: &[S], dst: &mut [D]) -> usize {
+		let (rows, cols) = (D::len(), S::len());
+		let mut input = [0.0; 8];
+		let mut output = [0.0; 8];
+
+		let len = src.len().min(dst.len());
+		for (s, d) in src.iter().zip(dst.iter_mut()) {
+			s.read(&mut input[.. cols]);
+			for (r, out) in output[.. rows].iter_mut().enumerate() {
+				*out = self.matrix[r * cols .. (r + 1) * cols].iter().zip(&input[.. cols]).map(|(g, i)| g * i).sum();
+			}
+
+			let dither = self.dither;
+			let seed = &mut self.seed;
+			*d = D::write(&output[.. rows], || if dither { tpdf(seed) } else { 0.0 });
+		}
+
+		len
+	}
+
+
+	/// Convert all of `src` into a new vector.
+	pub fn convert_vec(&mut self, src: &[S]) -> Vec<D> {
+		let mut dst = Vec::with_capacity(src.len());
+		let mut block = [D::write(&[0.0; 8][.. D::len()], || 0.0); 256];
+		for chunk in src.chunks(block.len()) {
+			let len = self.convert(chunk, &mut block);
+			dst.extend_from_slice(&block[.. len]);
+		}
+		dst
+	}
+}
+
+
+/// Convert `src` to a new vector of frames of type `D`, using the standard mix matrix and triangular dither.
+pub fn convert<S: ConvertFrame, D: ConvertFrame>(src: &[S]) -> Vec<D> {
+	Converter::new(Dither::Triangular).convert_vec(src)
+}
+
+
+/// Triangular noise in `(-1.0, 1.0)` from the difference of two uniform values of a xorshift generator.
+fn tpdf(seed: &mut u32) -> f32 {
+	let mut next = || {
+		*seed ^= *seed << 13;
+		*seed ^= *seed >> 17;
+		*seed ^= *seed << 5;
+		(*seed >> 8) as f32 / (1 << 24) as f32
+	};
+	next() - next()
+}
+
+
+impl ConvertSample for u8 {
+	const BITS: u32 = 8;
+	fn to_f32(self) -> f32 { (self as f32 - 128.0) / 128.0 }
+	fn from_f32(value: f32, noise: f32) -> u8 { (value * 128.0 + 128.0 + noise).round().clamp(0.0, 255.0) as u8 }
+}
+impl ConvertSample for i16 {
+	const BITS: u32 = 16;
+	fn to_f32(self) -> f32 { self as f32 / 32768.0 }
+	fn from_f32(value: f32, noise: f32) -> i16 { (value * 32768.0 + noise).round().clamp(-32768.0, 32767.0) as i16 }
+}
+impl ConvertSample for f32 {
+	const BITS: u32 = 24;
+	fn to_f32(self) -> f32 { self }
+	fn from_f32(value: f32, _: f32) -> f32 { value.clamp(-1.0, 1.0) }
+}
+impl ConvertSample for f64 {
+	const BITS: u32 = 53;
+	fn to_f32(self) -> f32 { self as f32 }
+	fn from_f32(value: f32, _: f32) -> f64 { value.clamp(-1.0, 1.0) as f64 }
+}
+impl ConvertSample for ALawSample {
+	const BITS: u32 = 13;
+	fn to_f32(self) -> f32 { alaw_decode(self.0).to_f32() }
+	fn from_f32(value: f32, noise: f32) -> ALawSample { ALawSample(alaw_encode(i16::from_f32(value, noise * 8.0))) }
+}
+impl ConvertSample for MuLawSample {
+	const BITS: u32 = 14;
+	fn to_f32(self) -> f32 { mulaw_decode(self.0).to_f32() }
+	fn from_f32(value: f32, noise: f32) -> MuLawSample { MuLawSample(mulaw_encode(i16::from_f32(value, noise * 4.0))) }
+}
+
+
+/// Decode a G.711 A-law sample to 16-bit linear PCM.
+pub fn alaw_decode(a: u8) -> i16 {
+	let a = a ^ 0x55;
+	let mut t = ((a & 0x0F) as i16) << 4;
+	match (a & 0x70) >> 4 {
+		0 => t += 8,
+		1 => t += 0x108,
+		seg => t = (t + 0x108) << (seg - 1),
+	}
+	if a & 0x80 != 0 { t } else { -t }
+}
+
+
+/// Encode a 16-bit linear PCM sample as G.711 A-law.
+pub fn alaw_encode(pcm: i16) -> u8 {
+	const SEG_END: [i16; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+	let mut pcm = pcm >> 3;
+	let mask = if pcm >= 0 {
+		0xD5
+	} else {
+		pcm = -pcm - 1;
+		0x55
+	};
+
+	match SEG_END.iter().position(|&end| pcm <= end) {
+		Some(seg) => {
+			let mant = if seg < 2 { pcm >> 1 } else { pcm >> seg } as u8 & 0x0F;
+			((seg as u8) << 4 | mant) ^ mask
+		},
+		None => 0x7F ^ mask,
+	}
+}
+
+
+/// Decode a G.711 mu-law sample to 16-bit linear PCM.
+pub fn mulaw_decode(u: u8) -> i16 {
+	let u = !u;
+	let t = ((((u & 0x0F) as i16) << 3) + 0x84) << ((u & 0x70) >> 4);
+	if u & 0x80 != 0 { 0x84 - t } else { t - 0x84 }
+}
+
+
+/// Encode a 16-bit linear PCM sample as G.711 mu-law.
+pub fn mulaw_encode(pcm: i16) -> u8 {
+	const SEG_END: [i16; 8] = [0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF, 0x1FFF];
+
+	let mut pcm = pcm >> 2;
+	let mask = if pcm < 0 {
+		pcm = -pcm;
+		0x7F
+	} else {
+		0xFF
+	};
+	pcm = pcm.min(8159) + (0x84 >> 2);
+
+	match SEG_END.iter().position(|&end| pcm <= end) {
+		Some(seg) => ((seg as u8) << 4 | ((pcm >> (seg + 1)) as u8 & 0x0F)) ^ mask,
+		None => 0x7F ^ mask,
+	}
+}
+
+
+macro_rules! convert_frame {
+	($frame:ident, $layout:expr, [$($field:ident),+]) => {
+		impl<S: ConvertSample> ConvertFrame for $frame<S> where $frame<S>: SampleFrame {
+			const LAYOUT: Layout = $layout;
+			const BITS: u32 = S::BITS;
+
+
+			fn read(&self, out: &mut [f32]) {
+				let mut out = out.iter_mut();
+				$(*out.next().unwrap() = self.$field.to_f32();)+
+			}
+
+
+			fn write<N: FnMut() -> f32>(samples: &[f32], mut noise: N) -> $frame<S> {
+				let mut samples = samples.iter();
+				$frame{$($field: S::from_f32(*samples.next().unwrap(), noise()),)+}
+			}
+		}
+	};
+}
+
+
+convert_frame!(Mono, Layout::Mono, [center]);
+convert_frame!(Stereo, Layout::Stereo, [left, right]);
+convert_frame!(McRear, Layout::Rear, [rear]);
+convert_frame!(McQuad, Layout::Quad, [front_left, front_right, back_left, back_right]);
+convert_frame!(Mc51Chn, Layout::Mc51Chn, [front_left, front_right, front_center, low_freq, back_left, back_right]);
+convert_frame!(Mc61Chn, Layout::Mc61Chn, [front_left, front_right, front_center, low_freq, back_left, back_right, back_center]);
+convert_frame!(Mc71Chn, Layout::Mc71Chn, [front_left, front_right, front_center, low_freq, back_left, back_right, side_left, side_right]);
+convert_frame!(BFormat2D, Layout::BFormat2D, [w, x, y]);
+convert_frame!(BFormat3D, Layout::BFormat3D, [w, x, y, z]);
+
+
+/// Describes how `Buffer::set_data_best_effort` adapted data to formats supported by the implementation.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Conversion {
+	/// Format of the data as supplied.
+	pub requested: Format,
+	/// Format of the data as uploaded.
+	pub uploaded: Format,
+	/// Whether the channel layout was mixed down to stereo because no format for the original layout was supported.
+	pub downmixed: bool,
+}
+
+
+impl Conversion {
+	/// Whether the data was uploaded in a different format than supplied.
+	pub fn is_converted(&self) -> bool { self.requested != self.uploaded }
+}
+
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Sample {
+	U8,
+	I16,
+	F32,
+}
+
+
+/// Upload `data` in its own format if supported, or else in the closest supported format.
+/// Float data is kept as float when `AL_EXT_float32` is present and converted to 16 bits otherwise,
+/// companded data is decoded to 16 bits, and layouts without a supported format are mixed down to stereo.
+pub(crate) fn set_data_best_effort<F: ConvertFrame>(buf: &mut Buffer, data: &[F], freq: sys::ALint) -> AltoResult<Conversion> {
+	let samples: &[Sample] = if F::BITS > 16 {
+		&[Sample::F32, Sample::I16]
+	} else if F::BITS == 8 {
+		&[Sample::U8]
+	} else {
+		&[Sample::I16]
+	};
+
+	match buf.set_data(data, freq) {
+		Err(AltoError::ExtensionNotPresent) => (),
+		res => return res.map(|_| Conversion{requested: F::format(), uploaded: F::format(), downmixed: false}),
+	}
+
+	for &layout in &[F::LAYOUT, Layout::Stereo] {
+		for &sample in samples {
+			match set_data_as(buf, data, freq, layout, sample) {
+				Err(AltoError::ExtensionNotPresent) => (),
+				res => return res.map(|uploaded| Conversion{requested: F::format(), uploaded: uploaded, downmixed: layout != F::LAYOUT}),
+			}
+		}
+	}
+
+	Err(AltoError::ExtensionNotPresent)
+}
+
+
+fn set_data_as<S: ConvertFrame>(buf: &mut Buffer, data: &[S], freq: sys::ALint, layout: Layout, sample: Sample) -> AltoResult<Format> {
+	macro_rules! with_sample {
+		($sample:ty) => {
+			match layout {
+				Layout::Mono => set_data_converted::<S, Mono<$sample>>(buf, data, freq),
+				Layout::Stereo => set_data_converted::<S, Stereo<$sample>>(buf, data, freq),
+				Layout::Rear => set_data_converted::<S, McRear<$sample>>(buf, data, freq),
+				Layout::Quad => set_data_converted::<S, McQuad<$sample>>(buf, data, freq),
+				Layout::Mc51Chn => set_data_converted::<S, Mc51Chn<$sample>>(buf, data, freq),
+				Layout::Mc61Chn => set_data_converted::<S, Mc61Chn<$sample>>(buf, data, freq),
+				Layout::Mc71Chn => set_data_converted::<S, Mc71Chn<$sample>>(buf, data, freq),
+				Layout::BFormat2D => set_data_converted::<S, BFormat2D<$sample>>(buf, data, freq),
+				Layout::BFormat3D => set_data_converted::<S, BFormat3D<$sample>>(buf, data, freq),
+			}
+		};
+	}
+
+	match sample {
+		Sample::U8 => with_sample!(u8),
+		Sample::I16 => with_sample!(i16),
+		Sample::F32 => with_sample!(f32),
+	}
+}
+
+
+fn set_data_converted<S: ConvertFrame, D: ConvertFrame>(buf: &mut Buffer, data: &[S], freq: sys::ALint) -> AltoResult<Format> {
+	// Check for support before spending time on conversion.
+	D::format().into_raw(Some(buf.context()))?;
+
+	let frames: Vec<D> = convert(data);
+	buf.set_data(&frames[..], freq).map(|_| D::format())
+}
@@ -0,0 +1,317 @@
+//! Decoding of compressed and container audio formats through symphonia.
+//!
+//! Any container and codec symphonia supports can be loaded fully into a `Buffer`,
+//! or decoded incrementally by a `StreamPlayer`. The frame type is chosen from the channel count of the stream,
+//! using `f32` samples when the stream has more than 16 bits of precision and the context supports `AL_EXT_float32`,
+//! and `i16` samples otherwise.
+
+extern crate symphonia;
+
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::slice;
+
+use self::symphonia::core::audio::SampleBuffer;
+use self::symphonia::core::codecs::{self, CodecParameters, DecoderOptions};
+use self::symphonia::core::conv::ConvertibleSample;
+use self::symphonia::core::errors::Error as SymphoniaError;
+use self::symphonia::core::formats::{FormatOptions, FormatReader, SeekMode, SeekTo};
+use self::symphonia::core::io::{MediaSource, MediaSourceStream};
+use self::symphonia::core::meta::MetadataOptions;
+use self::symphonia::core::probe::Hint;
+use self::symphonia::core::sample::Sample;
+use self::symphonia::core::units::Time;
+
+use ::{AltoError, AltoResult};
+use sys;
+use ext;
+use al::*;
+use stream::{self, StreamPlayer};
+
+
+/// A probed media stream whose frame type has not yet been chosen.
+pub struct MediaFile {
+	format: Box<dyn FormatReader>,
+	decoder: Box<dyn codecs::Decoder>,
+	track: u32,
+	channels: usize,
+	frequency: sys::ALint,
+	bits: Option<u32>,
+}
+
+
+/// A `stream::Decoder` that produces sample frames of type `F` from a symphonia stream.
+pub struct MediaDecoder<F: SampleFrame> {
+	file: MediaFile,
+	/// Samples decoded from the last packet that did not fit in the requested frames.
+	pending: Vec<F::Sample>,
+	/// Number of sample frames to discard after an inexact seek.
+	skip: u64,
+	marker: PhantomData<F>,
+}
+
+
+fn invalid_data<E: ToString>(e: E) -> AltoError {
+	AltoError::Io(io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+
+fn from_symphonia(e: SymphoniaError) -> AltoError {
+	match e {
+		SymphoniaError::IoError(e) => AltoError::Io(e),
+		e => invalid_data(e),
+	}
+}
+
+
+impl MediaFile {
+	/// Open and probe the file at `path`, using its extension as a hint to the container format.
+	pub fn open<P: AsRef<Path>>(path: P) -> AltoResult<MediaFile> {
+		let path = path.as_ref();
+		let ext = path.extension().and_then(|e| e.to_str());
+		MediaFile::from_source(Box::new(File::open(path)?), ext)
+	}
+
+
+	/// Probe `source` for a supported container, optionally hinted by a file extension such as `"ogg"`.
+	/// The first track with a supported codec is selected.
+	pub fn from_source(source: Box<dyn MediaSource>, ext: Option<&str>) -> AltoResult<MediaFile> {
+		let mut hint = Hint::new();
+		if let Some(ext) = ext {
+			hint.with_extension(ext);
+		}
+
+		let mss = MediaSourceStream::new(source, Default::default());
+		let probed = symphonia::default::get_probe().format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default()).map_err(from_symphonia)?;
+		let format = probed.format;
+
+		let (track, params) = format.tracks().iter()
+			.find(|t| t.codec_params.codec != codecs::CODEC_TYPE_NULL)
+			.map(|t| (t.id, t.codec_params.clone()))
+			.ok_or_else(|| invalid_data("no supported audio track"))?;
+		let decoder = symphonia::default::get_codecs().make(&params, &DecoderOptions::default()).map_err(from_symphonia)?;
+
+		let channels = params.channels.map(|c| c.count()).ok_or_else(|| invalid_data("unknown channel layout"))?;
+		let frequency = params.sample_rate.ok_or_else(|| invalid_data("unknown sample rate"))?;
+
+		Ok(MediaFile{
+			format: format,
+			decoder: decoder,
+			track: track,
+			channels: channels,
+			frequency: frequency as sys::ALint,
+			bits: params.bits_per_sample,
+		})
+	}
+
+
+	/// Number of interleaved channels in the stream.
+	pub fn channels(&self) -> usize { self.channels }
+	/// Sample rate of the stream in hertz.
+	pub fn frequency(&self) -> sys::ALint { self.frequency }
+	/// Codec parameters of the selected track.
+	pub fn codec_params(&self) -> &CodecParameters { self.decoder.codec_params() }
+
+
+	/// The format the stream will be uploaded as in `ctx`.
+	/// Multichannel streams require `AL_EXT_MCFORMATS`.
+	pub fn format(&self, ctx: &Context) -> AltoResult<Format> {
+		let float = ctx.is_extension_present(ext::Al::Float32) && self.bits.map(|b| b > 16).unwrap_or(true);
+		let mc = ctx.is_extension_present(ext::Al::McFormats);
+
+		Ok(match (self.channels, float) {
+			(1, false) => Format::Standard(StandardFormat::MonoI16),
+			(1, true) => Format::ExtFloat32(ExtFloat32Format::Mono),
+			(2, false) => Format::Standard(StandardFormat::StereoI16),
+			(2, true) => Format::ExtFloat32(ExtFloat32Format::Stereo),
+			(4, _) | (6, _) | (7, _) | (8, _) if !mc => return Err(AltoError::ExtensionNotPresent),
+			(4, false) => Format::ExtMcFormats(ExtMcFormat::QuadI16),
+			(4, true) => Format::ExtMcFormats(ExtMcFormat::QuadF32),
+			(6, false) => Format::ExtMcFormats(ExtMcFormat::Mc51ChnI16),
+			(6, true) => Format::ExtMcFormats(ExtMcFormat::Mc51ChnF32),
+			(7, false) => Format::ExtMcFormats(ExtMcFormat::Mc61ChnI16),
+			(7, true) => Format::ExtMcFormats(ExtMcFormat::Mc61ChnF32),
+			(8, false) => Format::ExtMcFormats(ExtMcFormat::Mc71ChnI16),
+			(8, true) => Format::ExtMcFormats(ExtMcFormat::Mc71ChnF32),
+			_ => return Err(invalid_data(format!("unsupported channel count {}", self.channels))),
+		})
+	}
+
+
+	/// Decode the entire stream into a new buffer.
+	pub fn load_buffer(self, ctx: &Context) -> AltoResult<Buffer> {
+		fn load<F: SampleFrame>(file: MediaFile, ctx: &Context) -> AltoResult<Buffer> where F::Sample: Sample + ConvertibleSample {
+			let mut decoder = file.into_decoder::<F>()?;
+			let mut frames = Vec::new();
+			loop {
+				let len = frames.len();
+				decoder.read_frames(&mut frames, 1 << 16)?;
+				if frames.len() == len {
+					break;
+				}
+			}
+			ctx.new_buffer(frames, decoder.file.frequency)
+		}
+
+		match self.format(ctx)? {
+			Format::Standard(StandardFormat::MonoI16) => load::<Mono<i16>>(self, ctx),
+			Format::ExtFloat32(ExtFloat32Format::Mono) => load::<Mono<f32>>(self, ctx),
+			Format::Standard(StandardFormat::StereoI16) => load::<Stereo<i16>>(self, ctx),
+			Format::ExtFloat32(ExtFloat32Format::Stereo) => load::<Stereo<f32>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::QuadI16) => load::<McQuad<i16>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::QuadF32) => load::<McQuad<f32>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::Mc51ChnI16) => load::<Mc51Chn<i16>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::Mc51ChnF32) => load::<Mc51Chn<f32>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::Mc61ChnI16) => load::<Mc61Chn<i16>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::Mc61ChnF32) => load::<Mc61Chn<f32>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::Mc71ChnI16) => load::<Mc71Chn<i16>>(self, ctx),
+			Format::ExtMcFormats(ExtMcFormat::Mc71ChnF32) => load::<Mc71Chn<f32>>(self, ctx),
+			_ => unreachable!(),
+		}
+	}
+
+
+	/// Stream the file through `src` with a new `StreamPlayer`. See `StreamPlayer::new`.
+	pub fn stream(self, src: StreamingSource, buffer_frames: usize, buffer_count: usize) -> AltoResult<StreamPlayer> {
+		fn play<F: SampleFrame + Send>(file: MediaFile, src: StreamingSource, buffer_frames: usize, buffer_count: usize) -> AltoResult<StreamPlayer> where F::Sample: Sample + ConvertibleSample + Send {
+			StreamPlayer::new(src, file.into_decoder::<F>()?, buffer_frames, buffer_count)
+		}
+
+		match self.format(src.context())? {
+			Format::Standard(StandardFormat::MonoI16) => play::<Mono<i16>>(self, src, buffer_frames, buffer_count),
+			Format::ExtFloat32(ExtFloat32Format::Mono) => play::<Mono<f32>>(self, src, buffer_frames, buffer_count),
+			Format::Standard(StandardFormat::StereoI16) => play::<Stereo<i16>>(self, src, buffer_frames, buffer_count),
+			Format::ExtFloat32(ExtFloat32Format::Stereo) => play::<Stereo<f32>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::QuadI16) => play::<McQuad<i16>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::QuadF32) => play::<McQuad<f32>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::Mc51ChnI16) => play::<Mc51Chn<i16>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::Mc51ChnF32) => play::<Mc51Chn<f32>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::Mc61ChnI16) => play::<Mc61Chn<i16>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::Mc61ChnF32) => play::<Mc61Chn<f32>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::Mc71ChnI16) => play::<Mc71Chn<i16>>(self, src, buffer_frames, buffer_count),
+			Format::ExtMcFormats(ExtMcFormat::Mc71ChnF32) => play::<Mc71Chn<f32>>(self, src, buffer_frames, buffer_count),
+			_ => unreachable!(),
+		}
+	}
+
+
+	/// Decode into sample frames of a specific type, which must have the same number of channels as the stream.
+	pub fn into_decoder<F: SampleFrame>(self) -> AltoResult<MediaDecoder<F>> where F::Sample: Sample + ConvertibleSample {
+		if F::len() != self.channels {
+			return Err(AltoError::InvalidValue);
+		}
+
+		Ok(MediaDecoder{
+			file: self,
+			pending: Vec::new(),
+			skip: 0,
+			marker: PhantomData,
+		})
+	}
+}
+
+
+impl<F: SampleFrame> MediaDecoder<F> where F::Sample: Sample + ConvertibleSample {
+	/// The underlying stream.
+	pub fn file(&self) -> &MediaFile { &self.file }
+
+
+	/// Append at most `max` decoded frames to `frames`. Appending nothing signals the end of the stream.
+	pub fn read_frames(&mut self, frames: &mut Vec<F>, max: usize) -> AltoResult<()> {
+		let mut decoded = 0;
+		while decoded < max {
+			if self.pending.is_empty() && !self.decode_packet()? {
+				break;
+			}
+
+			let len = (self.pending.len() / F::len()).min(max - decoded);
+			// Sample frames are `repr(C)` structs of `F::len()` samples, matching the interleaved layout.
+			let src = unsafe { slice::from_raw_parts(self.pending.as_ptr() as *const F, len) };
+			frames.extend_from_slice(src);
+			self.pending.drain(.. len * F::len());
+			decoded += len;
+		}
+
+		Ok(())
+	}
+
+
+	/// Decode the next packet of the selected track into `pending`. Returns `false` at the end of the stream.
+	fn decode_packet(&mut self) -> AltoResult<bool> {
+		loop {
+			let packet = match self.file.format.next_packet() {
+				Ok(packet) => packet,
+				Err(SymphoniaError::IoError(ref e)) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+				// Chained Ogg streams start a new logical stream here; the decoder is reset and decoding carries on.
+				Err(SymphoniaError::ResetRequired) => {
+					self.file.decoder.reset();
+					continue;
+				},
+				Err(e) => return Err(from_symphonia(e)),
+			};
+			if packet.track_id() != self.file.track {
+				continue;
+			}
+
+			let decoded = match self.file.decoder.decode(&packet) {
+				Ok(decoded) => decoded,
+				// Corrupt packets are skipped rather than ending the stream.
+				Err(SymphoniaError::DecodeError(_)) => continue,
+				Err(SymphoniaError::ResetRequired) => {
+					self.file.decoder.reset();
+					continue;
+				},
+				Err(e) => return Err(from_symphonia(e)),
+			};
+			if decoded.spec().channels.count() != self.file.channels {
+				return Err(invalid_data("channel layout changed mid-stream"));
+			}
+
+			let mut samples = SampleBuffer::<F::Sample>::new(decoded.capacity() as u64, *decoded.spec());
+			samples.copy_interleaved_ref(decoded);
+			let mut samples = samples.samples();
+
+			let skip = (self.skip as usize).min(samples.len() / F::len());
+			self.skip -= skip as u64;
+			samples = &samples[skip * F::len() ..];
+
+			self.pending.extend_from_slice(samples);
+			return Ok(true);
+		}
+	}
+}
+
+
+impl<F: SampleFrame + Send> stream::Decoder for MediaDecoder<F> where F::Sample: Sample + ConvertibleSample + Send {
+	type Frame = F;
+
+
+	fn frequency(&self) -> sys::ALint { self.file.frequency }
+
+
+	fn decode(&mut self, frames: &mut Vec<F>, max: usize) -> AltoResult<()> {
+		self.read_frames(frames, max)
+	}
+
+
+	fn seek(&mut self, frame: u64) -> AltoResult<()> {
+		let freq = self.file.frequency as u64;
+		let time = Time::new(frame / freq, (frame % freq) as f64 / freq as f64);
+		let seeked = self.file.format.seek(SeekMode::Accurate, SeekTo::Time{time: time, track_id: Some(self.file.track)}).map_err(from_symphonia)?;
+		self.file.decoder.reset();
+		self.pending.clear();
+
+		// The demuxer may land before the requested position, in which case the difference is decoded and discarded.
+		self.skip = match self.file.decoder.codec_params().time_base {
+			Some(tb) => {
+				let delta = tb.calc_time(seeked.required_ts.saturating_sub(seeked.actual_ts));
+				delta.seconds * freq + (delta.frac * freq as f64).round() as u64
+			},
+			None => 0,
+		};
+		Ok(())
+	}
+}
+
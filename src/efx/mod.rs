@@ -1,4 +1,6 @@
 use std::sync::Weak;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 use ::{AltoError, AltoResult};
 use sys;
@@ -7,8 +9,10 @@ use ext;
 
 
 mod presets;
+mod snapshot;
 
 pub use self::presets::*;
+pub use self::snapshot::*;
 
 
 /// An aux effect slot as provided by EFX.
@@ -53,6 +57,7 @@ pub struct ChorusEffect {
 
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChorusWaveform {
 	Sinusoid,
 	Triangle,
@@ -82,6 +87,7 @@ pub struct FlangerEffect {
 
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FlangerWaveform {
 	Sinusoid,
 	Triangle,
@@ -97,6 +103,7 @@ pub struct FrequencyShifterEffect {
 
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FrequencyShifterDirection {
 	Down,
 	Up,
@@ -113,6 +120,7 @@ pub struct VocalMorpherEffect {
 
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VocalMorpherPhoneme {
 	A,
 	E,
@@ -149,6 +157,7 @@ pub enum VocalMorpherPhoneme {
 
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VocalMorpherWaveform {
 	Sinusoid,
 	Triangle,
@@ -172,6 +181,7 @@ pub struct RingModulatorEffect {
 
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RingModulatorWaveform {
 	Sinusoid,
 	Sawtooth,
@@ -446,7 +456,7 @@ impl AuxEffectSlot {
 			efx.AL_EFFECTSLOT_GAIN?;
 			efx.AL_EFFECTSLOT_AUXILIARY_SEND_AUTO?;
 			let _lock = ctx.make_current(true);
-			unsafe { efx.alGenAuxiliaryEffectSlots?(1, &mut slot); }
+			unsafe { ctx.0.dev.0.alto.0.api.alGenAuxiliaryEffectSlots(efx.alGenAuxiliaryEffectSlots?, 1, &mut slot); }
 			ctx.get_error()?;
 		}
 		Ok(AuxEffectSlot{ctx: ctx, slot: slot, inputs: Vec::new()})
@@ -475,14 +485,14 @@ impl AuxEffectSlot {
 		}
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alAuxiliaryEffectSloti.unwrap()(self.slot, efx.AL_EFFECTSLOT_EFFECT.unwrap(), value.as_raw() as sys::ALint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alAuxiliaryEffectSloti(efx.alAuxiliaryEffectSloti.unwrap(), self.slot, efx.AL_EFFECTSLOT_EFFECT.unwrap(), value.as_raw() as sys::ALint); }
 		self.ctx.get_error()
 	}
 	/// `alAuxiliaryEffectSloti(AL_EFFECTSLOT_EFFECT)`
 	pub fn clear_effect(&mut self) {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alAuxiliaryEffectSloti.unwrap()(self.slot, efx.AL_EFFECTSLOT_EFFECT.unwrap(), 0); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alAuxiliaryEffectSloti(efx.alAuxiliaryEffectSloti.unwrap(), self.slot, efx.AL_EFFECTSLOT_EFFECT.unwrap(), 0); }
 	}
 
 
@@ -491,14 +501,14 @@ impl AuxEffectSlot {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetAuxiliaryEffectSlotf.unwrap()(self.slot, efx.AL_EFFECTSLOT_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetAuxiliaryEffectSlotf(efx.alGetAuxiliaryEffectSlotf.unwrap(), self.slot, efx.AL_EFFECTSLOT_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alAuxiliaryEffectSloti(AL_EFFECTSLOT_GAIN)`
 	pub fn set_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alAuxiliaryEffectSlotf.unwrap()(self.slot, efx.AL_EFFECTSLOT_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alAuxiliaryEffectSlotf(efx.alAuxiliaryEffectSlotf.unwrap(), self.slot, efx.AL_EFFECTSLOT_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -508,14 +518,14 @@ impl AuxEffectSlot {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetAuxiliaryEffectSloti.unwrap()(self.slot, efx.AL_EFFECTSLOT_AUXILIARY_SEND_AUTO.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetAuxiliaryEffectSloti(efx.alGetAuxiliaryEffectSloti.unwrap(), self.slot, efx.AL_EFFECTSLOT_AUXILIARY_SEND_AUTO.unwrap(), &mut value); }
 		value == sys::AL_TRUE as sys::ALint
 	}
 	/// `alAuxiliaryEffectSloti(AL_EFFECTSLOT_AUXILIARY_SEND_AUTO)`
 	pub fn set_aux_send_auto(&mut self, value: bool) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alAuxiliaryEffectSloti.unwrap()(self.slot, efx.AL_EFFECTSLOT_AUXILIARY_SEND_AUTO.unwrap(), if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alAuxiliaryEffectSloti(efx.alAuxiliaryEffectSloti.unwrap(), self.slot, efx.AL_EFFECTSLOT_AUXILIARY_SEND_AUTO.unwrap(), if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
 		self.ctx.get_error()
 	}
 }
@@ -532,7 +542,7 @@ impl Drop for AuxEffectSlot {
 		}
 
 		let adaes = efx.alDeleteAuxiliaryEffectSlots.unwrap();
-		unsafe { adaes(1, &mut self.slot as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteAuxiliaryEffectSlots(adaes, 1, &mut self.slot as *mut sys::ALuint); }
 	}
 }
 
@@ -582,8 +592,8 @@ unsafe impl Effect for EaxReverbEffect {
 			efx.AL_EAXREVERB_DECAY_HFLIMIT?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_EAXREVERB?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_EAXREVERB?);
 			}
 			ctx.get_error()?;
 		}
@@ -636,14 +646,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DENSITY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DENSITY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_DENSITY)`
 	pub fn set_density(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DENSITY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DENSITY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -653,14 +663,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DIFFUSION.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DIFFUSION.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_DIFFUSION)`
 	pub fn set_diffusion(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DIFFUSION.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DIFFUSION.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -670,14 +680,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_GAIN)`
 	pub fn set_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -687,14 +697,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_GAINHF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_GAINHF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_GAINHF)`
 	pub fn set_gainhf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_GAINHF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_GAINHF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -704,14 +714,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_GAINLF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_GAINLF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_GAINLF)`
 	pub fn set_gainlf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_GAINLF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_GAINLF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -721,14 +731,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_TIME.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_TIME.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_DECAY_TIME)`
 	pub fn set_decay_time(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_TIME.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_TIME.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -738,14 +748,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_HFRATIO.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_HFRATIO.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_DECAY_HFRATIO)`
 	pub fn set_decay_hfratio(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_HFRATIO.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_HFRATIO.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -755,14 +765,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_LFRATIO.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_LFRATIO.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_DECAY_LFRATIO)`
 	pub fn set_decay_lfratio(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_LFRATIO.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_LFRATIO.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -772,14 +782,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_REFLECTIONS_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_REFLECTIONS_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_REFLECTIONS_GAIN)`
 	pub fn set_reflections_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_REFLECTIONS_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_REFLECTIONS_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -789,14 +799,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_REFLECTIONS_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_REFLECTIONS_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_REFLECTIONS_DELAY)`
 	pub fn set_reflections_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_REFLECTIONS_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_REFLECTIONS_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -806,14 +816,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = [0.0, 0.0, 0.0];
-		unsafe { efx.alGetEffectfv.unwrap()(self.effect, efx.AL_EAXREVERB_REFLECTIONS_PAN.unwrap(), &mut value as *mut [f32; 3] as *mut f32); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectfv(efx.alGetEffectfv.unwrap(), self.effect, efx.AL_EAXREVERB_REFLECTIONS_PAN.unwrap(), &mut value as *mut [f32; 3] as *mut f32); }
 		value.into()
 	}
 	/// `alEffectfv(AL_EAXREVERB_REFLECTIONS_PAN)`
 	pub fn set_reflections_pan<V: Into<[f32; 3]>>(&mut self, value: V) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectfv.unwrap()(self.effect, efx.AL_EAXREVERB_REFLECTIONS_PAN.unwrap(), &mut value.into() as *mut [f32; 3] as *mut f32); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectfv(efx.alEffectfv.unwrap(), self.effect, efx.AL_EAXREVERB_REFLECTIONS_PAN.unwrap(), &mut value.into() as *mut [f32; 3] as *mut f32); }
 		self.ctx.get_error()
 	}
 
@@ -823,14 +833,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_LATE_REVERB_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_LATE_REVERB_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_LATE_REVERB_GAIN)`
 	pub fn set_late_reverb_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_LATE_REVERB_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_LATE_REVERB_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -840,14 +850,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_LATE_REVERB_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_LATE_REVERB_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_LATE_REVERB_DELAY)`
 	pub fn set_late_reverb_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_LATE_REVERB_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_LATE_REVERB_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -857,14 +867,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = [0.0, 0.0, 0.0];
-		unsafe { efx.alGetEffectfv.unwrap()(self.effect, efx.AL_EAXREVERB_LATE_REVERB_PAN.unwrap(), &mut value as *mut [f32; 3] as *mut f32); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectfv(efx.alGetEffectfv.unwrap(), self.effect, efx.AL_EAXREVERB_LATE_REVERB_PAN.unwrap(), &mut value as *mut [f32; 3] as *mut f32); }
 		value.into()
 	}
 	/// `alEffectfv(AL_EAXREVERB_LATE_REVERB_PAN)`
 	pub fn set_late_reverb_pan<V: Into<[f32; 3]>>(&mut self, value: V) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectfv.unwrap()(self.effect, efx.AL_EAXREVERB_LATE_REVERB_PAN.unwrap(), &mut value.into() as *mut [f32; 3] as *mut f32); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectfv(efx.alEffectfv.unwrap(), self.effect, efx.AL_EAXREVERB_LATE_REVERB_PAN.unwrap(), &mut value.into() as *mut [f32; 3] as *mut f32); }
 		self.ctx.get_error()
 	}
 
@@ -874,14 +884,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_ECHO_TIME.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_ECHO_TIME.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_ECHO_TIME)`
 	pub fn set_echo_time(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_ECHO_TIME.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_ECHO_TIME.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -891,14 +901,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_ECHO_DEPTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_ECHO_DEPTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_ECHO_DEPTH)`
 	pub fn set_echo_depth(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_ECHO_DEPTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_ECHO_DEPTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -908,14 +918,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_MODULATION_TIME.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_MODULATION_TIME.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_MODULATION_TIME)`
 	pub fn set_modulation_time(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_MODULATION_TIME.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_MODULATION_TIME.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -925,14 +935,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_MODULATION_DEPTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_MODULATION_DEPTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_MODULATION_DEPTH)`
 	pub fn set_modulation_depth(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_MODULATION_DEPTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_MODULATION_DEPTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -942,14 +952,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_HFREFERENCE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_HFREFERENCE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_HFREFERENCE)`
 	pub fn set_hfreference(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_HFREFERENCE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_HFREFERENCE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -959,14 +969,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_LFREFERENCE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_LFREFERENCE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_LFREFERENCE)`
 	pub fn set_lfreference(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_LFREFERENCE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_LFREFERENCE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -976,14 +986,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_AIR_ABSORPTION_GAINHF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_AIR_ABSORPTION_GAINHF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_AIR_ABSORPTION_GAINHF)`
 	pub fn set_air_absorption_gainhf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_AIR_ABSORPTION_GAINHF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_AIR_ABSORPTION_GAINHF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -993,14 +1003,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_ROOM_ROLLOFF_FACTOR.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_ROOM_ROLLOFF_FACTOR.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EAXREVERB_ROOM_ROLLOFF_FACTOR)`
 	pub fn set_room_rolloff_factor(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EAXREVERB_ROOM_ROLLOFF_FACTOR.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EAXREVERB_ROOM_ROLLOFF_FACTOR.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1010,14 +1020,14 @@ impl EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_HFLIMIT.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_HFLIMIT.unwrap(), &mut value); }
 		value == sys::AL_TRUE as sys::ALint
 	}
 	/// `alEffecti(AL_EAXREVERB_DECAY_HFLIMIT)`
 	pub fn set_decay_hflimit(&mut self, value: bool) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_EAXREVERB_DECAY_HFLIMIT.unwrap(), if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_EAXREVERB_DECAY_HFLIMIT.unwrap(), if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
 		self.ctx.get_error()
 	}
 }
@@ -1028,7 +1038,7 @@ impl Drop for EaxReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1054,8 +1064,8 @@ unsafe impl Effect for ReverbEffect {
 			efx.AL_REVERB_DECAY_HFLIMIT?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_REVERB?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_REVERB?);
 			}
 			ctx.get_error()?;
 		}
@@ -1098,14 +1108,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_DENSITY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_DENSITY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_DENSITY)`
 	pub fn set_density(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_DENSITY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_DENSITY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1115,14 +1125,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_DIFFUSION.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_DIFFUSION.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_DIFFUSION)`
 	pub fn set_diffusion(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_DIFFUSION.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_DIFFUSION.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1132,14 +1142,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_GAIN)`
 	pub fn set_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1149,14 +1159,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_GAINHF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_GAINHF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_GAINHF)`
 	pub fn set_gainhf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_GAINHF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_GAINHF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1166,14 +1176,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_DECAY_TIME.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_DECAY_TIME.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_DECAY_TIME)`
 	pub fn set_decay_time(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_DECAY_TIME.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_DECAY_TIME.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1183,14 +1193,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_DECAY_HFRATIO.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_DECAY_HFRATIO.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_DECAY_HFRATIO)`
 	pub fn set_decay_hfratio(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_DECAY_HFRATIO.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_DECAY_HFRATIO.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1200,14 +1210,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_REFLECTIONS_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_REFLECTIONS_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_REFLECTIONS_GAIN)`
 	pub fn set_reflections_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_REFLECTIONS_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_REFLECTIONS_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1217,14 +1227,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_REFLECTIONS_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_REFLECTIONS_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_REFLECTIONS_DELAY)`
 	pub fn set_reflections_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_REFLECTIONS_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_REFLECTIONS_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1234,14 +1244,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_LATE_REVERB_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_LATE_REVERB_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_LATE_REVERB_GAIN)`
 	pub fn set_late_reverb_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_LATE_REVERB_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_LATE_REVERB_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1251,14 +1261,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_LATE_REVERB_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_LATE_REVERB_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_LATE_REVERB_DELAY)`
 	pub fn set_late_reverb_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_LATE_REVERB_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_LATE_REVERB_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1268,14 +1278,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_AIR_ABSORPTION_GAINHF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_AIR_ABSORPTION_GAINHF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_AIR_ABSORPTION_GAINHF)`
 	pub fn set_air_absorption_gainhf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_AIR_ABSORPTION_GAINHF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_AIR_ABSORPTION_GAINHF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1285,14 +1295,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_REVERB_ROOM_ROLLOFF_FACTOR.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_REVERB_ROOM_ROLLOFF_FACTOR.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_REVERB_ROOM_ROLLOFF_FACTOR)`
 	pub fn set_room_rolloff_factor(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_REVERB_ROOM_ROLLOFF_FACTOR.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_REVERB_ROOM_ROLLOFF_FACTOR.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1302,14 +1312,14 @@ impl ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_REVERB_DECAY_HFLIMIT.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_REVERB_DECAY_HFLIMIT.unwrap(), &mut value); }
 		value == sys::AL_TRUE as sys::ALint
 	}
 	/// `alEffecti(AL_REVERB_DECAY_HFLIMIT)`
 	pub fn set_decay_hflimit(&mut self, value: bool) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_REVERB_DECAY_HFLIMIT.unwrap(), if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_REVERB_DECAY_HFLIMIT.unwrap(), if value { sys::AL_TRUE } else { sys::AL_FALSE } as sys::ALint); }
 		self.ctx.get_error()
 	}
 }
@@ -1320,7 +1330,7 @@ impl Drop for ReverbEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1339,8 +1349,8 @@ unsafe impl Effect for ChorusEffect {
 			efx.AL_CHORUS_DELAY?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_CHORUS?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_CHORUS?);
 			}
 			ctx.get_error()?;
 		}
@@ -1361,14 +1371,14 @@ impl ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_CHORUS_WAVEFORM.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_CHORUS_WAVEFORM.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_CHORUS_WAVEFORM)`
 	pub fn set_waveform(&mut self, value: ChorusWaveform) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_CHORUS_WAVEFORM.unwrap(), value.into()) };
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_CHORUS_WAVEFORM.unwrap(), value.into()) };
 		self.ctx.get_error()
 	}
 
@@ -1378,14 +1388,14 @@ impl ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_CHORUS_PHASE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_CHORUS_PHASE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffecti(AL_CHORUS_PHASE)`
 	pub fn set_phase(&mut self, value: sys::ALint) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_CHORUS_PHASE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_CHORUS_PHASE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1395,14 +1405,14 @@ impl ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_CHORUS_RATE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_CHORUS_RATE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_CHORUS_RATE)`
 	pub fn set_rate(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_CHORUS_RATE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_CHORUS_RATE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1412,14 +1422,14 @@ impl ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_CHORUS_DEPTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_CHORUS_DEPTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_CHORUS_DEPTH)`
 	pub fn set_depth(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_CHORUS_DEPTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_CHORUS_DEPTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1429,14 +1439,14 @@ impl ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_CHORUS_FEEDBACK.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_CHORUS_FEEDBACK.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_CHORUS_FEEDBACK)`
 	pub fn set_feedback(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_CHORUS_FEEDBACK.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_CHORUS_FEEDBACK.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1446,14 +1456,14 @@ impl ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_CHORUS_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_CHORUS_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_CHORUS_DELAY)`
 	pub fn set_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_CHORUS_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_CHORUS_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -1464,7 +1474,7 @@ impl Drop for ChorusEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1482,8 +1492,8 @@ unsafe impl Effect for DistortionEffect {
 			efx.AL_DISTORTION_EQBANDWIDTH?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_DISTORTION?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_DISTORTION?);
 			}
 			ctx.get_error()?;
 		}
@@ -1504,14 +1514,14 @@ impl DistortionEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_DISTORTION_EDGE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_DISTORTION_EDGE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_DISTORTION_EDGE)`
 	pub fn set_edge(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_DISTORTION_EDGE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_DISTORTION_EDGE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1521,14 +1531,14 @@ impl DistortionEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_DISTORTION_LOWPASS_CUTOFF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_DISTORTION_LOWPASS_CUTOFF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_DISTORTION_LOWPASS_CUTOFF)`
 	pub fn set_lowpass_cutoff(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_DISTORTION_LOWPASS_CUTOFF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_DISTORTION_LOWPASS_CUTOFF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1538,14 +1548,14 @@ impl DistortionEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_DISTORTION_EQCENTER.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_DISTORTION_EQCENTER.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_DISTORTION_EQCENTER)`
 	pub fn set_eqcenter(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_DISTORTION_EQCENTER.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_DISTORTION_EQCENTER.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1555,14 +1565,14 @@ impl DistortionEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_DISTORTION_EQBANDWIDTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_DISTORTION_EQBANDWIDTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_DISTORTION_EQBANDWIDTH)`
 	pub fn set_eqbandwidth(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_DISTORTION_EQBANDWIDTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_DISTORTION_EQBANDWIDTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -1573,7 +1583,7 @@ impl Drop for DistortionEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1591,8 +1601,8 @@ unsafe impl Effect for EchoEffect {
 			efx.AL_ECHO_SPREAD?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_ECHO?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_ECHO?);
 			}
 			ctx.get_error()?;
 		}
@@ -1613,14 +1623,14 @@ impl EchoEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_ECHO_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_ECHO_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_ECHO_DELAY)`
 	pub fn set_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_ECHO_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_ECHO_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1630,14 +1640,14 @@ impl EchoEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_ECHO_LRDELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_ECHO_LRDELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_ECHO_LRDELAY)`
 	pub fn set_lrdelay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_ECHO_LRDELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_ECHO_LRDELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1647,14 +1657,14 @@ impl EchoEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_ECHO_DAMPING.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_ECHO_DAMPING.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_ECHO_DAMPING)`
 	pub fn set_damping(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_ECHO_DAMPING.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_ECHO_DAMPING.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1664,14 +1674,14 @@ impl EchoEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_ECHO_FEEDBACK.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_ECHO_FEEDBACK.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_ECHO_FEEDBACK)`
 	pub fn set_feedback(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_ECHO_FEEDBACK.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_ECHO_FEEDBACK.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1681,14 +1691,14 @@ impl EchoEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_ECHO_SPREAD.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_ECHO_SPREAD.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_ECHO_SPREAD)`
 	pub fn set_spread(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_ECHO_SPREAD.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_ECHO_SPREAD.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -1699,7 +1709,7 @@ impl Drop for EchoEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1718,8 +1728,8 @@ unsafe impl Effect for FlangerEffect {
 			efx.AL_FLANGER_DELAY?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_FLANGER?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_FLANGER?);
 			}
 			ctx.get_error()?;
 		}
@@ -1740,14 +1750,14 @@ impl FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_FLANGER_WAVEFORM.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_FLANGER_WAVEFORM.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_FLANGER_WAVEFORM)`
 	pub fn set_waveform(&mut self, value: FlangerWaveform) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_FLANGER_WAVEFORM.unwrap(), value.into()) };
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_FLANGER_WAVEFORM.unwrap(), value.into()) };
 		self.ctx.get_error()
 	}
 
@@ -1757,14 +1767,14 @@ impl FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_FLANGER_PHASE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_FLANGER_PHASE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffecti(AL_FLANGER_PHASE)`
 	pub fn set_phase(&mut self, value: sys::ALint) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_FLANGER_PHASE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_FLANGER_PHASE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1774,14 +1784,14 @@ impl FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_FLANGER_RATE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_FLANGER_RATE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_FLANGER_RATE)`
 	pub fn set_rate(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_FLANGER_RATE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_FLANGER_RATE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1791,14 +1801,14 @@ impl FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_FLANGER_DEPTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_FLANGER_DEPTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_FLANGER_DEPTH)`
 	pub fn set_depth(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_FLANGER_DEPTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_FLANGER_DEPTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1808,14 +1818,14 @@ impl FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_FLANGER_FEEDBACK.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_FLANGER_FEEDBACK.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_FLANGER_FEEDBACK)`
 	pub fn set_feedback(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_FLANGER_FEEDBACK.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_FLANGER_FEEDBACK.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1825,14 +1835,14 @@ impl FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_FLANGER_DELAY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_FLANGER_DELAY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_FLANGER_DELAY)`
 	pub fn set_delay(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_FLANGER_DELAY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_FLANGER_DELAY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -1843,7 +1853,7 @@ impl Drop for FlangerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1859,8 +1869,8 @@ unsafe impl Effect for FrequencyShifterEffect {
 			efx.AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_FREQUENCY_SHIFTER?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_FREQUENCY_SHIFTER?);
 			}
 			ctx.get_error()?;
 		}
@@ -1881,14 +1891,14 @@ impl FrequencyShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_FREQUENCY_SHIFTER_FREQUENCY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_FREQUENCY_SHIFTER_FREQUENCY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_FREQUENCY_SHIFTER_FREQUENCY)`
 	pub fn set_frequency(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_FREQUENCY_SHIFTER_FREQUENCY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_FREQUENCY_SHIFTER_FREQUENCY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -1898,14 +1908,14 @@ impl FrequencyShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_FREQUENCY_SHIFTER_LEFT_DIRECTION.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_FREQUENCY_SHIFTER_LEFT_DIRECTION.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_FREQUENCY_SHIFTER_LEFT_DIRECTION)`
 	pub fn set_left_direction(&mut self, value: FrequencyShifterDirection) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_FREQUENCY_SHIFTER_LEFT_DIRECTION.unwrap(), value.into()); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_FREQUENCY_SHIFTER_LEFT_DIRECTION.unwrap(), value.into()); }
 		self.ctx.get_error()
 	}
 
@@ -1915,14 +1925,14 @@ impl FrequencyShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION)`
 	pub fn set_right_direction(&mut self, value: FrequencyShifterDirection) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION.unwrap(), value.into()); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION.unwrap(), value.into()); }
 		self.ctx.get_error()
 	}
 }
@@ -1933,7 +1943,7 @@ impl Drop for FrequencyShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -1952,8 +1962,8 @@ unsafe impl Effect for VocalMorpherEffect {
 			efx.AL_VOCAL_MORPHER_RATE?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_VOCAL_MORPHER?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_VOCAL_MORPHER?);
 			}
 			ctx.get_error()?;
 		}
@@ -1974,14 +1984,14 @@ impl VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_VOCAL_MORPHER_PHONEMEA)`
 	pub fn set_phonemea(&mut self, value: VocalMorpherPhoneme) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA.unwrap(), value.into()); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA.unwrap(), value.into()); }
 		self.ctx.get_error()
 	}
 
@@ -1991,14 +2001,14 @@ impl VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_VOCAL_MORPHER_PHONEMEB)`
 	pub fn set_phonemeb(&mut self, value: VocalMorpherPhoneme) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB.unwrap(), value.into()); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB.unwrap(), value.into()); }
 		self.ctx.get_error()
 	}
 
@@ -2008,14 +2018,14 @@ impl VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffecti(AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING)`
 	pub fn set_phonemea_coarse_tuning(&mut self, value: sys::ALint) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2025,14 +2035,14 @@ impl VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffecti(AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING)`
 	pub fn set_phonemeb_coarse_tuning(&mut self, value: sys::ALint) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2042,14 +2052,14 @@ impl VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_WAVEFORM.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_WAVEFORM.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_VOCAL_MORPHER_WAVEFORM)`
 	pub fn set_waveform(&mut self, value: VocalMorpherWaveform) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_WAVEFORM.unwrap(), value.into()); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_WAVEFORM.unwrap(), value.into()); }
 		self.ctx.get_error()
 	}
 
@@ -2059,14 +2069,14 @@ impl VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_RATE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_RATE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_VOCAL_MORPHER_RATE)`
 	pub fn set_rate(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_VOCAL_MORPHER_RATE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_VOCAL_MORPHER_RATE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2077,7 +2087,7 @@ impl Drop for VocalMorpherEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2092,8 +2102,8 @@ unsafe impl Effect for PitchShifterEffect {
 			efx.AL_PITCH_SHIFTER_FINE_TUNE?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_PITCH_SHIFTER?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_PITCH_SHIFTER?);
 			}
 			ctx.get_error()?;
 		}
@@ -2114,14 +2124,14 @@ impl PitchShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_PITCH_SHIFTER_COARSE_TUNE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_PITCH_SHIFTER_COARSE_TUNE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffecti(AL_PITCH_SHIFTER_COARSE_TUNE)`
 	pub fn set_coarse_tune(&mut self, value: sys::ALint) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_PITCH_SHIFTER_COARSE_TUNE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_PITCH_SHIFTER_COARSE_TUNE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2131,14 +2141,14 @@ impl PitchShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_PITCH_SHIFTER_FINE_TUNE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_PITCH_SHIFTER_FINE_TUNE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffecti(AL_PITCH_SHIFTER_FINE_TUNE)`
 	pub fn set_fine_tune(&mut self, value: sys::ALint) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_PITCH_SHIFTER_FINE_TUNE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_PITCH_SHIFTER_FINE_TUNE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2149,7 +2159,7 @@ impl Drop for PitchShifterEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2165,8 +2175,8 @@ unsafe impl Effect for RingModulatorEffect {
 			efx.AL_RING_MODULATOR_WAVEFORM?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_RING_MODULATOR?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_RING_MODULATOR?);
 			}
 			ctx.get_error()?;
 		}
@@ -2187,14 +2197,14 @@ impl RingModulatorEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_RING_MODULATOR_FREQUENCY.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_RING_MODULATOR_FREQUENCY.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_RING_MODULATOR_FREQUENCY)`
 	pub fn set_frequency(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_RING_MODULATOR_FREQUENCY.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_RING_MODULATOR_FREQUENCY.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2204,14 +2214,14 @@ impl RingModulatorEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_RING_MODULATOR_HIGHPASS_CUTOFF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_RING_MODULATOR_HIGHPASS_CUTOFF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_RING_MODULATOR_HIGHPASS_CUTOFF)`
 	pub fn set_highpass_cutoff(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_RING_MODULATOR_HIGHPASS_CUTOFF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_RING_MODULATOR_HIGHPASS_CUTOFF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2221,14 +2231,14 @@ impl RingModulatorEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_RING_MODULATOR_WAVEFORM.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_RING_MODULATOR_WAVEFORM.unwrap(), &mut value); }
 		value.into()
 	}
 	/// `alEffecti(AL_RING_MODULATOR_WAVEFORM)`
 	pub fn set_waveform(&mut self, value: RingModulatorWaveform) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_RING_MODULATOR_WAVEFORM.unwrap(), value.into()) };
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_RING_MODULATOR_WAVEFORM.unwrap(), value.into()) };
 		self.ctx.get_error()
 	}
 }
@@ -2239,7 +2249,7 @@ impl Drop for RingModulatorEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2256,8 +2266,8 @@ unsafe impl Effect for AutowahEffect {
 			efx.AL_AUTOWAH_PEAK_GAIN?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_AUTOWAH?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_AUTOWAH?);
 			}
 			ctx.get_error()?;
 		}
@@ -2278,14 +2288,14 @@ impl AutowahEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_ATTACK_TIME.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_ATTACK_TIME.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_AUTOWAH_ATTACK_TIME)`
 	pub fn set_attack_time(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_ATTACK_TIME.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_ATTACK_TIME.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2295,14 +2305,14 @@ impl AutowahEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_RELEASE_TIME.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_RELEASE_TIME.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_AUTOWAH_RELEASE_TIME)`
 	pub fn set_release_time(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_RELEASE_TIME.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_RELEASE_TIME.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2312,14 +2322,14 @@ impl AutowahEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_RESONANCE.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_RESONANCE.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_AUTOWAH_RESONANCE)`
 	pub fn set_resonance(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_RESONANCE.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_RESONANCE.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2329,14 +2339,14 @@ impl AutowahEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_PEAK_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_PEAK_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_AUTOWAH_PEAK_GAIN)`
 	pub fn set_peak_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_AUTOWAH_PEAK_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_AUTOWAH_PEAK_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2347,7 +2357,7 @@ impl Drop for AutowahEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2361,8 +2371,8 @@ unsafe impl Effect for CompressorEffect {
 			efx.AL_COMPRESSOR_ONOFF?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_COMPRESSOR?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_COMPRESSOR?);
 			}
 			ctx.get_error()?;
 		}
@@ -2383,14 +2393,14 @@ impl CompressorEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0;
-		unsafe { efx.alGetEffecti.unwrap()(self.effect, efx.AL_COMPRESSOR_ONOFF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffecti(efx.alGetEffecti.unwrap(), self.effect, efx.AL_COMPRESSOR_ONOFF.unwrap(), &mut value); }
 		value == 1 as sys::ALint
 	}
 	/// `alEffecti(AL_COMPRESSOR_ONOFF)`
 	pub fn set_onoff(&mut self, value: bool) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffecti.unwrap()(self.effect, efx.AL_COMPRESSOR_ONOFF.unwrap(), if value { 1 } else { 0 } as sys::ALint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti.unwrap(), self.effect, efx.AL_COMPRESSOR_ONOFF.unwrap(), if value { 1 } else { 0 } as sys::ALint); }
 		self.ctx.get_error()
 	}
 }
@@ -2401,7 +2411,7 @@ impl Drop for CompressorEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2424,8 +2434,8 @@ unsafe impl Effect for EqualizerEffect {
 			efx.AL_EQUALIZER_HIGH_CUTOFF?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_EQUALIZER?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, efx.AL_EFFECT_EQUALIZER?);
 			}
 			ctx.get_error()?;
 		}
@@ -2446,14 +2456,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_LOW_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_LOW_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_LOW_GAIN)`
 	pub fn set_low_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_LOW_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_LOW_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2463,14 +2473,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_LOW_CUTOFF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_LOW_CUTOFF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_LOW_CUTOFF)`
 	pub fn set_low_cutoff(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_LOW_CUTOFF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_LOW_CUTOFF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2480,14 +2490,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID1_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID1_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_MID1_GAIN)`
 	pub fn set_mid1_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID1_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID1_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2497,14 +2507,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID1_CENTER.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID1_CENTER.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_MID1_CENTER)`
 	pub fn set_mid1_center(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID1_CENTER.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID1_CENTER.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2514,14 +2524,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID1_WIDTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID1_WIDTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_MID1_WIDTH)`
 	pub fn set_mid1_width(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID1_WIDTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID1_WIDTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2531,14 +2541,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID2_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID2_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_MID2_GAIN)`
 	pub fn set_mid2_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID2_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID2_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2548,14 +2558,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID2_CENTER.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID2_CENTER.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_MID2_CENTER)`
 	pub fn set_mid2_center(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID2_CENTER.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID2_CENTER.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2565,14 +2575,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID2_WIDTH.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID2_WIDTH.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_MID2_WIDTH)`
 	pub fn set_mid2_width(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_MID2_WIDTH.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_MID2_WIDTH.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2582,14 +2592,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_HIGH_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_HIGH_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_HIGH_GAIN)`
 	pub fn set_high_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_HIGH_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_HIGH_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2599,14 +2609,14 @@ impl EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_HIGH_CUTOFF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_HIGH_CUTOFF.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EQUALIZER_HIGH_CUTOFF)`
 	pub fn set_high_cutoff(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, efx.AL_EQUALIZER_HIGH_CUTOFF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, efx.AL_EQUALIZER_HIGH_CUTOFF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2617,7 +2627,7 @@ impl Drop for EqualizerEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2632,8 +2642,8 @@ unsafe impl Effect for DedicatedLowFrequencyEffect {
 			d.AL_EFFECT_DEDICATED_GAIN?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, d.AL_EFFECT_DEDICATED_LOW_FREQUENCY_EFFECT?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, d.AL_EFFECT_DEDICATED_LOW_FREQUENCY_EFFECT?);
 			}
 			ctx.get_error()?;
 		}
@@ -2655,7 +2665,7 @@ impl DedicatedLowFrequencyEffect {
 		let d = self.ctx.0.dev.0.exts.ALC_EXT_DEDICATED().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EFFECT_DEDICATED_GAIN)`
@@ -2663,7 +2673,7 @@ impl DedicatedLowFrequencyEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let d = self.ctx.0.dev.0.exts.ALC_EXT_DEDICATED().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2674,7 +2684,7 @@ impl Drop for DedicatedLowFrequencyEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2689,8 +2699,8 @@ unsafe impl Effect for DedicatedDialogueEffect {
 			d.AL_EFFECT_DEDICATED_GAIN?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenEffects?(1, &mut effect);
-				efx.alEffecti?(effect, efx.AL_EFFECT_TYPE?, d.AL_EFFECT_DEDICATED_DIALOGUE?);
+				ctx.0.dev.0.alto.0.api.alGenEffects(efx.alGenEffects?, 1, &mut effect);
+				ctx.0.dev.0.alto.0.api.alEffecti(efx.alEffecti?, effect, efx.AL_EFFECT_TYPE?, d.AL_EFFECT_DEDICATED_DIALOGUE?);
 			}
 			ctx.get_error()?;
 		}
@@ -2712,7 +2722,7 @@ impl DedicatedDialogueEffect {
 		let d = self.ctx.0.dev.0.exts.ALC_EXT_DEDICATED().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetEffectf.unwrap()(self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetEffectf(efx.alGetEffectf.unwrap(), self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alEffectf(AL_EFFECT_DEDICATED_GAIN)`
@@ -2720,7 +2730,7 @@ impl DedicatedDialogueEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let d = self.ctx.0.dev.0.exts.ALC_EXT_DEDICATED().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alEffectf.unwrap()(self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alEffectf(efx.alEffectf.unwrap(), self.effect, d.AL_EFFECT_DEDICATED_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2731,7 +2741,7 @@ impl Drop for DedicatedDialogueEffect {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let ade = efx.alDeleteEffects.unwrap();
-		unsafe { ade(1, &mut self.effect as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteEffects(ade, 1, &mut self.effect as *mut sys::ALuint); }
 	}
 }
 
@@ -2760,8 +2770,8 @@ unsafe impl Filter for LowpassFilter {
 			efx.AL_LOWPASS_GAINHF?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenFilters?(1, &mut filter);
-				efx.alFilteri?(filter, efx.AL_FILTER_TYPE?, efx.AL_FILTER_LOWPASS?);
+				ctx.0.dev.0.alto.0.api.alGenFilters(efx.alGenFilters?, 1, &mut filter);
+				ctx.0.dev.0.alto.0.api.alFilteri(efx.alFilteri?, filter, efx.AL_FILTER_TYPE?, efx.AL_FILTER_LOWPASS?);
 			}
 			ctx.get_error()?;
 		}
@@ -2782,14 +2792,14 @@ impl LowpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_LOWPASS_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_LOWPASS_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_LOWPASS_GAIN)`
 	pub fn set_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_LOWPASS_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_LOWPASS_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2799,14 +2809,14 @@ impl LowpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_LOWPASS_GAINHF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_LOWPASS_GAINHF.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_LOWPASS_GAINHF)`
 	pub fn set_gainhf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_LOWPASS_GAINHF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_LOWPASS_GAINHF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2817,7 +2827,7 @@ impl Drop for LowpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let adf = efx.alDeleteFilters.unwrap();
-		unsafe { adf(1, &mut self.filter as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteFilters(adf, 1, &mut self.filter as *mut sys::ALuint); }
 	}
 }
 
@@ -2832,8 +2842,8 @@ unsafe impl Filter for HighpassFilter {
 			efx.AL_HIGHPASS_GAINLF?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenFilters?(1, &mut filter);
-				efx.alFilteri?(filter, efx.AL_FILTER_TYPE?, efx.AL_FILTER_HIGHPASS?);
+				ctx.0.dev.0.alto.0.api.alGenFilters(efx.alGenFilters?, 1, &mut filter);
+				ctx.0.dev.0.alto.0.api.alFilteri(efx.alFilteri?, filter, efx.AL_FILTER_TYPE?, efx.AL_FILTER_HIGHPASS?);
 			}
 			ctx.get_error()?;
 		}
@@ -2854,14 +2864,14 @@ impl HighpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_HIGHPASS_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_HIGHPASS_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_HIGHPASS_GAIN)`
 	pub fn set_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_HIGHPASS_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_HIGHPASS_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2871,14 +2881,14 @@ impl HighpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_HIGHPASS_GAINLF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_HIGHPASS_GAINLF.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_HIGHPASS_GAINLF)`
 	pub fn set_gainlf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_HIGHPASS_GAINLF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_HIGHPASS_GAINLF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2889,7 +2899,7 @@ impl Drop for HighpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let adf = efx.alDeleteFilters.unwrap();
-		unsafe { adf(1, &mut self.filter as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteFilters(adf, 1, &mut self.filter as *mut sys::ALuint); }
 	}
 }
 
@@ -2905,8 +2915,8 @@ unsafe impl Filter for BandpassFilter {
 			efx.AL_BANDPASS_GAINHF?;
 			let _lock = ctx.make_current(true);
 			unsafe {
-				efx.alGenFilters?(1, &mut filter);
-				efx.alFilteri?(filter, efx.AL_FILTER_TYPE?, efx.AL_FILTER_BANDPASS?);
+				ctx.0.dev.0.alto.0.api.alGenFilters(efx.alGenFilters?, 1, &mut filter);
+				ctx.0.dev.0.alto.0.api.alFilteri(efx.alFilteri?, filter, efx.AL_FILTER_TYPE?, efx.AL_FILTER_BANDPASS?);
 			}
 			ctx.get_error()?;
 		}
@@ -2927,14 +2937,14 @@ impl BandpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_BANDPASS_GAIN.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_BANDPASS_GAIN.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_BANDPASS_GAIN)`
 	pub fn set_gain(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_BANDPASS_GAIN.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_BANDPASS_GAIN.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2944,14 +2954,14 @@ impl BandpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_BANDPASS_GAINLF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_BANDPASS_GAINLF.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_BANDPASS_GAINLF)`
 	pub fn set_gainlf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_BANDPASS_GAINLF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_BANDPASS_GAINLF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 
@@ -2961,14 +2971,14 @@ impl BandpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let mut value = 0.0;
-		unsafe { efx.alGetFilterf.unwrap()(self.filter, efx.AL_BANDPASS_GAINHF.unwrap(), &mut value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alGetFilterf(efx.alGetFilterf.unwrap(), self.filter, efx.AL_BANDPASS_GAINHF.unwrap(), &mut value); }
 		value
 	}
 	/// `alFilterf(AL_BANDPASS_GAINHF)`
 	pub fn set_gainhf(&mut self, value: f32) -> AltoResult<()> {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
-		unsafe { efx.alFilterf.unwrap()(self.filter, efx.AL_BANDPASS_GAINHF.unwrap(), value); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alFilterf(efx.alFilterf.unwrap(), self.filter, efx.AL_BANDPASS_GAINHF.unwrap(), value); }
 		self.ctx.get_error()
 	}
 }
@@ -2979,6 +2989,6 @@ impl Drop for BandpassFilter {
 		let efx = self.ctx.0.dev.0.exts.ALC_EXT_EFX().unwrap();
 		let _lock = self.ctx.make_current(true);
 		let adf = efx.alDeleteFilters.unwrap();
-		unsafe { adf(1, &mut self.filter as *mut sys::ALuint); }
+		unsafe { self.ctx.0.dev.0.alto.0.api.alDeleteFilters(adf, 1, &mut self.filter as *mut sys::ALuint); }
 	}
 }
@@ -1,4 +1,10 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+
 /// Represents a preset for the `AL_EFFECT_REVERB` and `AL_EFFECT_EAXREVERB` effects.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EaxReverbProperties {
 	pub density: f32,
 	pub diffusion: f32,
@@ -0,0 +1,445 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use ::AltoResult;
+use sys;
+use al::Source;
+use super::*;
+
+
+/// Defines a plain-data snapshot of the properties of an effect or filter,
+/// along with methods to read it from and apply it to a live object.
+macro_rules! snapshot {
+	{
+		$(#[$attr:meta])*
+		$object:ident => $snapshot:ident {
+			$($(#[$field_attr:meta])* $field:ident / $setter:ident: $ty:ty,)*
+		}
+	} => {
+		$(#[$attr])*
+		#[derive(Copy, Clone, PartialEq, Debug)]
+		#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+		pub struct $snapshot {
+			$($(#[$field_attr])* pub $field: $ty,)*
+		}
+
+
+		impl $object {
+			/// Read the current value of every property.
+			pub fn snapshot(&self) -> $snapshot {
+				$snapshot{
+					$($field: self.$field(),)*
+				}
+			}
+
+
+			/// Set every property from a snapshot.
+			/// All properties are applied even if some fail, and the first error is returned.
+			pub fn apply_snapshot(&mut self, snapshot: &$snapshot) -> AltoResult<()> {
+				let mut r = Ok(());
+				$(r = r.and(self.$setter(snapshot.$field));)*
+				r
+			}
+		}
+	};
+}
+
+
+impl EaxReverbEffect {
+	/// Read the current value of every property.
+	pub fn snapshot(&self) -> EaxReverbProperties {
+		EaxReverbProperties{
+			density: self.density(),
+			diffusion: self.diffusion(),
+			gain: self.gain(),
+			gainhf: self.gainhf(),
+			gainlf: self.gainlf(),
+			decay_time: self.decay_time(),
+			decay_hfratio: self.decay_hfratio(),
+			decay_lfratio: self.decay_lfratio(),
+			reflections_gain: self.reflections_gain(),
+			reflections_delay: self.reflections_delay(),
+			reflections_pan: self.reflections_pan(),
+			late_reverb_gain: self.late_reverb_gain(),
+			late_reverb_delay: self.late_reverb_delay(),
+			late_reverb_pan: self.late_reverb_pan(),
+			echo_time: self.echo_time(),
+			echo_depth: self.echo_depth(),
+			modulation_time: self.modulation_time(),
+			modulation_depth: self.modulation_depth(),
+			air_absorption_gainhf: self.air_absorption_gainhf(),
+			hfreference: self.hfreference(),
+			lfreference: self.lfreference(),
+			room_rolloff_factor: self.room_rolloff_factor(),
+			decay_hflimit: self.decay_hflimit(),
+		}
+	}
+
+
+	/// Set every property from a snapshot. Equivalent to `set_preset`.
+	pub fn apply_snapshot(&mut self, snapshot: &EaxReverbProperties) -> AltoResult<()> {
+		self.set_preset(snapshot)
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `ReverbEffect`.
+	ReverbEffect => ReverbSnapshot {
+		/// `AL_REVERB_DENSITY`
+		density / set_density: f32,
+		/// `AL_REVERB_DIFFUSION`
+		diffusion / set_diffusion: f32,
+		/// `AL_REVERB_GAIN`
+		gain / set_gain: f32,
+		/// `AL_REVERB_GAINHF`
+		gainhf / set_gainhf: f32,
+		/// `AL_REVERB_DECAY_TIME`
+		decay_time / set_decay_time: f32,
+		/// `AL_REVERB_DECAY_HFRATIO`
+		decay_hfratio / set_decay_hfratio: f32,
+		/// `AL_REVERB_REFLECTIONS_GAIN`
+		reflections_gain / set_reflections_gain: f32,
+		/// `AL_REVERB_REFLECTIONS_DELAY`
+		reflections_delay / set_reflections_delay: f32,
+		/// `AL_REVERB_LATE_REVERB_GAIN`
+		late_reverb_gain / set_late_reverb_gain: f32,
+		/// `AL_REVERB_LATE_REVERB_DELAY`
+		late_reverb_delay / set_late_reverb_delay: f32,
+		/// `AL_REVERB_AIR_ABSORPTION_GAINHF`
+		air_absorption_gainhf / set_air_absorption_gainhf: f32,
+		/// `AL_REVERB_ROOM_ROLLOFF_FACTOR`
+		room_rolloff_factor / set_room_rolloff_factor: f32,
+		/// `AL_REVERB_DECAY_HFLIMIT`
+		decay_hflimit / set_decay_hflimit: bool,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `ChorusEffect`.
+	ChorusEffect => ChorusSnapshot {
+		/// `AL_CHORUS_WAVEFORM`
+		waveform / set_waveform: ChorusWaveform,
+		/// `AL_CHORUS_PHASE`
+		phase / set_phase: sys::ALint,
+		/// `AL_CHORUS_RATE`
+		rate / set_rate: f32,
+		/// `AL_CHORUS_DEPTH`
+		depth / set_depth: f32,
+		/// `AL_CHORUS_FEEDBACK`
+		feedback / set_feedback: f32,
+		/// `AL_CHORUS_DELAY`
+		delay / set_delay: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `DistortionEffect`.
+	DistortionEffect => DistortionSnapshot {
+		/// `AL_DISTORTION_EDGE`
+		edge / set_edge: f32,
+		/// `AL_DISTORTION_LOWPASS_CUTOFF`
+		lowpass_cutoff / set_lowpass_cutoff: f32,
+		/// `AL_DISTORTION_EQCENTER`
+		eqcenter / set_eqcenter: f32,
+		/// `AL_DISTORTION_EQBANDWIDTH`
+		eqbandwidth / set_eqbandwidth: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of an `EchoEffect`.
+	EchoEffect => EchoSnapshot {
+		/// `AL_ECHO_DELAY`
+		delay / set_delay: f32,
+		/// `AL_ECHO_LRDELAY`
+		lrdelay / set_lrdelay: f32,
+		/// `AL_ECHO_DAMPING`
+		damping / set_damping: f32,
+		/// `AL_ECHO_FEEDBACK`
+		feedback / set_feedback: f32,
+		/// `AL_ECHO_SPREAD`
+		spread / set_spread: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `FlangerEffect`.
+	FlangerEffect => FlangerSnapshot {
+		/// `AL_FLANGER_WAVEFORM`
+		waveform / set_waveform: FlangerWaveform,
+		/// `AL_FLANGER_PHASE`
+		phase / set_phase: sys::ALint,
+		/// `AL_FLANGER_RATE`
+		rate / set_rate: f32,
+		/// `AL_FLANGER_DEPTH`
+		depth / set_depth: f32,
+		/// `AL_FLANGER_FEEDBACK`
+		feedback / set_feedback: f32,
+		/// `AL_FLANGER_DELAY`
+		delay / set_delay: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `FrequencyShifterEffect`.
+	FrequencyShifterEffect => FrequencyShifterSnapshot {
+		/// `AL_FREQUENCY_SHIFTER_FREQUENCY`
+		frequency / set_frequency: f32,
+		/// `AL_FREQUENCY_SHIFTER_LEFT_DIRECTION`
+		left_direction / set_left_direction: FrequencyShifterDirection,
+		/// `AL_FREQUENCY_SHIFTER_RIGHT_DIRECTION`
+		right_direction / set_right_direction: FrequencyShifterDirection,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `VocalMorpherEffect`.
+	VocalMorpherEffect => VocalMorpherSnapshot {
+		/// `AL_VOCAL_MORPHER_PHONEMEA`
+		phonemea / set_phonemea: VocalMorpherPhoneme,
+		/// `AL_VOCAL_MORPHER_PHONEMEB`
+		phonemeb / set_phonemeb: VocalMorpherPhoneme,
+		/// `AL_VOCAL_MORPHER_PHONEMEA_COARSE_TUNING`
+		phonemea_coarse_tuning / set_phonemea_coarse_tuning: sys::ALint,
+		/// `AL_VOCAL_MORPHER_PHONEMEB_COARSE_TUNING`
+		phonemeb_coarse_tuning / set_phonemeb_coarse_tuning: sys::ALint,
+		/// `AL_VOCAL_MORPHER_WAVEFORM`
+		waveform / set_waveform: VocalMorpherWaveform,
+		/// `AL_VOCAL_MORPHER_RATE`
+		rate / set_rate: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `PitchShifterEffect`.
+	PitchShifterEffect => PitchShifterSnapshot {
+		/// `AL_PITCH_SHIFTER_COARSE_TUNE`
+		coarse_tune / set_coarse_tune: sys::ALint,
+		/// `AL_PITCH_SHIFTER_FINE_TUNE`
+		fine_tune / set_fine_tune: sys::ALint,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `RingModulatorEffect`.
+	RingModulatorEffect => RingModulatorSnapshot {
+		/// `AL_RING_MODULATOR_FREQUENCY`
+		frequency / set_frequency: f32,
+		/// `AL_RING_MODULATOR_HIGHPASS_CUTOFF`
+		highpass_cutoff / set_highpass_cutoff: f32,
+		/// `AL_RING_MODULATOR_WAVEFORM`
+		waveform / set_waveform: RingModulatorWaveform,
+	}
+}
+
+
+snapshot! {
+	/// Properties of an `AutowahEffect`.
+	AutowahEffect => AutowahSnapshot {
+		/// `AL_AUTOWAH_ATTACK_TIME`
+		attack_time / set_attack_time: f32,
+		/// `AL_AUTOWAH_RELEASE_TIME`
+		release_time / set_release_time: f32,
+		/// `AL_AUTOWAH_RESONANCE`
+		resonance / set_resonance: f32,
+		/// `AL_AUTOWAH_PEAK_GAIN`
+		peak_gain / set_peak_gain: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `CompressorEffect`.
+	CompressorEffect => CompressorSnapshot {
+		/// `AL_COMPRESSOR_ONOFF`
+		onoff / set_onoff: bool,
+	}
+}
+
+
+snapshot! {
+	/// Properties of an `EqualizerEffect`.
+	EqualizerEffect => EqualizerSnapshot {
+		/// `AL_EQUALIZER_LOW_GAIN`
+		low_gain / set_low_gain: f32,
+		/// `AL_EQUALIZER_LOW_CUTOFF`
+		low_cutoff / set_low_cutoff: f32,
+		/// `AL_EQUALIZER_MID1_GAIN`
+		mid1_gain / set_mid1_gain: f32,
+		/// `AL_EQUALIZER_MID1_CENTER`
+		mid1_center / set_mid1_center: f32,
+		/// `AL_EQUALIZER_MID1_WIDTH`
+		mid1_width / set_mid1_width: f32,
+		/// `AL_EQUALIZER_MID2_GAIN`
+		mid2_gain / set_mid2_gain: f32,
+		/// `AL_EQUALIZER_MID2_CENTER`
+		mid2_center / set_mid2_center: f32,
+		/// `AL_EQUALIZER_MID2_WIDTH`
+		mid2_width / set_mid2_width: f32,
+		/// `AL_EQUALIZER_HIGH_GAIN`
+		high_gain / set_high_gain: f32,
+		/// `AL_EQUALIZER_HIGH_CUTOFF`
+		high_cutoff / set_high_cutoff: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `DedicatedLowFrequencyEffect`.
+	DedicatedLowFrequencyEffect => DedicatedLowFrequencySnapshot {
+		/// `AL_EFFECT_DEDICATED_GAIN`
+		gain / set_gain: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `DedicatedDialogueEffect`.
+	DedicatedDialogueEffect => DedicatedDialogueSnapshot {
+		/// `AL_EFFECT_DEDICATED_GAIN`
+		gain / set_gain: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `LowpassFilter`.
+	LowpassFilter => LowpassSnapshot {
+		/// `AL_LOWPASS_GAIN`
+		gain / set_gain: f32,
+		/// `AL_LOWPASS_GAINHF`
+		gainhf / set_gainhf: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `HighpassFilter`.
+	HighpassFilter => HighpassSnapshot {
+		/// `AL_HIGHPASS_GAIN`
+		gain / set_gain: f32,
+		/// `AL_HIGHPASS_GAINLF`
+		gainlf / set_gainlf: f32,
+	}
+}
+
+
+snapshot! {
+	/// Properties of a `BandpassFilter`.
+	BandpassFilter => BandpassSnapshot {
+		/// `AL_BANDPASS_GAIN`
+		gain / set_gain: f32,
+		/// `AL_BANDPASS_GAINLF`
+		gainlf / set_gainlf: f32,
+		/// `AL_BANDPASS_GAINHF`
+		gainhf / set_gainhf: f32,
+	}
+}
+
+
+/// A snapshot of any kind of effect, suitable for describing effects in data files.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EffectSnapshot {
+	/// Properties of an `EaxReverbEffect`.
+	EaxReverb(EaxReverbProperties),
+	/// Properties of a `ReverbEffect`.
+	Reverb(ReverbSnapshot),
+	/// Properties of a `ChorusEffect`.
+	Chorus(ChorusSnapshot),
+	/// Properties of a `DistortionEffect`.
+	Distortion(DistortionSnapshot),
+	/// Properties of an `EchoEffect`.
+	Echo(EchoSnapshot),
+	/// Properties of a `FlangerEffect`.
+	Flanger(FlangerSnapshot),
+	/// Properties of a `FrequencyShifterEffect`.
+	FrequencyShifter(FrequencyShifterSnapshot),
+	/// Properties of a `VocalMorpherEffect`.
+	VocalMorpher(VocalMorpherSnapshot),
+	/// Properties of a `PitchShifterEffect`.
+	PitchShifter(PitchShifterSnapshot),
+	/// Properties of a `RingModulatorEffect`.
+	RingModulator(RingModulatorSnapshot),
+	/// Properties of an `AutowahEffect`.
+	Autowah(AutowahSnapshot),
+	/// Properties of a `CompressorEffect`.
+	Compressor(CompressorSnapshot),
+	/// Properties of an `EqualizerEffect`.
+	Equalizer(EqualizerSnapshot),
+	/// Properties of a `DedicatedLowFrequencyEffect`.
+	DedicatedLowFrequency(DedicatedLowFrequencySnapshot),
+	/// Properties of a `DedicatedDialogueEffect`.
+	DedicatedDialogue(DedicatedDialogueSnapshot),
+}
+
+
+/// A snapshot of any kind of filter, suitable for describing filters in data files.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FilterSnapshot {
+	/// Properties of a `LowpassFilter`.
+	Lowpass(LowpassSnapshot),
+	/// Properties of a `HighpassFilter`.
+	Highpass(HighpassSnapshot),
+	/// Properties of a `BandpassFilter`.
+	Bandpass(BandpassSnapshot),
+}
+
+
+impl EffectSnapshot {
+	/// Create an effect of the appropriate type in the context of `slot`, apply this snapshot to it, and load it into the slot.
+	pub fn apply(&self, slot: &mut AuxEffectSlot) -> AltoResult<()> {
+		fn load<E: Effect, F: FnOnce(&mut E) -> AltoResult<()>>(slot: &mut AuxEffectSlot, f: F) -> AltoResult<()> {
+			let mut effect = slot.context().new_effect::<E>()?;
+			f(&mut effect)?;
+			slot.set_effect(&effect)
+		}
+
+		match *self {
+			EffectSnapshot::EaxReverb(ref s) => load(slot, |e: &mut EaxReverbEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Reverb(ref s) => load(slot, |e: &mut ReverbEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Chorus(ref s) => load(slot, |e: &mut ChorusEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Distortion(ref s) => load(slot, |e: &mut DistortionEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Echo(ref s) => load(slot, |e: &mut EchoEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Flanger(ref s) => load(slot, |e: &mut FlangerEffect| e.apply_snapshot(s)),
+			EffectSnapshot::FrequencyShifter(ref s) => load(slot, |e: &mut FrequencyShifterEffect| e.apply_snapshot(s)),
+			EffectSnapshot::VocalMorpher(ref s) => load(slot, |e: &mut VocalMorpherEffect| e.apply_snapshot(s)),
+			EffectSnapshot::PitchShifter(ref s) => load(slot, |e: &mut PitchShifterEffect| e.apply_snapshot(s)),
+			EffectSnapshot::RingModulator(ref s) => load(slot, |e: &mut RingModulatorEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Autowah(ref s) => load(slot, |e: &mut AutowahEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Compressor(ref s) => load(slot, |e: &mut CompressorEffect| e.apply_snapshot(s)),
+			EffectSnapshot::Equalizer(ref s) => load(slot, |e: &mut EqualizerEffect| e.apply_snapshot(s)),
+			EffectSnapshot::DedicatedLowFrequency(ref s) => load(slot, |e: &mut DedicatedLowFrequencyEffect| e.apply_snapshot(s)),
+			EffectSnapshot::DedicatedDialogue(ref s) => load(slot, |e: &mut DedicatedDialogueEffect| e.apply_snapshot(s)),
+		}
+	}
+}
+
+
+impl FilterSnapshot {
+	/// Create a filter of the appropriate type in the context of `src`, apply this snapshot to it, and set it as the direct filter.
+	pub fn apply_direct<S: Source>(&self, src: &mut S) -> AltoResult<()> {
+		fn load<S: Source, F: Filter, A: FnOnce(&mut F) -> AltoResult<()>>(src: &mut S, f: A) -> AltoResult<()> {
+			let mut filter = src.context().new_filter::<F>()?;
+			f(&mut filter)?;
+			src.set_direct_filter(&filter)
+		}
+
+		match *self {
+			FilterSnapshot::Lowpass(ref s) => load(src, |f: &mut LowpassFilter| f.apply_snapshot(s)),
+			FilterSnapshot::Highpass(ref s) => load(src, |f: &mut HighpassFilter| f.apply_snapshot(s)),
+			FilterSnapshot::Bandpass(ref s) => load(src, |f: &mut BandpassFilter| f.apply_snapshot(s)),
+		}
+	}
+}
+
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use parking_lot::Mutex;
+
+use ::{AltoError, AltoResult};
+use sys;
+use alc::*;
+use al::*;
+
+
+/// Notifications delivered by an `EventListener`.
+/// Sources are identified by the key they were watched with.
+#[derive(Debug)]
+pub enum ContextEvent<K> {
+	/// A watched source changed to the contained state.
+	SourceStateChanged(K, SourceState),
+	/// A watched source finished playing the contained number of buffers from its queue.
+	/// When polling, buffers are counted from the processed and queued totals,
+	/// so a completed buffer that is unqueued and replaced by a new one within the same period goes unnoticed.
+	BufferCompleted(K, usize),
+	/// The device of the context was disconnected. Contains the implementation's description of the event.
+	Disconnected(String),
+}
+
+
+struct Watched<K> {
+	key: K,
+	state: SourceState,
+	processed: sys::ALint,
+	queued: sys::ALint,
+}
+
+
+struct Shared<K> {
+	/// `AL_SOFT_events` enum values of source state changed, buffer completed, and disconnected events.
+	types: [sys::ALenum; 3],
+	watched: Mutex<HashMap<sys::ALuint, Watched<K>>>,
+	events: Mutex<mpsc::Sender<ContextEvent<K>>>,
+	stop: AtomicBool,
+}
+
+
+/// Delivers source and device events for a context.
+///
+/// With `AL_SOFT_events`, events are pushed by the implementation as they happen.
+/// Otherwise, a background thread polls the watched sources for the same information.
+/// Only one listener per context can use `AL_SOFT_events` at a time, since the extension supports a single callback.
+/// While one exists, further listeners created with `new` poll instead.
+pub struct EventListener<K: Clone + Send + 'static> {
+	ctx: Context,
+	shared: Arc<Shared<K>>,
+	events: mpsc::Receiver<ContextEvent<K>>,
+	native: bool,
+	thread: Option<thread::JoinHandle<()>>,
+}
+
+
+impl<K: Clone + Send + 'static> EventListener<K> {
+	/// `alEventCallbackSOFT()`, `alEventControlSOFT()`
+	/// Listen for events on `ctx`, using `AL_SOFT_events` if present or else polling every `period`.
+	/// Polling is also used if another listener on the context is already using `AL_SOFT_events`.
+	pub fn new(ctx: &Context, period: Duration) -> AltoResult<EventListener<K>> {
+		let types = match event_types(ctx) {
+			Ok(types) => types,
+			Err(_) => return EventListener::polling(ctx, period),
+		};
+		if ctx.0.native_events.swap(true, Ordering::AcqRel) {
+			return EventListener::polling(ctx, period);
+		}
+
+		let (tx, rx) = mpsc::channel();
+		let shared = Arc::new(Shared{
+			types: types,
+			watched: Mutex::new(HashMap::new()),
+			events: Mutex::new(tx),
+			stop: AtomicBool::new(false),
+		});
+		if let Err(e) = EventListener::install(ctx, &shared) {
+			ctx.0.native_events.store(false, Ordering::Release);
+			return Err(e);
+		}
+
+		Ok(EventListener{
+			ctx: ctx.clone(),
+			shared: shared,
+			events: rx,
+			native: true,
+			thread: None,
+		})
+	}
+
+
+	/// Listen for events on `ctx` by polling every `period`, even if `AL_SOFT_events` is present.
+	/// This allows several listeners on the same context.
+	pub fn polling(ctx: &Context, period: Duration) -> AltoResult<EventListener<K>> {
+		let (tx, rx) = mpsc::channel();
+		let shared = Arc::new(Shared{
+			types: [0; 3],
+			watched: Mutex::new(HashMap::new()),
+			events: Mutex::new(tx),
+			stop: AtomicBool::new(false),
+		});
+
+		let thread_ctx = ctx.clone();
+		let thread_shared = shared.clone();
+		let thread = thread::Builder::new()
+			.name("alto-events".into())
+			.spawn(move || {
+				let mut connected = true;
+				while !thread_shared.stop.load(Ordering::Acquire) {
+					thread_shared.poll(&thread_ctx, &mut connected);
+					thread::sleep(period);
+				}
+			})?;
+
+		Ok(EventListener{
+			ctx: ctx.clone(),
+			shared: shared,
+			events: rx,
+			native: false,
+			thread: Some(thread),
+		})
+	}
+
+
+	fn install(ctx: &Context, shared: &Arc<Shared<K>>) -> AltoResult<()> {
+		let ase = ctx.0.exts.AL_SOFT_events()?;
+		let aec = ase.alEventControlSOFT?;
+		let aecb = ase.alEventCallbackSOFT?;
+
+		let _lock = ctx.make_current(true);
+		unsafe { ctx.0.dev.0.alto.0.api.alEventControlSOFT(aec, shared.types.len() as sys::ALsizei, shared.types.as_ptr(), sys::AL_TRUE); }
+		ctx.get_error()?;
+
+		let param = Arc::into_raw(shared.clone()) as *mut c_void;
+		unsafe { ctx.0.dev.0.alto.0.api.alEventCallbackSOFT(aecb, Some(event_callback::<K>), param); }
+		let res = ctx.get_error();
+		if res.is_err() {
+			unsafe { drop(Arc::from_raw(param as *const Shared<K>)); }
+		}
+		res
+	}
+
+
+	/// Context whose events are delivered.
+	pub fn context(&self) -> &Context { &self.ctx }
+	/// Whether events are pushed by `AL_SOFT_events` rather than polled.
+	pub fn is_native(&self) -> bool { self.native }
+
+
+	/// Deliver events for `src`, identified by `key`. Watching a source again replaces its key.
+	/// Sources should be unwatched before they are dropped, since OpenAL may reuse their names.
+	pub fn watch<S: Source>(&self, src: &S, key: K) -> AltoResult<()> {
+		if *src.context() != self.ctx {
+			return Err(AltoError::WrongContext);
+		}
+
+		let (state, processed, queued) = query(&self.ctx, src.as_raw());
+		self.shared.watched.lock().insert(src.as_raw(), Watched{key: key, state: state, processed: processed, queued: queued});
+		Ok(())
+	}
+
+
+	/// Stop delivering events for `src`.
+	pub fn unwatch<S: Source>(&self, src: &S) {
+		self.shared.watched.lock().remove(&src.as_raw());
+	}
+
+
+	/// Receiver of events.
+	pub fn events(&self) -> &mpsc::Receiver<ContextEvent<K>> { &self.events }
+}
+
+
+impl<K: Clone + Send + 'static> Drop for EventListener<K> {
+	fn drop(&mut self) {
+		if self.native {
+			let _ = (|| -> AltoResult<()> {
+				let ase = self.ctx.0.exts.AL_SOFT_events()?;
+				let types = &self.shared.types;
+
+				let _lock = self.ctx.make_current(true);
+				unsafe {
+					self.ctx.0.dev.0.alto.0.api.alEventControlSOFT(ase.alEventControlSOFT?, types.len() as sys::ALsizei, types.as_ptr(), sys::AL_FALSE);
+					self.ctx.0.dev.0.alto.0.api.alEventCallbackSOFT(ase.alEventCallbackSOFT?, None, ptr::null_mut());
+				}
+				// The implementation no longer holds the callback's reference.
+				unsafe { drop(Arc::from_raw(Arc::as_ptr(&self.shared))); }
+				Ok(())
+			})();
+			self.ctx.0.native_events.store(false, Ordering::Release);
+		}
+
+		self.shared.stop.store(true, Ordering::Release);
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+
+impl<K: Clone + Send> Shared<K> {
+	fn send(&self, event: ContextEvent<K>) {
+		let _ = self.events.lock().send(event);
+	}
+
+
+	fn poll(&self, ctx: &Context, connected: &mut bool) {
+		let ids: Vec<_> = self.watched.lock().keys().cloned().collect();
+		// Query without holding the lock, so a callback on the mixer thread can never wait on a thread waiting on the mixer.
+		let current: Vec<_> = ids.iter().map(|&id| (id, query(ctx, id))).collect();
+
+		let mut events = Vec::new();
+		{
+			let mut watched = self.watched.lock();
+			for (id, (state, processed, queued)) in current {
+				if let Some(w) = watched.get_mut(&id) {
+					// Buffers unqueued since the last poll were processed too, even though they no longer count as such.
+					let completed = processed - w.processed + (w.queued - queued);
+					if completed > 0 {
+						events.push(ContextEvent::BufferCompleted(w.key.clone(), completed as usize));
+					}
+					w.processed = processed;
+					w.queued = queued;
+					if state != w.state {
+						w.state = state;
+						events.push(ContextEvent::SourceStateChanged(w.key.clone(), state));
+					}
+				}
+			}
+		}
+		for event in events {
+			self.send(event);
+		}
+
+		if *connected {
+			if let Ok(false) = ctx.device().connected() {
+				*connected = false;
+				self.send(ContextEvent::Disconnected("Device disconnected".into()));
+			}
+		}
+	}
+}
+
+
+fn event_types(ctx: &Context) -> AltoResult<[sys::ALenum; 3]> {
+	let ase = ctx.0.exts.AL_SOFT_events()?;
+	Ok([ase.AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT?, ase.AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT?, ase.AL_EVENT_TYPE_DISCONNECTED_SOFT?])
+}
+
+
+/// `alGetSourcei(AL_SOURCE_STATE)`, `alGetSourcei(AL_BUFFERS_PROCESSED)` and `alGetSourcei(AL_BUFFERS_QUEUED)` of a raw source name.
+fn query(ctx: &Context, src: sys::ALuint) -> (SourceState, sys::ALint, sys::ALint) {
+	let _lock = ctx.make_current(true);
+	let mut state = 0;
+	let mut processed = 0;
+	let mut queued = 0;
+	unsafe {
+		ctx.0.dev.0.alto.0.api.alGetSourcei(src, sys::AL_SOURCE_STATE, &mut state);
+		ctx.0.dev.0.alto.0.api.alGetSourcei(src, sys::AL_BUFFERS_PROCESSED, &mut processed);
+		ctx.0.dev.0.alto.0.api.alGetSourcei(src, sys::AL_BUFFERS_QUEUED, &mut queued);
+	}
+	let _ = ctx.get_error();
+	(SourceState::from_raw(state), processed, queued)
+}
+
+
+unsafe extern "C" fn event_callback<K: Clone + Send>(
+	event_type: sys::ALenum,
+	object: sys::ALuint,
+	param: sys::ALuint,
+	length: sys::ALsizei,
+	message: *const sys::ALchar,
+	user_param: *mut c_void,
+) {
+	let shared = &*(user_param as *const Shared<K>);
+
+	let [state_changed, buffer_completed, disconnected] = shared.types;
+	let event = if event_type == disconnected {
+		let message = if message.is_null() || length <= 0 {
+			String::new()
+		} else {
+			String::from_utf8_lossy(slice::from_raw_parts(message as *const u8, length as usize)).into_owned()
+		};
+		Some(ContextEvent::Disconnected(message))
+	} else {
+		let watched = shared.watched.lock();
+		match watched.get(&object) {
+			Some(w) if event_type == state_changed => Some(ContextEvent::SourceStateChanged(w.key.clone(), SourceState::from_raw(param as sys::ALint))),
+			Some(w) if event_type == buffer_completed => Some(ContextEvent::BufferCompleted(w.key.clone(), param as usize)),
+			_ => None,
+		}
+	};
+
+	if let Some(event) = event {
+		shared.send(event);
+	}
+}
@@ -22,6 +22,10 @@ macro_rules! alc_ext {
 
 		#[allow(non_snake_case, dead_code)]
 		impl $cache {
+			/// Names of the extensions in this cache.
+			pub const NAMES: &'static [&'static str] = &[$(stringify!($ext),)*];
+
+
 			pub unsafe fn new(api: &AlApi, dev: *mut ALCdevice) -> $cache {
 				$cache{
 					$($ext: $ext::load(api, dev),)*
@@ -102,6 +106,10 @@ macro_rules! al_ext {
 
 		#[allow(non_snake_case, dead_code)]
 		impl $cache {
+			/// Names of the extensions in this cache.
+			pub const NAMES: &'static [&'static str] = &[$(stringify!($ext),)*];
+
+
 			pub unsafe fn new(api: &AlApi) -> $cache {
 				$cache{
 					$($ext: $ext::load(api),)*
@@ -191,6 +199,16 @@ pub enum Alc {
 	SoftPauseDevice,
 	/// `ALC_SOFT_output_limiter`
 	SoftOutputLimiter,
+	/// `ALC_SOFT_device_clock`
+	SoftDeviceClock,
+	/// `ALC_SOFT_reopen_device`
+	SoftReopenDevice,
+	/// `ALC_SOFT_output_mode`
+	SoftOutputMode,
+	/// `ALC_SOFT_loopback_bformat`
+	SoftLoopbackBformat,
+	/// `ALC_EXT_debug`
+	Debug,
 }
 
 
@@ -217,9 +235,14 @@ pub enum Al {
 	/// `AL_SOFT_block_alignment`
 	SoftBlockAlignment,
 //	SoftBufferSamples,
-//	SoftBufferSubData,
+	/// `AL_SOFT_buffer_sub_data`
+	SoftBufferSubData,
+	/// `AL_SOFT_callback_buffer`
+	SoftCallbackBuffer,
 	/// `AL_SOFT_deferred_updates`
 	SoftDeferredUpdates,
+	/// `AL_SOFT_events`
+	SoftEvents,
 	/// `AL_SOFT_direct_channels`
 	SoftDirectChannels,
 	/// `AL_SOFT_loop_points`
@@ -230,6 +253,8 @@ pub enum Al {
 	SoftSourceLatency,
 	/// `AL_SOFT_source_length`
 	SoftSourceLength,
+	/// `AL_SOFT_source_start_delay`
+	SoftSourceStartDelay,
 	/// `AL_EXT_source_distance_model`
 	SourceDistanceModel,
 	/// `AL_SOFT_source_spatialize`
@@ -285,6 +310,10 @@ alc_ext! {
 }
 
 
+pub type ALCint64SOFT = i64;
+pub type ALCuint64SOFT = u64;
+
+
 alc_ext! {
 	pub(crate) cache AlcCache;
 
@@ -505,11 +534,60 @@ alc_ext! {
 
 		pub fn alcResetDeviceSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, attrList: *const ALCint) -> ALCboolean,
 	}
+
+
+	pub ext ALC_SOFT_device_clock {
+		pub const ALC_DEVICE_CLOCK_SOFT,
+		pub const ALC_DEVICE_LATENCY_SOFT,
+		pub const ALC_DEVICE_CLOCK_LATENCY_SOFT,
+
+		pub fn alcGetInteger64vSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, pname: ALCenum, size: ALsizei, values: *mut ALCint64SOFT),
+	}
+
+
+	pub ext ALC_SOFT_output_mode {
+		pub const ALC_OUTPUT_MODE_SOFT,
+		pub const ALC_ANY_SOFT,
+		pub const ALC_MONO_SOFT,
+		pub const ALC_STEREO_SOFT,
+		pub const ALC_STEREO_BASIC_SOFT,
+		pub const ALC_STEREO_UHJ_SOFT,
+		pub const ALC_STEREO_HRTF_SOFT,
+		pub const ALC_QUAD_SOFT,
+		pub const ALC_SURROUND_5_1_SOFT,
+		pub const ALC_SURROUND_6_1_SOFT,
+		pub const ALC_SURROUND_7_1_SOFT,
+	}
+
+
+	pub ext ALC_SOFT_loopback_bformat {
+		pub const ALC_AMBISONIC_LAYOUT_SOFT,
+		pub const ALC_AMBISONIC_SCALING_SOFT,
+		pub const ALC_AMBISONIC_ORDER_SOFT,
+		pub const ALC_MAX_AMBISONIC_ORDER_SOFT,
+		pub const ALC_BFORMAT3D_SOFT,
+		pub const ALC_ACN_SOFT,
+		pub const ALC_SN3D_SOFT,
+		pub const ALC_N3D_SOFT,
+	}
+
+
+	pub ext ALC_EXT_debug {
+		pub const ALC_CONTEXT_FLAGS_EXT,
+		pub const ALC_CONTEXT_DEBUG_BIT_EXT,
+	}
+
+
+	pub ext ALC_SOFT_reopen_device {
+		pub fn alcReopenDeviceSOFT: unsafe extern "C" fn(dev: *mut ALCdevice, deviceName: *const ALCchar, attribs: *const ALCint) -> ALCboolean,
+	}
 }
 
 
 pub type ALint64SOFT = i64;
 pub type ALuint64SOFT = u64;
+pub type ALBUFFERCALLBACKTYPESOFT = unsafe extern "C" fn(userptr: *mut ALvoid, sampledata: *mut ALvoid, numbytes: ALsizei) -> ALsizei;
+pub type ALEVENTPROCSOFT = unsafe extern "C" fn(event_type: ALenum, object: ALuint, param: ALuint, length: ALsizei, message: *const ALchar, user_param: *mut ALvoid);
 
 
 al_ext! {
@@ -650,14 +728,25 @@ al_ext! {
 //		pub fn alGetBufferSamplesSOFT: unsafe extern "C" fn(buffer: ALuint, offset: ALsizei, samples: ALsizei, channels: ALenum, type_: ALenum, data: *mut ALvoid),
 //		pub fn alIsBufferFormatSupportedSOFT: unsafe extern "C" fn(format: ALenum) -> ALboolean,
 //	}
-//
-//
-//	pub ext AL_SOFT_buffer_sub_data {
-//		pub const AL_BYTE_RW_OFFSETS_SOFT,
-//		pub const AL_SAMPLE_RW_OFFSETS_SOFT,
-//
-//		pub fn alBufferSubDataSOFT: unsafe extern "C" fn(buffer: ALuint, format: ALenum, data: *const ALvoid, offset: ALsizei, length: ALsizei),
-//	}
+
+
+	pub ext AL_SOFT_buffer_sub_data {
+		pub const AL_BYTE_RW_OFFSETS_SOFT,
+		pub const AL_SAMPLE_RW_OFFSETS_SOFT,
+
+		pub fn alBufferSubDataSOFT: unsafe extern "C" fn(buffer: ALuint, format: ALenum, data: *const ALvoid, offset: ALsizei, length: ALsizei),
+	}
+
+
+	pub ext AL_SOFT_callback_buffer {
+		pub const AL_BUFFER_CALLBACK_FUNCTION_SOFT,
+		pub const AL_BUFFER_CALLBACK_USER_PARAM_SOFT,
+
+		pub fn alBufferCallbackSOFT: unsafe extern "C" fn(buffer: ALuint, format: ALenum, freq: ALsizei, callback: Option<ALBUFFERCALLBACKTYPESOFT>, userptr: *mut ALvoid),
+		pub fn alGetBufferPtrSOFT: unsafe extern "C" fn(buffer: ALuint, param: ALenum, ptr: *mut *mut ALvoid),
+		pub fn alGetBuffer3PtrSOFT: unsafe extern "C" fn(buffer: ALuint, param: ALenum, ptr0: *mut *mut ALvoid, ptr1: *mut *mut ALvoid, ptr2: *mut *mut ALvoid),
+		pub fn alGetBufferPtrvSOFT: unsafe extern "C" fn(buffer: ALuint, param: ALenum, ptr: *mut *mut ALvoid),
+	}
 
 
 	pub ext AL_SOFT_deferred_updates {
@@ -668,6 +757,20 @@ al_ext! {
 	}
 
 
+	pub ext AL_SOFT_events {
+		pub const AL_EVENT_CALLBACK_FUNCTION_SOFT,
+		pub const AL_EVENT_CALLBACK_USER_PARAM_SOFT,
+		pub const AL_EVENT_TYPE_BUFFER_COMPLETED_SOFT,
+		pub const AL_EVENT_TYPE_SOURCE_STATE_CHANGED_SOFT,
+		pub const AL_EVENT_TYPE_DISCONNECTED_SOFT,
+
+		pub fn alEventControlSOFT: unsafe extern "C" fn(count: ALsizei, types: *const ALenum, enable: ALboolean),
+		pub fn alEventCallbackSOFT: unsafe extern "C" fn(callback: Option<ALEVENTPROCSOFT>, userParam: *mut ALvoid),
+		pub fn alGetPointerSOFT: unsafe extern "C" fn(pname: ALenum) -> *mut ALvoid,
+		pub fn alGetPointervSOFT: unsafe extern "C" fn(pname: ALenum, values: *mut *mut ALvoid),
+	}
+
+
 	pub ext AL_SOFT_direct_channels {
 		pub const AL_DIRECT_CHANNELS_SOFT,
 	}
@@ -710,6 +813,12 @@ al_ext! {
 	}
 
 
+	pub ext AL_SOFT_source_start_delay {
+		pub fn alSourcePlayAtTimeSOFT: unsafe extern "C" fn(source: ALuint, start_time: ALint64SOFT),
+		pub fn alSourcePlayAtTimevSOFT: unsafe extern "C" fn(n: ALsizei, sources: *const ALuint, start_time: ALint64SOFT),
+	}
+
+
 	pub ext AL_EXT_source_distance_model {
 		pub const AL_SOURCE_DISTANCE_MODEL,
 	}
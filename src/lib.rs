@@ -17,6 +17,8 @@
 extern crate lazy_static;
 extern crate parking_lot;
 extern crate al_sys;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 use std::error::Error as StdError;
 use std::fmt;
@@ -37,6 +39,43 @@ pub mod ext;
 pub mod efx;
 
 
+pub mod wav;
+
+
+pub mod stream;
+
+
+pub mod trace;
+
+
+pub mod render;
+
+
+pub mod monitor;
+
+
+pub mod reopen;
+
+
+pub mod capture;
+
+
+pub mod convert;
+
+
+pub mod resample;
+
+
+pub mod events;
+
+
+pub mod report;
+
+
+#[cfg(feature = "symphonia")]
+pub mod decode;
+
+
 pub mod sys {
 	pub use al_sys::*;
 }
@@ -108,7 +147,10 @@ impl AltoError {
 
 impl fmt::Display for AltoError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "{}", self.description())
+		match *self {
+			AltoError::Io(ref io) => write!(f, "ALTO ERROR: {}", io),
+			_ => write!(f, "{}", self.description()),
+		}
 	}
 }
 
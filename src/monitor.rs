@@ -0,0 +1,175 @@
+use std::ffi::CString;
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use ::{AltoError, AltoResult};
+use alc::*;
+
+
+/// Notifications sent by the background thread of a `DeviceMonitor`.
+#[derive(Debug)]
+pub enum DeviceEvent {
+	/// A new output device specifier appeared in `alcGetString(ALC_DEVICE_SPECIFIER)`.
+	OutputAdded(CString),
+	/// An output device specifier is no longer reported.
+	OutputRemoved(CString),
+	/// A new capture device specifier appeared in `alcGetString(ALC_CAPTURE_DEVICE_SPECIFIER)`.
+	CaptureAdded(CString),
+	/// A capture device specifier is no longer reported.
+	CaptureRemoved(CString),
+	/// `alcGetString(ALC_DEFAULT_DEVICE_SPECIFIER)` changed to the contained value.
+	DefaultOutputChanged(Option<CString>),
+	/// `alcGetString(ALC_CAPTURE_DEFAULT_DEVICE_SPECIFIER)` changed to the contained value.
+	DefaultCaptureChanged(Option<CString>),
+	/// A watched device reported that it is no longer connected, and is no longer watched.
+	Disconnected(Device),
+}
+
+
+enum Command {
+	Watch(Device),
+	Unwatch(Device),
+	Shutdown,
+}
+
+
+/// Periodically polls the implementation from a background thread for changes to the available devices,
+/// and for disconnection of watched open devices.
+/// Dropping the monitor joins the thread.
+pub struct DeviceMonitor {
+	cmds: mpsc::Sender<Command>,
+	events: mpsc::Receiver<DeviceEvent>,
+	thread: Option<thread::JoinHandle<()>>,
+}
+
+
+struct Poller {
+	alto: Alto,
+	events: mpsc::Sender<DeviceEvent>,
+	outputs: Vec<CString>,
+	captures: Vec<CString>,
+	default_output: Option<CString>,
+	default_capture: Option<CString>,
+	watched: Vec<Device>,
+}
+
+
+impl DeviceMonitor {
+	/// Take a snapshot of the current devices and begin polling for changes every `period` from a new thread.
+	pub fn new(alto: &Alto, period: Duration) -> AltoResult<DeviceMonitor> {
+		let (cmd_tx, cmd_rx) = mpsc::channel();
+		let (event_tx, event_rx) = mpsc::channel();
+
+		let mut poller = Poller{
+			alto: alto.clone(),
+			events: event_tx,
+			outputs: alto.enumerate_outputs(),
+			captures: alto.enumerate_captures(),
+			default_output: alto.default_output(),
+			default_capture: alto.default_capture(),
+			watched: Vec::new(),
+		};
+		let thread = thread::Builder::new()
+			.name("alto-monitor".into())
+			.spawn(move || poller.run(cmd_rx, period))?;
+
+		Ok(DeviceMonitor{
+			cmds: cmd_tx,
+			events: event_rx,
+			thread: Some(thread),
+		})
+	}
+
+
+	/// Report a `DeviceEvent::Disconnected` when `dev` is no longer connected.
+	/// The monitor keeps a handle to the device until it is disconnected or unwatched.
+	/// Fails with an IO error if the background thread has stopped.
+	/// Requires `ALC_EXT_DISCONNECT`
+	pub fn watch<D: DeviceObject>(&self, dev: &D) -> AltoResult<()> {
+		dev.connected()?;
+		self.cmds.send(Command::Watch(dev.to_device()))
+			.map_err(|_| AltoError::Io(io::Error::new(io::ErrorKind::BrokenPipe, "device monitor thread has stopped")))
+	}
+
+
+	/// Stop watching `dev` for disconnection and release the monitor's handle to it.
+	pub fn unwatch<D: DeviceObject>(&self, dev: &D) {
+		let _ = self.cmds.send(Command::Unwatch(dev.to_device()));
+	}
+
+
+	/// Receiver of notifications from the background thread.
+	pub fn events(&self) -> &mpsc::Receiver<DeviceEvent> { &self.events }
+}
+
+
+impl Drop for DeviceMonitor {
+	fn drop(&mut self) {
+		let _ = self.cmds.send(Command::Shutdown);
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+
+impl Poller {
+	fn run(&mut self, cmds: mpsc::Receiver<Command>, period: Duration) {
+		loop {
+			match cmds.recv_timeout(period) {
+				Ok(Command::Watch(dev)) => {
+					if !self.watched.iter().any(|d| d.as_raw() == dev.as_raw()) {
+						self.watched.push(dev);
+					}
+				},
+				Ok(Command::Unwatch(dev)) => self.watched.retain(|d| d.as_raw() != dev.as_raw()),
+				Ok(Command::Shutdown) | Err(mpsc::RecvTimeoutError::Disconnected) => return,
+				Err(mpsc::RecvTimeoutError::Timeout) => self.poll(),
+			}
+		}
+	}
+
+
+	fn poll(&mut self) {
+		let outputs = self.alto.enumerate_outputs();
+		for spec in outputs.iter().filter(|s| !self.outputs.contains(s)) {
+			let _ = self.events.send(DeviceEvent::OutputAdded(spec.clone()));
+		}
+		for spec in self.outputs.iter().filter(|s| !outputs.contains(s)) {
+			let _ = self.events.send(DeviceEvent::OutputRemoved(spec.clone()));
+		}
+		self.outputs = outputs;
+
+		let captures = self.alto.enumerate_captures();
+		for spec in captures.iter().filter(|s| !self.captures.contains(s)) {
+			let _ = self.events.send(DeviceEvent::CaptureAdded(spec.clone()));
+		}
+		for spec in self.captures.iter().filter(|s| !captures.contains(s)) {
+			let _ = self.events.send(DeviceEvent::CaptureRemoved(spec.clone()));
+		}
+		self.captures = captures;
+
+		let default_output = self.alto.default_output();
+		if default_output != self.default_output {
+			self.default_output = default_output.clone();
+			let _ = self.events.send(DeviceEvent::DefaultOutputChanged(default_output));
+		}
+
+		let default_capture = self.alto.default_capture();
+		if default_capture != self.default_capture {
+			self.default_capture = default_capture.clone();
+			let _ = self.events.send(DeviceEvent::DefaultCaptureChanged(default_capture));
+		}
+
+		let mut i = 0;
+		while i < self.watched.len() {
+			if let Ok(false) = self.watched[i].connected() {
+				let _ = self.events.send(DeviceEvent::Disconnected(self.watched.remove(i)));
+			} else {
+				i += 1;
+			}
+		}
+	}
+}
@@ -0,0 +1,125 @@
+use std::fs::File;
+use std::io::{BufWriter, Seek, Write};
+use std::mem;
+use std::path::Path;
+use std::time::Duration;
+
+use ::AltoResult;
+use sys;
+use alc::*;
+use al::*;
+use wav::WavWriter;
+
+
+/// When an `OfflineRenderer` should stop rendering.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RenderUntil {
+	/// Render exactly this much audio.
+	Elapsed(Duration),
+	/// Render until none of the sources of the context are playing.
+	/// Paused sources are considered stopped, and sources that have been dropped are ignored.
+	Stopped,
+}
+
+
+/// Renders a scene faster than realtime through a loopback device, writing the mixed output to a WAV file.
+/// Requires `ALC_SOFT_loopback`
+pub struct OfflineRenderer<F: LoopbackFrame> {
+	dev: LoopbackDevice<F>,
+	ctx: Context,
+	freq: sys::ALCint,
+	block_len: usize,
+}
+
+
+impl<F: LoopbackFrame> OfflineRenderer<F> {
+	/// Open a new loopback device and create a context on it that renders at `freq` hertz.
+	pub fn new(alto: &Alto, freq: sys::ALCint, attrs: Option<LoopbackAttrs>) -> AltoResult<OfflineRenderer<F>> {
+		let dev = alto.open_loopback::<F>(None)?;
+		let ctx = dev.new_context(freq, attrs)?;
+
+		Ok(OfflineRenderer{
+			dev: dev,
+			ctx: ctx,
+			freq: freq,
+			block_len: (freq / 100).max(1) as usize,
+		})
+	}
+
+
+	/// The loopback device being rendered.
+	pub fn device(&self) -> &LoopbackDevice<F> { &self.dev }
+	/// The context in which the scene should be built.
+	pub fn context(&self) -> &Context { &self.ctx }
+	/// Sample rate of the rendered output in hertz.
+	pub fn frequency(&self) -> sys::ALCint { self.freq }
+
+
+	/// Number of sample frames rendered between invocations of the callback. Defaults to 10ms worth.
+	pub fn block_len(&self) -> usize { self.block_len }
+	/// Set the number of sample frames rendered between invocations of the callback. A length of 0 is treated as 1.
+	pub fn set_block_len(&mut self, len: usize) {
+		self.block_len = len.max(1);
+	}
+
+
+	/// Render the scene into `writer` one block at a time.
+	/// Before each block, `callback` is invoked with the context and the number of frames rendered so far,
+	/// and may modify the scene. Returning `false` from the callback ends the rendering early.
+	/// It is not invoked again once an `Elapsed` limit has been reached.
+	/// Returns the number of sample frames written.
+	pub fn render<W, C>(&mut self, writer: &mut WavWriter<W, F>, until: RenderUntil, mut callback: C) -> AltoResult<u64> where
+		W: Write + Seek,
+		C: FnMut(&Context, u64) -> AltoResult<bool>,
+	{
+		let limit = match until {
+			RenderUntil::Elapsed(d) => Some((d.as_secs() * 1_000_000_000 + d.subsec_nanos() as u64) * self.freq as u64 / 1_000_000_000),
+			RenderUntil::Stopped => None,
+		};
+
+		let mut block = vec![unsafe { mem::zeroed() }; self.block_len];
+		let mut rendered = 0;
+		loop {
+			if limit.map(|limit| rendered >= limit).unwrap_or(false) || !callback(&self.ctx, rendered)? {
+				break;
+			}
+
+			let len = match limit {
+				Some(limit) => (limit - rendered).min(self.block_len as u64) as usize,
+				None if !self.any_playing() => break,
+				None => self.block_len,
+			};
+
+			self.dev.soft_render_samples(&mut block[.. len]);
+			writer.write_frames(&block[.. len])?;
+			rendered += len as u64;
+		}
+
+		Ok(rendered)
+	}
+
+
+	/// Render the scene into a new WAV file at `path`. See `render`.
+	pub fn render_to_file<P, C>(&mut self, path: P, until: RenderUntil, callback: C) -> AltoResult<u64> where
+		P: AsRef<Path>,
+		C: FnMut(&Context, u64) -> AltoResult<bool>,
+	{
+		let mut writer = WavWriter::new(BufWriter::new(File::create(path)?), self.freq)?;
+		let frames = self.render(&mut writer, until, callback)?;
+		writer.finish()?;
+		Ok(frames)
+	}
+
+
+	fn any_playing(&self) -> bool {
+		// The returned references keep each source from being deleted while its state is queried.
+		let srcs = self.ctx.live_sources();
+		let _lock = self.ctx.make_current(true);
+		let api = &self.ctx.0.dev.0.alto.0.api;
+		srcs.iter().any(|src| unsafe {
+			let mut state = 0;
+			api.alGetSourcei(src.as_raw(), sys::AL_SOURCE_STATE, &mut state);
+			state == sys::AL_PLAYING
+		})
+	}
+}
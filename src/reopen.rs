@@ -0,0 +1,575 @@
+use std::collections::VecDeque;
+use std::ffi::CStr;
+use std::mem;
+use std::slice;
+use std::sync::Arc;
+
+use ::{AltoError, AltoResult};
+use sys;
+use ext;
+use alc::*;
+use al::*;
+use efx::{AuxEffectSlot, EffectSnapshot, FilterSnapshot};
+
+
+/// Handle to a buffer owned by a `ReopenableDevice`. Remains valid across migrations.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BufferId(usize);
+
+
+/// Handle to a source owned by a `ReopenableDevice`. Remains valid across migrations.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SourceId(usize);
+
+
+/// Handle to an aux effect slot owned by a `ReopenableDevice`. Remains valid across migrations.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SlotId(usize);
+
+
+/// A copy of uploaded sample data, kept so it can be uploaded again to a new device.
+struct BufferData {
+	format: Format,
+	data: Vec<u8>,
+	len: sys::ALsizei,
+	freq: sys::ALint,
+}
+
+
+struct ShadowBuffer {
+	buf: Arc<Buffer>,
+	data: BufferData,
+}
+
+
+enum SourceKind {
+	Static(StaticSource, Option<BufferId>),
+	Streaming(StreamingSource, VecDeque<BufferData>),
+}
+
+
+struct ShadowSource {
+	kind: SourceKind,
+	state: SourceState,
+	offset: sys::ALint,
+	filter: Option<FilterSnapshot>,
+	sends: Vec<(sys::ALint, SlotId)>,
+}
+
+
+struct ShadowSlot {
+	slot: AuxEffectSlot,
+	effect: Option<EffectSnapshot>,
+}
+
+
+/// An output device whose context and objects can be moved to another device,
+/// such as when the original is unplugged.
+///
+/// Objects are created through the device and addressed by id, rather than owned directly.
+/// A copy of all buffer data is kept, along with the state that can't be read back from a disconnected device.
+/// Everything else, such as source and listener properties, is read from the old objects when migrating.
+/// Buffers, effects, filters, and sends must be assigned through the device so they can be tracked.
+pub struct ReopenableDevice {
+	alto: Alto,
+	attrs: Option<ContextAttrs>,
+	dev: OutputDevice,
+	ctx: Context,
+	buffers: Vec<Option<ShadowBuffer>>,
+	sources: Vec<Option<ShadowSource>>,
+	slots: Vec<Option<ShadowSlot>>,
+}
+
+
+impl BufferData {
+	fn new<F: SampleFrame, B: AsBufferData<F>>(data: B, freq: sys::ALint) -> BufferData {
+		let (ptr, size) = data.as_buffer_data();
+		BufferData{
+			format: F::format(),
+			data: unsafe { slice::from_raw_parts(ptr as *const u8, size) }.to_vec(),
+			len: (size / mem::size_of::<F::Sample>() / F::len()) as sys::ALsizei,
+			freq: freq,
+		}
+	}
+
+
+	fn upload(&self, ctx: &Context) -> AltoResult<Buffer> {
+		Buffer::new_raw(ctx.clone(), self.format, &self.data, self.len, self.freq, None)
+	}
+}
+
+
+impl ShadowSource {
+	/// Remember the playback position, which is lost when the device is disconnected.
+	fn record(&mut self) {
+		let (state, offset) = match self.kind {
+			SourceKind::Static(ref src, _) => (src.state(), src.sample_offset()),
+			SourceKind::Streaming(ref src, _) => (src.state(), src.sample_offset()),
+		};
+		self.state = state;
+		self.offset = offset;
+	}
+}
+
+
+impl ReopenableDevice {
+	/// Open the device named by `spec`, or the default device, and create a context on it with `attrs`.
+	/// The same attributes are used when the context is recreated on another device.
+	pub fn open(alto: &Alto, spec: Option<&CStr>, attrs: Option<ContextAttrs>) -> AltoResult<ReopenableDevice> {
+		let dev = alto.open(spec)?;
+		let ctx = dev.new_context(attrs)?;
+
+		Ok(ReopenableDevice{
+			alto: alto.clone(),
+			attrs: attrs,
+			dev: dev,
+			ctx: ctx,
+			buffers: Vec::new(),
+			sources: Vec::new(),
+			slots: Vec::new(),
+		})
+	}
+
+
+	/// The device currently in use. This changes when the device is reopened.
+	pub fn device(&self) -> &OutputDevice { &self.dev }
+	/// The context currently in use, through which listener state can be modified.
+	/// This changes when the device is reopened.
+	pub fn context(&self) -> &Context { &self.ctx }
+
+
+	/// Check whether the current device is still connected, and if not, migrate to the default device.
+	/// While connected, the playback position of every source is recorded so it can be restored later,
+	/// so this should be called regularly. Devices without `ALC_EXT_DISCONNECT` are assumed to stay connected.
+	/// Returns `true` if the device was reopened.
+	pub fn check(&mut self) -> AltoResult<bool> {
+		if self.dev.connected().unwrap_or(true) {
+			self.record();
+			Ok(false)
+		} else {
+			self.reopen(None).map(|_| true)
+		}
+	}
+
+
+	/// Move the context and every object to the device named by `spec`, or the default device.
+	/// Sources resume from the position they were at when the old device was disconnected,
+	/// or from their current position if it is still connected.
+	/// If migration fails, the old device and objects are left in place.
+	pub fn reopen(&mut self, spec: Option<&CStr>) -> AltoResult<()> {
+		if self.dev.connected().unwrap_or(true) {
+			self.record();
+		}
+
+		let dev = self.alto.open(spec)?;
+		let ctx = dev.new_context(self.attrs)?;
+		copy_listener(&self.ctx, &ctx)?;
+
+		let mut bufs = Vec::with_capacity(self.buffers.len());
+		for shadow in &self.buffers {
+			bufs.push(match *shadow {
+				Some(ref shadow) => Some(Arc::new(shadow.data.upload(&ctx)?)),
+				None => None,
+			});
+		}
+
+		let mut slots = Vec::with_capacity(self.slots.len());
+		for shadow in &self.slots {
+			slots.push(match *shadow {
+				Some(ref shadow) => {
+					let mut slot = ctx.new_aux_effect_slot()?;
+					slot.set_gain(shadow.slot.gain())?;
+					slot.set_aux_send_auto(shadow.slot.aux_send_auto())?;
+					if let Some(ref effect) = shadow.effect {
+						effect.apply(&mut slot)?;
+					}
+					Some(slot)
+				},
+				None => None,
+			});
+		}
+
+		let mut srcs = Vec::with_capacity(self.sources.len());
+		for shadow in &self.sources {
+			srcs.push(match *shadow {
+				Some(ref shadow) => Some(match shadow.kind {
+					SourceKind::Static(ref old, buf) => {
+						let mut src = ctx.new_static_source()?;
+						copy_source(old, &mut src)?;
+						src.set_looping(old.looping());
+						if let Some(buf) = buf {
+							src.set_buffer(bufs[buf.0].clone().unwrap())?;
+						}
+						restore_source(shadow, &mut src, &mut slots)?;
+						SourceKind::Static(src, buf)
+					},
+					SourceKind::Streaming(ref old, ref queue) => {
+						let mut src = ctx.new_streaming_source()?;
+						copy_source(old, &mut src)?;
+						for data in queue {
+							src.queue_buffer(data.upload(&ctx)?)?;
+						}
+						restore_source(shadow, &mut src, &mut slots)?;
+						SourceKind::Streaming(src, VecDeque::new())
+					},
+				}),
+				None => None,
+			});
+		}
+
+		for (shadow, src) in self.sources.iter_mut().zip(srcs) {
+			if let (&mut Some(ref mut shadow), Some(src)) = (shadow, src) {
+				let old = mem::replace(&mut shadow.kind, src);
+				if let (SourceKind::Streaming(_, queue), &mut SourceKind::Streaming(_, ref mut new)) = (old, &mut shadow.kind) {
+					*new = queue;
+				}
+			}
+		}
+		for (shadow, slot) in self.slots.iter_mut().zip(slots) {
+			if let (&mut Some(ref mut shadow), Some(slot)) = (shadow, slot) {
+				shadow.slot = slot;
+			}
+		}
+		for (shadow, buf) in self.buffers.iter_mut().zip(bufs) {
+			if let (&mut Some(ref mut shadow), Some(buf)) = (shadow, buf) {
+				shadow.buf = buf;
+			}
+		}
+		self.ctx = ctx;
+		self.dev = dev;
+		self.record();
+
+		Ok(())
+	}
+
+
+	/// Create a new buffer on the current device and keep a copy of `data`.
+	pub fn new_buffer<F: SampleFrame, B: AsBufferData<F>>(&mut self, data: B, freq: sys::ALint) -> AltoResult<BufferId> {
+		let data = BufferData::new(data, freq);
+		let buf = data.upload(&self.ctx)?;
+		self.buffers.push(Some(ShadowBuffer{buf: Arc::new(buf), data: data}));
+		Ok(BufferId(self.buffers.len() - 1))
+	}
+
+
+	/// The buffer currently backing `id`.
+	pub fn buffer(&self, id: BufferId) -> AltoResult<&Arc<Buffer>> {
+		self.buffers.get(id.0).and_then(|b| b.as_ref()).map(|b| &b.buf).ok_or(AltoError::InvalidName)
+	}
+
+
+	/// Delete a buffer and its copy of the data.
+	/// Fails with `InvalidOperation` if the buffer is attached to a source.
+	pub fn delete_buffer(&mut self, id: BufferId) -> AltoResult<()> {
+		self.buffer(id)?;
+		if self.sources.iter().any(|s| match *s {
+			Some(ShadowSource{kind: SourceKind::Static(_, Some(buf)), ..}) => buf == id,
+			_ => false,
+		}) {
+			return Err(AltoError::InvalidOperation);
+		}
+
+		self.buffers[id.0] = None;
+		Ok(())
+	}
+
+
+	/// Create a new static source on the current device.
+	pub fn new_static_source(&mut self) -> AltoResult<SourceId> {
+		let src = self.ctx.new_static_source()?;
+		Ok(self.push_source(SourceKind::Static(src, None)))
+	}
+
+
+	/// Create a new streaming source on the current device.
+	pub fn new_streaming_source(&mut self) -> AltoResult<SourceId> {
+		let src = self.ctx.new_streaming_source()?;
+		Ok(self.push_source(SourceKind::Streaming(src, VecDeque::new())))
+	}
+
+
+	fn push_source(&mut self, kind: SourceKind) -> SourceId {
+		self.sources.push(Some(ShadowSource{
+			kind: kind,
+			state: SourceState::Initial,
+			offset: 0,
+			filter: None,
+			sends: Vec::new(),
+		}));
+		SourceId(self.sources.len() - 1)
+	}
+
+
+	fn shadow_source(&mut self, id: SourceId) -> AltoResult<&mut ShadowSource> {
+		self.sources.get_mut(id.0).and_then(|s| s.as_mut()).ok_or(AltoError::InvalidName)
+	}
+
+
+	/// The static source currently backing `id`, through which its properties can be modified.
+	/// Fails with `InvalidName` if `id` is a streaming source.
+	pub fn static_source(&mut self, id: SourceId) -> AltoResult<&mut StaticSource> {
+		match self.shadow_source(id)?.kind {
+			SourceKind::Static(ref mut src, _) => Ok(src),
+			SourceKind::Streaming(..) => Err(AltoError::InvalidName),
+		}
+	}
+
+
+	/// The streaming source currently backing `id`, through which its properties can be modified.
+	/// Buffers must be queued with `queue_data` rather than on the source directly.
+	/// Fails with `InvalidName` if `id` is a static source.
+	pub fn streaming_source(&mut self, id: SourceId) -> AltoResult<&mut StreamingSource> {
+		match self.shadow_source(id)?.kind {
+			SourceKind::Streaming(ref mut src, _) => Ok(src),
+			SourceKind::Static(..) => Err(AltoError::InvalidName),
+		}
+	}
+
+
+	/// Delete a source.
+	pub fn delete_source(&mut self, id: SourceId) -> AltoResult<()> {
+		self.shadow_source(id)?;
+		self.sources[id.0] = None;
+		Ok(())
+	}
+
+
+	/// Attach a buffer to a static source, or detach its buffer with `None`.
+	pub fn set_buffer(&mut self, src: SourceId, buf: Option<BufferId>) -> AltoResult<()> {
+		let arc = match buf {
+			Some(buf) => Some(self.buffer(buf)?.clone()),
+			None => None,
+		};
+		let shadow = self.shadow_source(src)?;
+		match shadow.kind {
+			SourceKind::Static(ref mut src, ref mut cur) => {
+				match arc {
+					Some(arc) => src.set_buffer(arc)?,
+					None => src.clear_buffer(),
+				}
+				*cur = buf;
+				Ok(())
+			},
+			SourceKind::Streaming(..) => Err(AltoError::InvalidName),
+		}
+	}
+
+
+	/// Upload `data` to a new buffer and append it to the queue of a streaming source.
+	pub fn queue_data<F: SampleFrame, B: AsBufferData<F>>(&mut self, src: SourceId, data: B, freq: sys::ALint) -> AltoResult<()> {
+		let data = BufferData::new(data, freq);
+		let shadow = self.shadow_source(src)?;
+		match shadow.kind {
+			SourceKind::Streaming(ref mut src, ref mut queue) => {
+				let buf = data.upload(src.context())?;
+				src.queue_buffer(buf)?;
+				queue.push_back(data);
+				Ok(())
+			},
+			SourceKind::Static(..) => Err(AltoError::InvalidName),
+		}
+	}
+
+
+	/// Remove and delete all processed buffers from the queue of a streaming source.
+	/// Returns the number of buffers removed.
+	pub fn unqueue_processed(&mut self, src: SourceId) -> AltoResult<usize> {
+		let shadow = self.shadow_source(src)?;
+		match shadow.kind {
+			SourceKind::Streaming(ref mut src, ref mut queue) => {
+				let processed = src.buffers_processed().max(0) as usize;
+				for _ in 0 .. processed {
+					src.unqueue_buffer()?;
+					if let Some(data) = queue.pop_front() {
+						shadow.offset = (shadow.offset - data.len).max(0);
+					}
+				}
+				Ok(processed)
+			},
+			SourceKind::Static(..) => Err(AltoError::InvalidName),
+		}
+	}
+
+
+	/// Create a new aux effect slot on the current device.
+	/// Requires `ALC_EXT_EFX`
+	pub fn new_aux_effect_slot(&mut self) -> AltoResult<SlotId> {
+		let slot = self.ctx.new_aux_effect_slot()?;
+		self.slots.push(Some(ShadowSlot{slot: slot, effect: None}));
+		Ok(SlotId(self.slots.len() - 1))
+	}
+
+
+	/// The aux effect slot currently backing `id`, through which its gain can be modified.
+	/// Effects must be assigned with `set_slot_effect` rather than on the slot directly.
+	pub fn aux_effect_slot(&mut self, id: SlotId) -> AltoResult<&mut AuxEffectSlot> {
+		self.slots.get_mut(id.0).and_then(|s| s.as_mut()).map(|s| &mut s.slot).ok_or(AltoError::InvalidName)
+	}
+
+
+	/// Load an effect into a slot, or clear it with `None`.
+	pub fn set_slot_effect(&mut self, id: SlotId, effect: Option<EffectSnapshot>) -> AltoResult<()> {
+		let shadow = self.slots.get_mut(id.0).and_then(|s| s.as_mut()).ok_or(AltoError::InvalidName)?;
+		match effect {
+			Some(ref effect) => effect.apply(&mut shadow.slot)?,
+			None => shadow.slot.clear_effect(),
+		}
+		shadow.effect = effect;
+		Ok(())
+	}
+
+
+	/// Delete an aux effect slot. Sources sending to it have the send cleared.
+	pub fn delete_aux_effect_slot(&mut self, id: SlotId) -> AltoResult<()> {
+		self.aux_effect_slot(id)?;
+		for shadow in self.sources.iter_mut().filter_map(|s| s.as_mut()) {
+			let sends = mem::take(&mut shadow.sends);
+			for (send, slot) in sends {
+				if slot == id {
+					match shadow.kind {
+						SourceKind::Static(ref mut src, _) => src.clear_aux_send(send),
+						SourceKind::Streaming(ref mut src, _) => src.clear_aux_send(send),
+					}
+				} else {
+					shadow.sends.push((send, slot));
+				}
+			}
+		}
+
+		self.slots[id.0] = None;
+		Ok(())
+	}
+
+
+	/// Set the direct filter of a source, or clear it with `None`.
+	/// Requires `ALC_EXT_EFX`
+	pub fn set_direct_filter(&mut self, src: SourceId, filter: Option<FilterSnapshot>) -> AltoResult<()> {
+		let shadow = self.shadow_source(src)?;
+		match shadow.kind {
+			SourceKind::Static(ref mut src, _) => set_direct_filter(src, filter.as_ref())?,
+			SourceKind::Streaming(ref mut src, _) => set_direct_filter(src, filter.as_ref())?,
+		}
+		shadow.filter = filter;
+		Ok(())
+	}
+
+
+	/// Route a send of a source to an aux effect slot, or clear it with `None`.
+	/// Requires `ALC_EXT_EFX`
+	pub fn set_aux_send(&mut self, src: SourceId, send: sys::ALint, slot: Option<SlotId>) -> AltoResult<()> {
+		let shadow = self.sources.get_mut(src.0).and_then(|s| s.as_mut()).ok_or(AltoError::InvalidName)?;
+		match slot {
+			Some(id) => {
+				let slot = self.slots.get_mut(id.0).and_then(|s| s.as_mut()).map(|s| &mut s.slot).ok_or(AltoError::InvalidName)?;
+				match shadow.kind {
+					SourceKind::Static(ref mut src, _) => src.set_aux_send(send, slot)?,
+					SourceKind::Streaming(ref mut src, _) => src.set_aux_send(send, slot)?,
+				}
+			},
+			None => match shadow.kind {
+				SourceKind::Static(ref mut src, _) => src.clear_aux_send(send),
+				SourceKind::Streaming(ref mut src, _) => src.clear_aux_send(send),
+			},
+		}
+
+		shadow.sends.retain(|&(s, _)| s != send);
+		if let Some(slot) = slot {
+			shadow.sends.push((send, slot));
+		}
+		Ok(())
+	}
+
+
+	fn record(&mut self) {
+		for shadow in self.sources.iter_mut().filter_map(|s| s.as_mut()) {
+			shadow.record();
+		}
+	}
+}
+
+
+fn set_direct_filter<S: Source>(src: &mut S, filter: Option<&FilterSnapshot>) -> AltoResult<()> {
+	match filter {
+		Some(filter) => filter.apply_direct(src),
+		None => {
+			src.clear_direct_filter();
+			Ok(())
+		},
+	}
+}
+
+
+fn copy_listener(from: &Context, to: &Context) -> AltoResult<()> {
+	to.set_gain(from.gain())?;
+	to.set_position(from.position::<[f32; 3]>())?;
+	to.set_velocity(from.velocity::<[f32; 3]>())?;
+	to.set_orientation(from.orientation::<[f32; 3]>())?;
+	to.set_distance_model(from.distance_model());
+	to.set_doppler_factor(from.doppler_factor())?;
+	to.set_speed_of_sound(from.speed_of_sound())?;
+	if to.is_extension_present(ext::Al::SourceDistanceModel) {
+		to.use_source_distance_model(from.using_source_distance_model())?;
+	}
+	if to.device().is_extension_present(ext::Alc::Efx) {
+		to.set_meters_per_unit(from.meters_per_unit())?;
+	}
+	Ok(())
+}
+
+
+fn copy_source<S: Source, T: Source>(from: &S, to: &mut T) -> AltoResult<()> {
+	to.set_relative(from.relative());
+	to.set_gain(from.gain())?;
+	to.set_min_gain(from.min_gain())?;
+	to.set_max_gain(from.max_gain())?;
+	to.set_reference_distance(from.reference_distance())?;
+	to.set_rolloff_factor(from.rolloff_factor())?;
+	to.set_max_distance(from.max_distance())?;
+	to.set_pitch(from.pitch())?;
+	to.set_position(from.position::<[f32; 3]>())?;
+	to.set_velocity(from.velocity::<[f32; 3]>())?;
+	to.set_direction(from.direction::<[f32; 3]>())?;
+	to.set_cone_inner_angle(from.cone_inner_angle())?;
+	to.set_cone_outer_angle(from.cone_outer_angle())?;
+	to.set_cone_outer_gain(from.cone_outer_gain())?;
+
+	let ctx = to.context().clone();
+	if ctx.is_extension_present(ext::Al::SoftDirectChannels) {
+		to.set_soft_direct_channels(from.soft_direct_channels())?;
+	}
+	if ctx.is_extension_present(ext::Al::SourceDistanceModel) {
+		to.set_distance_model(from.distance_model())?;
+	}
+	if ctx.device().is_extension_present(ext::Alc::Efx) {
+		to.set_air_absorption_factor(from.air_absorption_factor())?;
+		to.set_room_rolloff_factor(from.room_rolloff_factor())?;
+		to.set_cone_outer_gainhf(from.cone_outer_gainhf())?;
+		to.set_direct_filter_gainhf_auto(from.direct_filter_gainhf_auto())?;
+	}
+	Ok(())
+}
+
+
+/// Reattach effects and resume playback of a recreated source.
+fn restore_source<S: Source>(shadow: &ShadowSource, src: &mut S, slots: &mut [Option<AuxEffectSlot>]) -> AltoResult<()> {
+	if let Some(ref filter) = shadow.filter {
+		filter.apply_direct(src)?;
+	}
+	for &(send, slot) in &shadow.sends {
+		if let Some(Some(ref mut slot)) = slots.get_mut(slot.0) {
+			src.set_aux_send(send, slot)?;
+		}
+	}
+
+	match shadow.state {
+		SourceState::Playing | SourceState::Paused => {
+			src.set_sample_offset(shadow.offset)?;
+			src.play();
+			if shadow.state == SourceState::Paused {
+				src.pause();
+			}
+		},
+		_ => (),
+	}
+	Ok(())
+}
@@ -0,0 +1,122 @@
+use std::ffi::CString;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use sys;
+use ext;
+use alc::*;
+use al::*;
+
+
+/// Extensions reported by the implementation, split by whether alto exposes them.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtensionReport {
+	/// Extensions alto knows how to use.
+	pub recognized: Vec<String>,
+	/// Extensions reported by the implementation that alto has no bindings for.
+	pub unrecognized: Vec<String>,
+}
+
+
+/// Capabilities of an OpenAL implementation as seen through a context and its device, suitable for attaching to bug reports.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CapabilityReport {
+	/// Specifier of the device.
+	pub device: Option<String>,
+	/// `alcGetIntegerv(ALC_MAJOR_VERSION)`, `alcGetIntegerv(ALC_MINOR_VERSION)`
+	pub alc_version: (sys::ALCint, sys::ALCint),
+	/// `alGetString(AL_VERSION)`
+	pub al_version: Option<String>,
+	/// `alGetString(AL_VENDOR)`
+	pub vendor: Option<String>,
+	/// `alGetString(AL_RENDERER)`
+	pub renderer: Option<String>,
+	/// `alcGetString(ALC_EXTENSIONS)`
+	pub alc_extensions: ExtensionReport,
+	/// `alGetString(AL_EXTENSIONS)`
+	pub al_extensions: ExtensionReport,
+	/// HRTFs available to the device, empty without `ALC_SOFT_HRTF`.
+	pub hrtfs: Vec<String>,
+	/// Resamplers available to sources, empty without `AL_SOFT_source_resampler`.
+	pub resamplers: Vec<String>,
+	/// Auxiliary sends per source, 0 without `ALC_EXT_EFX`.
+	pub max_aux_sends: sys::ALCint,
+	/// Largest gain a source or the listener may be set to, `None` without `AL_SOFT_gain_clamp_ex`.
+	pub gain_limit: Option<f32>,
+}
+
+
+impl CapabilityReport {
+	/// Gather the capabilities of `ctx` and its device.
+	pub fn new(ctx: &Context) -> CapabilityReport {
+		let dev = ctx.device();
+		let alc_names: Vec<_> = ext::AlcNullCache::NAMES.iter().chain(ext::AlcCache::NAMES).collect();
+
+		CapabilityReport{
+			device: dev.specifier().map(|s| s.to_string_lossy().into_owned()),
+			alc_version: dev.alc_version(),
+			al_version: ctx.version().map(lossy),
+			vendor: ctx.vendor().map(lossy),
+			renderer: ctx.renderer().map(lossy),
+			alc_extensions: ExtensionReport::new(dev.alc_extensions(), |n| alc_names.contains(&&n)),
+			al_extensions: ExtensionReport::new(ctx.extensions(), |n| ext::AlCache::NAMES.contains(&n)),
+			hrtfs: dev.enumerate_soft_hrtfs().into_iter().map(lossy).collect(),
+			resamplers: ctx.enumerate_soft_resamplers().into_iter().map(lossy).collect(),
+			max_aux_sends: dev.max_aux_sends(),
+			gain_limit: ctx.soft_gain_limit().ok(),
+		}
+	}
+}
+
+
+impl ExtensionReport {
+	fn new<F: Fn(&str) -> bool>(names: Vec<CString>, recognized: F) -> ExtensionReport {
+		let mut report = ExtensionReport::default();
+		for name in names.into_iter().map(lossy) {
+			if recognized(&name) {
+				report.recognized.push(name);
+			} else {
+				report.unrecognized.push(name);
+			}
+		}
+		report
+	}
+}
+
+
+impl fmt::Display for CapabilityReport {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fn opt(s: &Option<String>) -> &str { s.as_ref().map(|s| s.as_str()).unwrap_or("(unknown)") }
+		fn list(f: &mut fmt::Formatter, title: &str, items: &[String]) -> fmt::Result {
+			writeln!(f, "{} ({}):", title, items.len())?;
+			for item in items {
+				writeln!(f, "    {}", item)?;
+			}
+			Ok(())
+		}
+
+		writeln!(f, "Device: {}", opt(&self.device))?;
+		writeln!(f, "ALC version: {}.{}", self.alc_version.0, self.alc_version.1)?;
+		writeln!(f, "AL version: {}", opt(&self.al_version))?;
+		writeln!(f, "Vendor: {}", opt(&self.vendor))?;
+		writeln!(f, "Renderer: {}", opt(&self.renderer))?;
+		list(f, "ALC extensions", &self.alc_extensions.recognized)?;
+		list(f, "Unrecognized ALC extensions", &self.alc_extensions.unrecognized)?;
+		list(f, "AL extensions", &self.al_extensions.recognized)?;
+		list(f, "Unrecognized AL extensions", &self.al_extensions.unrecognized)?;
+		list(f, "HRTFs", &self.hrtfs)?;
+		list(f, "Resamplers", &self.resamplers)?;
+		writeln!(f, "Max auxiliary sends: {}", self.max_aux_sends)?;
+		match self.gain_limit {
+			Some(limit) => writeln!(f, "Gain limit: {}", limit),
+			None => writeln!(f, "Gain limit: (unknown)"),
+		}
+	}
+}
+
+
+fn lossy(s: CString) -> String { s.to_string_lossy().into_owned() }
@@ -0,0 +1,296 @@
+use std::f64::consts::PI;
+use std::marker::PhantomData;
+
+use ::{AltoError, AltoResult};
+use sys;
+use convert::ConvertFrame;
+use stream::Decoder;
+
+
+/// Number of fractional positions between input frames for which filter coefficients are tabulated.
+/// Coefficients between two tabulated positions are linearly interpolated.
+const PHASES: usize = 512;
+
+
+/// Tradeoff between quality and CPU time of a `Resampler`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Quality {
+	/// Linear interpolation between adjacent frames. Very cheap, but aliases noticeably.
+	Linear,
+	/// Kaiser-windowed sinc filter with 16 taps.
+	Low,
+	/// Kaiser-windowed sinc filter with 32 taps.
+	Medium,
+	/// Kaiser-windowed sinc filter with 64 taps.
+	High,
+}
+
+
+/// Converts sample frames from one sample rate to another.
+///
+/// Input may be supplied in blocks of any size; the filter state carries over between calls to `process`,
+/// so the output is the same as if all input was supplied at once.
+pub struct Resampler<F: ConvertFrame> {
+	from: sys::ALint,
+	to: sys::ALint,
+	half: usize,
+	table: Vec<f32>,
+	/// Interleaved samples of the frames around the current position, padded at the start with silence.
+	history: Vec<f32>,
+	/// Index of the frame in `history` at or before the current position.
+	base: usize,
+	/// Fractional part of the current position, in units of `1 / to`.
+	frac: u64,
+	consumed: u64,
+	produced: u64,
+	marker: PhantomData<F>,
+}
+
+
+/// Wraps a `Decoder`, resampling its output to a different rate.
+pub struct ResampleDecoder<D: Decoder> where D::Frame: ConvertFrame {
+	decoder: D,
+	resampler: Resampler<D::Frame>,
+	input: Vec<D::Frame>,
+	output: Vec<D::Frame>,
+	finished: bool,
+}
+
+
+impl Quality {
+	fn taps(&self) -> (usize, f64, f64) {
+		match *self {
+			Quality::Linear => (1, 0.0, 1.0),
+			Quality::Low => (8, 6.0, 0.85),
+			Quality::Medium => (16, 8.0, 0.9),
+			Quality::High => (32, 10.0, 0.95),
+		}
+	}
+}
+
+
+impl<F: ConvertFrame> Resampler<F> {
+	/// A resampler from `from` hertz to `to` hertz.
+	pub fn new(from: sys::ALint, to: sys::ALint, quality: Quality) -> AltoResult<Resampler<F>> {
+		if from <= 0 || to <= 0 {
+			return Err(AltoError::InvalidValue);
+		}
+
+		let (half, beta, bandwidth) = quality.taps();
+		// Lower the cutoff below the output's nyquist frequency when downsampling.
+		let cutoff = bandwidth * (to as f64 / from as f64).min(1.0);
+		let mut table = Vec::with_capacity((PHASES + 1) * half * 2);
+		for p in 0 .. PHASES + 1 {
+			for k in 0 .. half * 2 {
+				let x = k as f64 - (half - 1) as f64 - p as f64 / PHASES as f64;
+				let coeff = if quality == Quality::Linear {
+					(1.0 - x.abs()).max(0.0)
+				} else {
+					cutoff * sinc(cutoff * x) * kaiser(x / half as f64, beta)
+				};
+				table.push(coeff as f32);
+			}
+		}
+
+		let mut resampler = Resampler{
+			from: from,
+			to: to,
+			half: half,
+			table: table,
+			history: Vec::new(),
+			base: 0,
+			frac: 0,
+			consumed: 0,
+			produced: 0,
+			marker: PhantomData,
+		};
+		resampler.reset();
+		Ok(resampler)
+	}
+
+
+	/// Input sample rate in hertz.
+	pub fn from_frequency(&self) -> sys::ALint { self.from }
+	/// Output sample rate in hertz.
+	pub fn to_frequency(&self) -> sys::ALint { self.to }
+
+
+	/// Discard all buffered input, as when seeking to a new position in a stream.
+	pub fn reset(&mut self) {
+		self.history.clear();
+		self.history.resize(self.half * F::len(), 0.0);
+		self.base = self.half;
+		self.frac = 0;
+		self.consumed = 0;
+		self.produced = 0;
+	}
+
+
+	/// Resample `input`, appending to `output` every frame that can be computed so far.
+	/// Frames that need input beyond the end of `input` are produced by later calls, or by `flush`.
+	pub fn process(&mut self, input: &[F], output: &mut Vec<F>) {
+		let chans = F::len();
+		let start = self.history.len();
+		self.history.resize(start + input.len() * chans, 0.0);
+		for (frame, samples) in input.iter().zip(self.history[start ..].chunks_mut(chans)) {
+			frame.read(samples);
+		}
+		self.consumed += input.len() as u64;
+
+		self.generate(output);
+	}
+
+
+	/// Resample any buffered input as though it were followed by silence, and reset for a new stream.
+	/// After a flush, the total output is `ceil(input * to / from)` frames.
+	pub fn flush(&mut self, output: &mut Vec<F>) {
+		let len = self.history.len();
+		self.history.resize(len + self.half * F::len(), 0.0);
+		self.generate(output);
+		self.reset();
+	}
+
+
+	fn generate(&mut self, output: &mut Vec<F>) {
+		let chans = F::len();
+		let taps = self.half * 2;
+		let (step, step_frac) = ((self.from / self.to) as usize, (self.from % self.to) as u64);
+		let mut acc = [0.0f32; 8];
+
+		while (self.base + self.half + 1) * chans <= self.history.len() && self.produced * (self.from as u64) < self.consumed * (self.to as u64) {
+			let phase = self.frac as f64 * PHASES as f64 / self.to as f64;
+			let row = (phase as usize).min(PHASES - 1);
+			let t = (phase - row as f64) as f32;
+			let (lo, hi) = (&self.table[row * taps .. (row + 1) * taps], &self.table[(row + 1) * taps .. (row + 2) * taps]);
+
+			for a in &mut acc[.. chans] {
+				*a = 0.0;
+			}
+			let first = self.base + 1 - self.half;
+			for k in 0 .. taps {
+				let coeff = lo[k] + (hi[k] - lo[k]) * t;
+				let frame = &self.history[(first + k) * chans .. (first + k + 1) * chans];
+				for (a, s) in acc[.. chans].iter_mut().zip(frame) {
+					*a += coeff * s;
+				}
+			}
+			output.push(F::write(&acc[.. chans], || 0.0));
+			self.produced += 1;
+
+			self.base += step;
+			self.frac += step_frac;
+			if self.frac >= self.to as u64 {
+				self.frac -= self.to as u64;
+				self.base += 1;
+			}
+		}
+
+		// Drop frames that no future output can reach.
+		let unused = (self.base + 1 - self.half).min(self.history.len() / chans);
+		if unused > 0 {
+			self.history.drain(.. unused * chans);
+			self.base -= unused;
+		}
+	}
+
+
+	/// Resample all of `input` at once, including the tail that would otherwise need a `flush`.
+	pub fn resample(&mut self, input: &[F]) -> Vec<F> {
+		let mut output = Vec::with_capacity((input.len() as u64 * self.to as u64 / self.from as u64) as usize + 1);
+		self.process(input, &mut output);
+		self.flush(&mut output);
+		output
+	}
+}
+
+
+/// Resample `frames` from `from` hertz to `to` hertz, for example to match the frequency of the output device
+/// before uploading with `Context::new_buffer`.
+pub fn resample<F: ConvertFrame>(frames: &[F], from: sys::ALint, to: sys::ALint, quality: Quality) -> AltoResult<Vec<F>> {
+	Ok(Resampler::new(from, to, quality)?.resample(frames))
+}
+
+
+impl<D: Decoder> ResampleDecoder<D> where D::Frame: ConvertFrame {
+	/// Resample the output of `decoder` to `to` hertz.
+	pub fn new(decoder: D, to: sys::ALint, quality: Quality) -> AltoResult<ResampleDecoder<D>> {
+		Ok(ResampleDecoder{
+			resampler: Resampler::new(decoder.frequency(), to, quality)?,
+			decoder: decoder,
+			input: Vec::new(),
+			output: Vec::new(),
+			finished: false,
+		})
+	}
+
+
+	/// The wrapped decoder.
+	pub fn get_ref(&self) -> &D { &self.decoder }
+	/// Unwrap the decoder.
+	pub fn into_inner(self) -> D { self.decoder }
+}
+
+
+impl<D: Decoder> Decoder for ResampleDecoder<D> where D::Frame: ConvertFrame {
+	type Frame = D::Frame;
+
+
+	fn frequency(&self) -> sys::ALint { self.resampler.to }
+
+
+	fn decode(&mut self, frames: &mut Vec<D::Frame>, max: usize) -> AltoResult<()> {
+		while self.output.len() < max && !self.finished {
+			self.input.clear();
+			self.decoder.decode(&mut self.input, max)?;
+			if self.input.is_empty() {
+				self.resampler.flush(&mut self.output);
+				self.finished = true;
+			} else {
+				self.resampler.process(&self.input, &mut self.output);
+			}
+		}
+
+		let len = self.output.len().min(max);
+		frames.extend(self.output.drain(.. len));
+		Ok(())
+	}
+
+
+	fn seek(&mut self, frame: u64) -> AltoResult<()> {
+		let (from, to) = (self.resampler.from as u64, self.resampler.to as u64);
+		self.decoder.seek(frame * from / to)?;
+		self.resampler.reset();
+		self.output.clear();
+		self.finished = false;
+		Ok(())
+	}
+}
+
+
+fn sinc(x: f64) -> f64 {
+	if x.abs() < 1e-9 { 1.0 } else { (PI * x).sin() / (PI * x) }
+}
+
+
+/// Kaiser window at `x` in `[-1, 1]`.
+fn kaiser(x: f64, beta: f64) -> f64 {
+	if x.abs() >= 1.0 {
+		0.0
+	} else {
+		bessel_i0(beta * (1.0 - x * x).sqrt()) / bessel_i0(beta)
+	}
+}
+
+
+/// Zeroth order modified bessel function of the first kind.
+fn bessel_i0(x: f64) -> f64 {
+	let mut sum = 1.0;
+	let mut term = 1.0;
+	let mut k = 1.0;
+	while term > sum * 1e-12 {
+		term *= (x / (2.0 * k)) * (x / (2.0 * k));
+		sum += term;
+		k += 1.0;
+	}
+	sum
+}
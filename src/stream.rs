@@ -0,0 +1,310 @@
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use parking_lot::{Mutex, MutexGuard};
+
+use ::{AltoError, AltoResult};
+use sys;
+use al::*;
+
+
+/// A source of sample frames that can be streamed by a `StreamPlayer`.
+pub trait Decoder: Send + 'static {
+	/// The frame type produced by this decoder.
+	type Frame: SampleFrame + Send;
+
+
+	/// Sample rate of the decoded frames in hertz.
+	fn frequency(&self) -> sys::ALint;
+	/// Append at most `max` decoded frames to `frames`.
+	/// Appending nothing signals the end of the stream.
+	fn decode(&mut self, frames: &mut Vec<Self::Frame>, max: usize) -> AltoResult<()>;
+	/// Reposition the decoder so that the next decoded frame is at offset `frame` from the start of the stream.
+	fn seek(&mut self, frame: u64) -> AltoResult<()>;
+}
+
+
+/// Notifications sent by the background thread of a `StreamPlayer`.
+#[derive(Debug)]
+pub enum StreamEvent {
+	/// The decoder reached the end of the stream and all queued audio has finished playing.
+	/// Playing again will restart the stream from the beginning.
+	EndOfStream,
+	/// The source ran out of queued audio before the decoder could refill it, and was restarted.
+	Underrun,
+	/// The decoder or the implementation reported an error. The player is stopped.
+	Error(AltoError),
+}
+
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum PlayerState {
+	Stopped,
+	Playing,
+	Paused,
+}
+
+
+enum Command {
+	Play,
+	Pause,
+	Stop,
+	Seek(u64),
+	Shutdown,
+}
+
+
+/// Streams audio from a `Decoder` through a `StreamingSource` on a background thread.
+/// Unqueued buffers are refilled and requeued, and the source is restarted if it runs dry.
+/// Dropping the player stops playback and joins the thread.
+pub struct StreamPlayer {
+	src: Arc<Mutex<StreamingSource>>,
+	cmds: mpsc::Sender<Command>,
+	events: mpsc::Receiver<StreamEvent>,
+	thread: Option<thread::JoinHandle<()>>,
+}
+
+
+struct Streamer<D: Decoder> {
+	src: Arc<Mutex<StreamingSource>>,
+	decoder: D,
+	events: mpsc::Sender<StreamEvent>,
+	state: PlayerState,
+	eos: bool,
+	scratch: Vec<D::Frame>,
+	free: Vec<Buffer>,
+	buffer_frames: usize,
+	buffer_count: usize,
+	allocated: usize,
+	/// Number of buffers at the end of the queue that were queued while the source was not playing.
+	unplayed: usize,
+}
+
+
+impl StreamPlayer {
+	/// Take ownership of a streaming source and begin servicing it from a new thread.
+	/// Up to `buffer_count` buffers of `buffer_frames` sample frames each will be kept queued.
+	/// The player starts out stopped.
+	pub fn new<D: Decoder>(src: StreamingSource, decoder: D, buffer_frames: usize, buffer_count: usize) -> AltoResult<StreamPlayer> {
+		if buffer_frames == 0 || buffer_count == 0 || decoder.frequency() <= 0 {
+			return Err(AltoError::InvalidValue);
+		}
+
+		let (cmd_tx, cmd_rx) = mpsc::channel();
+		let (event_tx, event_rx) = mpsc::channel();
+		let src = Arc::new(Mutex::new(src));
+
+		let mut streamer = Streamer{
+			src: src.clone(),
+			decoder: decoder,
+			events: event_tx,
+			state: PlayerState::Stopped,
+			eos: false,
+			scratch: Vec::with_capacity(buffer_frames),
+			free: Vec::with_capacity(buffer_count),
+			buffer_frames: buffer_frames,
+			buffer_count: buffer_count,
+			allocated: 0,
+			unplayed: 0,
+		};
+		let thread = thread::Builder::new()
+			.name("alto-stream".into())
+			.spawn(move || streamer.run(cmd_rx))?;
+
+		Ok(StreamPlayer{
+			src: src,
+			cmds: cmd_tx,
+			events: event_rx,
+			thread: Some(thread),
+		})
+	}
+
+
+	/// Begin or resume playback.
+	pub fn play(&self) { let _ = self.cmds.send(Command::Play); }
+	/// Pause playback, keeping the queued audio.
+	pub fn pause(&self) { let _ = self.cmds.send(Command::Pause); }
+	/// Stop playback and rewind the decoder to the start of the stream.
+	pub fn stop(&self) { let _ = self.cmds.send(Command::Stop); }
+	/// Discard queued audio and continue from sample frame `frame`.
+	pub fn seek(&self, frame: u64) { let _ = self.cmds.send(Command::Seek(frame)); }
+
+
+	/// Receiver of notifications from the background thread.
+	pub fn events(&self) -> &mpsc::Receiver<StreamEvent> { &self.events }
+
+
+	/// Lock the source to inspect it or adjust properties such as gain and position.
+	/// The background thread blocks while the lock is held.
+	/// Playback state and the buffer queue belong to the player, and should only be changed through it.
+	pub fn source<'p>(&'p self) -> MutexGuard<'p, StreamingSource> { self.src.lock() }
+}
+
+
+impl Drop for StreamPlayer {
+	fn drop(&mut self) {
+		let _ = self.cmds.send(Command::Shutdown);
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+
+impl<D: Decoder> Streamer<D> {
+	fn run(&mut self, cmds: mpsc::Receiver<Command>) {
+		// Wake up often enough to refill a buffer well before the queue drains.
+		let period = Duration::from_millis(((self.buffer_frames as u64 * 1000) / (self.decoder.frequency() as u64 * 4)).max(1));
+
+		loop {
+			let cmd = if self.state == PlayerState::Playing {
+				match cmds.recv_timeout(period) {
+					Ok(cmd) => Some(cmd),
+					Err(mpsc::RecvTimeoutError::Timeout) => None,
+					Err(mpsc::RecvTimeoutError::Disconnected) => Some(Command::Shutdown),
+				}
+			} else {
+				Some(cmds.recv().unwrap_or(Command::Shutdown))
+			};
+
+			let res = match cmd {
+				Some(Command::Play) => self.play(),
+				Some(Command::Pause) => {
+					if self.state == PlayerState::Playing {
+						self.src.lock().pause();
+						self.state = PlayerState::Paused;
+					}
+					Ok(())
+				},
+				Some(Command::Stop) => self.stop(),
+				Some(Command::Seek(frame)) => self.seek(frame),
+				Some(Command::Shutdown) => {
+					self.src.lock().stop();
+					return;
+				},
+				None => self.service(),
+			};
+
+			if let Err(e) = res {
+				let _ = self.stop();
+				let _ = self.events.send(StreamEvent::Error(e));
+			}
+		}
+	}
+
+
+	fn play(&mut self) -> AltoResult<()> {
+		match self.state {
+			PlayerState::Playing => return Ok(()),
+			PlayerState::Paused => (),
+			PlayerState::Stopped => self.fill()?,
+		}
+
+		self.src.lock().play();
+		self.unplayed = 0;
+		self.state = PlayerState::Playing;
+		Ok(())
+	}
+
+
+	fn stop(&mut self) -> AltoResult<()> {
+		self.state = PlayerState::Stopped;
+		self.reset()?;
+		self.decoder.seek(0)
+	}
+
+
+	fn seek(&mut self, frame: u64) -> AltoResult<()> {
+		let state = self.state;
+		self.reset()?;
+		self.decoder.seek(frame)?;
+
+		self.state = PlayerState::Stopped;
+		match state {
+			PlayerState::Playing => self.play(),
+			PlayerState::Paused => {
+				self.fill()?;
+				self.state = PlayerState::Paused;
+				Ok(())
+			},
+			PlayerState::Stopped => Ok(()),
+		}
+	}
+
+
+	/// Stop the source and reclaim all of its buffers.
+	fn reset(&mut self) -> AltoResult<()> {
+		let mut src = self.src.lock();
+		src.stop();
+		while src.buffers_queued() > 0 {
+			self.free.push(src.unqueue_buffer()?);
+		}
+		self.unplayed = 0;
+		self.eos = false;
+		Ok(())
+	}
+
+
+	/// Reclaim processed buffers, refill them, and restart the source if it ran dry.
+	fn service(&mut self) -> AltoResult<()> {
+		{
+			let mut src = self.src.lock();
+			for _ in 0 .. src.buffers_processed() {
+				self.free.push(src.unqueue_buffer()?);
+			}
+		}
+		self.fill()?;
+
+		let mut src = self.src.lock();
+		if src.state() != SourceState::Playing {
+			// The source may have run dry while decoding, after which all of its buffers count as processed.
+			// Those queued before it stopped have been heard, and would otherwise be played again from the start of the queue.
+			for _ in 0 .. src.buffers_queued() as usize - self.unplayed {
+				self.free.push(src.unqueue_buffer()?);
+			}
+			if src.buffers_queued() > 0 {
+				src.play();
+				self.unplayed = 0;
+				let _ = self.events.send(StreamEvent::Underrun);
+			} else if self.eos {
+				self.state = PlayerState::Stopped;
+				self.decoder.seek(0)?;
+				self.eos = false;
+				let _ = self.events.send(StreamEvent::EndOfStream);
+			}
+		}
+
+		Ok(())
+	}
+
+
+	/// Decode into free buffers, allocating new ones up to the buffer count, and queue them.
+	fn fill(&mut self) -> AltoResult<()> {
+		while !self.eos && (!self.free.is_empty() || self.allocated < self.buffer_count) {
+			self.scratch.clear();
+			self.decoder.decode(&mut self.scratch, self.buffer_frames)?;
+			if self.scratch.is_empty() {
+				self.eos = true;
+				break;
+			}
+			self.scratch.truncate(self.buffer_frames);
+
+			let freq = self.decoder.frequency();
+			let buf = if let Some(mut buf) = self.free.pop() {
+				buf.set_data(&self.scratch[..], freq)?;
+				buf
+			} else {
+				let buf = self.src.lock().context().new_buffer(&self.scratch[..], freq)?;
+				self.allocated += 1;
+				buf
+			};
+			let mut src = self.src.lock();
+			if src.state() != SourceState::Playing {
+				self.unplayed += 1;
+			}
+			src.queue_buffer(buf)?;
+		}
+
+		Ok(())
+	}
+}
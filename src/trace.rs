@@ -0,0 +1,987 @@
+//! Recording of the calls made through an `AlApi`, and replay of those recordings against a loopback device.
+//!
+//! Tracing is opt-in. A `Tracer` is attached by wrapping the entry points before constructing the `Alto` instance:
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use alto::{Alto, sys};
+//! # use alto::trace::Tracer;
+//! let tracer = Arc::new(Tracer::new());
+//! let alto = Alto::from_api(sys::AlApi::traced(sys::AlApi::load_default().unwrap(), tracer.clone())).unwrap();
+//! // ...
+//! let log = tracer.log();
+//! ```
+//!
+//! Every AL and ALC call alto makes is recorded, including EFX and the other extension functions it
+//! obtains through `alcGetProcAddress` and `alGetProcAddress`. Callers that look up and invoke
+//! extension functions themselves bypass the tracer.
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::io::{self, BufRead, Write};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::slice;
+use std::time::{Duration, Instant};
+use parking_lot::Mutex;
+
+use ::{AltoError, AltoResult};
+use sys;
+use alc::*;
+use ext;
+
+
+/// A value passed to or returned from a traced call.
+/// Pointer arguments are recorded by the data they point to.
+#[derive(Clone, PartialEq, Debug)]
+pub enum TraceValue {
+	/// No value, as returned by functions without a result.
+	Void,
+	/// A null pointer.
+	Null,
+	/// Any integral, boolean, or enum value.
+	Int(i64),
+	/// Any floating point value.
+	Float(f64),
+	/// An opaque device or context handle, or other untraceable pointer.
+	Handle(u64),
+	/// A null-terminated string.
+	Str(Vec<u8>),
+	/// The contents of an integral array, such as object names or attribute lists.
+	Ints(Vec<i64>),
+	/// The contents of a floating point array.
+	Floats(Vec<f64>),
+	/// Raw sample data.
+	Bytes(Vec<u8>),
+}
+
+
+/// A single recorded call.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TraceEntry {
+	/// Time at which the call returned, relative to the creation of the tracer.
+	pub time: Duration,
+	/// Name of the entry point, e.g. `alSourcePlay`.
+	pub name: String,
+	pub args: Vec<TraceValue>,
+	pub ret: TraceValue,
+}
+
+
+/// An ordered list of recorded calls.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TraceLog {
+	pub entries: Vec<TraceEntry>,
+}
+
+
+/// Records every call made through an `AlApi` created with `AlApi::traced`.
+pub struct Tracer {
+	start: Instant,
+	entries: Mutex<Vec<TraceEntry>>,
+}
+
+
+/// Re-issues recorded calls against a loopback device, rendering output in step with the recorded timestamps.
+/// All recorded devices are mapped onto the same loopback device, and the names of buffers, sources,
+/// effects, filters, and effect slots are translated to their counterparts in the replay.
+/// Contexts are created with their recorded attributes, except that the loopback format and frequency of the replay take precedence.
+/// Contexts made current on a thread are made current globally.
+/// Queries, capture calls, callbacks, and event listeners are skipped.
+pub struct Replayer<F: LoopbackFrame> {
+	dev: LoopbackDevice<F>,
+	freq: sys::ALCint,
+	attrs: Vec<sys::ALCint>,
+	rendered: u64,
+	contexts: HashMap<u64, ReplayContext>,
+	current: Option<u64>,
+	sources: HashMap<(u64, i64), sys::ALuint>,
+	buffers: HashMap<(u64, i64), sys::ALuint>,
+	slots: HashMap<(u64, i64), sys::ALuint>,
+	effects: HashMap<(u64, i64), sys::ALuint>,
+	filters: HashMap<(u64, i64), sys::ALuint>,
+}
+
+
+/// A context created by the replay, standing in for a recorded one.
+struct ReplayContext {
+	raw: *mut sys::ALCcontext,
+	/// Recorded handle of the device the context was created on.
+	dev: u64,
+	exts: ext::AlCache,
+}
+
+
+impl Tracer {
+	pub fn new() -> Tracer {
+		Tracer{
+			start: Instant::now(),
+			entries: Mutex::new(Vec::new()),
+		}
+	}
+
+
+	/// A copy of all calls recorded so far.
+	pub fn log(&self) -> TraceLog {
+		TraceLog{entries: self.entries.lock().clone()}
+	}
+
+
+	/// Discard all calls recorded so far.
+	pub fn clear(&self) {
+		self.entries.lock().clear();
+	}
+}
+
+
+impl Default for Tracer {
+	fn default() -> Tracer { Tracer::new() }
+}
+
+
+impl sys::AlTrace for Tracer {
+	unsafe fn trace(&self, call: &sys::AlCall) {
+		let time = self.start.elapsed();
+		let (args, ret) = capture(call);
+		self.entries.lock().push(TraceEntry{
+			time: time,
+			name: call.name().to_owned(),
+			args: args,
+			ret: ret,
+		});
+	}
+}
+
+
+impl TraceLog {
+	/// Serialize the log as text, one call per line.
+	pub fn write<W: Write>(&self, mut w: W) -> AltoResult<()> {
+		for entry in &self.entries {
+			write!(w, "{}.{:09} {}", entry.time.as_secs(), entry.time.subsec_nanos(), entry.name)?;
+			for arg in &entry.args {
+				write!(w, " ")?;
+				write_value(&mut w, arg)?;
+			}
+			write!(w, " -> ")?;
+			write_value(&mut w, &entry.ret)?;
+			writeln!(w)?;
+		}
+
+		Ok(())
+	}
+
+
+	/// Parse a log in the format produced by `write`.
+	pub fn read<R: BufRead>(r: R) -> AltoResult<TraceLog> {
+		let mut entries = Vec::new();
+
+		for line in r.lines() {
+			let line = line?;
+			if line.trim().is_empty() {
+				continue;
+			}
+
+			let mut tokens = line.split(' ');
+			let time = tokens.next().ok_or_else(|| invalid_data("missing timestamp"))?;
+			let mut time = time.splitn(2, '.');
+			let secs = time.next().and_then(|s| s.parse().ok()).ok_or_else(|| invalid_data("invalid timestamp"))?;
+			let nanos = time.next().and_then(|s| s.parse().ok()).ok_or_else(|| invalid_data("invalid timestamp"))?;
+			let name = tokens.next().ok_or_else(|| invalid_data("missing call name"))?;
+
+			let mut args = Vec::new();
+			let mut ret = None;
+			while let Some(token) = tokens.next() {
+				if token == "->" {
+					ret = Some(read_value(tokens.next().ok_or_else(|| invalid_data("missing return value"))?)?);
+					break;
+				}
+				args.push(read_value(token)?);
+			}
+
+			entries.push(TraceEntry{
+				time: Duration::new(secs, nanos),
+				name: name.to_owned(),
+				args: args,
+				ret: ret.ok_or_else(|| invalid_data("missing return value"))?,
+			});
+		}
+
+		Ok(TraceLog{entries: entries})
+	}
+}
+
+
+impl<F: LoopbackFrame> Replayer<F> {
+	/// Open a new loopback device rendering at `freq` hertz.
+	/// Requires `ALC_SOFT_loopback`
+	pub fn new(alto: &Alto, freq: sys::ALCint) -> AltoResult<Replayer<F>> {
+		let dev = alto.open_loopback::<F>(None)?;
+		let attrs = dev.make_attrs_vec(freq, None)?;
+
+		Ok(Replayer{
+			dev: dev,
+			freq: freq,
+			attrs: attrs,
+			rendered: 0,
+			contexts: HashMap::new(),
+			current: None,
+			sources: HashMap::new(),
+			buffers: HashMap::new(),
+			slots: HashMap::new(),
+			effects: HashMap::new(),
+			filters: HashMap::new(),
+		})
+	}
+
+
+	/// The loopback device the log is replayed against.
+	pub fn device(&self) -> &LoopbackDevice<F> { &self.dev }
+
+
+	/// Issue every call in the log, first rendering output up to the time at which it was recorded.
+	/// Returns the frames rendered.
+	pub fn replay(&mut self, log: &TraceLog) -> AltoResult<Vec<F>> {
+		let mut out = Vec::new();
+		for entry in &log.entries {
+			let nanos = entry.time.as_secs() * 1_000_000_000 + entry.time.subsec_nanos() as u64;
+			let target = nanos * self.freq as u64 / 1_000_000_000;
+			if target > self.rendered {
+				let len = (target - self.rendered) as usize;
+				out.extend(self.render(len));
+			}
+
+			unsafe { self.issue(entry)?; }
+		}
+
+		Ok(out)
+	}
+
+
+	/// Render `len` further frames from the current state of the replay.
+	pub fn render(&mut self, len: usize) -> Vec<F> {
+		let mut out = vec![unsafe { mem::zeroed() }; len];
+		let mut done = 0;
+		while done < len {
+			done += self.dev.soft_render_samples(&mut out[done ..]);
+		}
+		self.rendered += len as u64;
+		out
+	}
+
+
+	unsafe fn issue(&mut self, entry: &TraceEntry) -> AltoResult<()> {
+		let dev = self.dev.0.clone();
+		let api = &dev.alto.0.api;
+		let efx = || dev.exts.ALC_EXT_EFX();
+		let args = &entry.args;
+
+		match &entry.name[..] {
+			"alcCreateContext" => {
+				let dev = handle_arg(args, 0)?;
+				let ctx = match entry.ret {
+					TraceValue::Handle(ctx) => ctx,
+					_ => return Ok(()),
+				};
+				let attrs = self.merge_attrs(args, 1)?;
+				let raw = api.alcCreateContext(self.dev.0.dev, attrs.as_ptr());
+				if raw == ptr::null_mut() {
+					return Err(AltoError::NullError);
+				}
+
+				// Extensions are queried on the new context, then the current one is restored.
+				api.alcMakeContextCurrent(raw);
+				let exts = ext::AlCache::new(api);
+				api.alcMakeContextCurrent(self.current.map(|c| self.contexts[&c].raw).unwrap_or(ptr::null_mut()));
+
+				self.contexts.insert(ctx, ReplayContext{raw: raw, dev: dev, exts: exts});
+			},
+			"alcResetDeviceSOFT" => {
+				if let Ok(ards) = self.dev.0.exts.ALC_SOFT_HRTF().and_then(|ash| ash.alcResetDeviceSOFT) {
+					let attrs = self.merge_attrs(args, 1)?;
+					api.alcResetDeviceSOFT(ards, self.dev.0.dev, attrs.as_ptr());
+				}
+			},
+			"alcMakeContextCurrent" | "alcSetThreadContext" => {
+				let ctx = handle_arg(args, 0)?;
+				if ctx == 0 {
+					api.alcMakeContextCurrent(ptr::null_mut());
+					self.current = None;
+				} else if let Some(replay) = self.contexts.get(&ctx) {
+					api.alcMakeContextCurrent(replay.raw);
+					self.current = Some(ctx);
+				}
+			},
+			"alcProcessContext" | "alcSuspendContext" | "alcDestroyContext" => {
+				let ctx = handle_arg(args, 0)?;
+				let raw = match self.contexts.get(&ctx) {
+					Some(replay) => replay.raw,
+					None => return Ok(()),
+				};
+				match &entry.name[..] {
+					"alcProcessContext" => api.alcProcessContext(raw),
+					"alcSuspendContext" => api.alcSuspendContext(raw),
+					_ => {
+						api.alcDestroyContext(raw);
+						self.contexts.remove(&ctx);
+						self.sources.retain(|&(c, _), _| c != ctx);
+						self.slots.retain(|&(c, _), _| c != ctx);
+						if self.current == Some(ctx) {
+							self.current = None;
+						}
+					},
+				}
+			},
+
+			"alDopplerFactor" => api.alDopplerFactor(float_arg(args, 0)? as sys::ALfloat),
+			"alDopplerVelocity" => api.alDopplerVelocity(float_arg(args, 0)? as sys::ALfloat),
+			"alSpeedOfSound" => api.alSpeedOfSound(float_arg(args, 0)? as sys::ALfloat),
+			"alDistanceModel" => api.alDistanceModel(int_arg(args, 0)? as sys::ALenum),
+			"alEnable" => api.alEnable(int_arg(args, 0)? as sys::ALenum),
+			"alDisable" => api.alDisable(int_arg(args, 0)? as sys::ALenum),
+
+			"alListenerf" => api.alListenerf(int_arg(args, 0)? as sys::ALenum, float_arg(args, 1)? as sys::ALfloat),
+			"alListener3f" => api.alListener3f(int_arg(args, 0)? as sys::ALenum, float_arg(args, 1)? as sys::ALfloat, float_arg(args, 2)? as sys::ALfloat, float_arg(args, 3)? as sys::ALfloat),
+			"alListenerfv" => {
+				let values = floats_arg(args, 1)?;
+				api.alListenerfv(int_arg(args, 0)? as sys::ALenum, values.as_ptr());
+			},
+			"alListeneri" => api.alListeneri(int_arg(args, 0)? as sys::ALenum, int_arg(args, 1)? as sys::ALint),
+			"alListener3i" => api.alListener3i(int_arg(args, 0)? as sys::ALenum, int_arg(args, 1)? as sys::ALint, int_arg(args, 2)? as sys::ALint, int_arg(args, 3)? as sys::ALint),
+			"alListeneriv" => {
+				let values = alints_arg(args, 1)?;
+				api.alListeneriv(int_arg(args, 0)? as sys::ALenum, values.as_ptr());
+			},
+
+			"alGenSources" | "alGenBuffers" | "alGenAuxiliaryEffectSlots" | "alGenEffects" | "alGenFilters" => {
+				let ids = ints_arg(args, 1)?;
+				let mut new = vec![0; ids.len()];
+				let n = new.len() as sys::ALsizei;
+				match &entry.name[..] {
+					"alGenSources" => api.alGenSources(n, new.as_mut_ptr()),
+					"alGenBuffers" => api.alGenBuffers(n, new.as_mut_ptr()),
+					"alGenAuxiliaryEffectSlots" => api.alGenAuxiliaryEffectSlots(efx()?.alGenAuxiliaryEffectSlots?, n, new.as_mut_ptr()),
+					"alGenEffects" => api.alGenEffects(efx()?.alGenEffects?, n, new.as_mut_ptr()),
+					_ => api.alGenFilters(efx()?.alGenFilters?, n, new.as_mut_ptr()),
+				}
+				for (&id, &new) in ids.iter().zip(new.iter()) {
+					let key = (self.object_owner(&entry.name)?, id);
+					self.objects(&entry.name).insert(key, new);
+				}
+			},
+			"alDeleteSources" | "alDeleteBuffers" | "alDeleteAuxiliaryEffectSlots" | "alDeleteEffects" | "alDeleteFilters" => {
+				let owner = self.object_owner(&entry.name)?;
+				let mut ids = self.map_ids(&entry.name, args, 1)?;
+				for id in ints_arg(args, 1)? {
+					self.objects(&entry.name).remove(&(owner, id));
+				}
+				let n = ids.len() as sys::ALsizei;
+				match &entry.name[..] {
+					"alDeleteSources" => api.alDeleteSources(n, ids.as_ptr()),
+					"alDeleteBuffers" => api.alDeleteBuffers(n, ids.as_ptr()),
+					"alDeleteAuxiliaryEffectSlots" => api.alDeleteAuxiliaryEffectSlots(efx()?.alDeleteAuxiliaryEffectSlots?, n, ids.as_mut_ptr()),
+					"alDeleteEffects" => api.alDeleteEffects(efx()?.alDeleteEffects?, n, ids.as_mut_ptr()),
+					_ => api.alDeleteFilters(efx()?.alDeleteFilters?, n, ids.as_mut_ptr()),
+				}
+			},
+
+			"alSourcef" => api.alSourcef(self.source(args)?, int_arg(args, 1)? as sys::ALenum, float_arg(args, 2)? as sys::ALfloat),
+			"alSource3f" => api.alSource3f(self.source(args)?, int_arg(args, 1)? as sys::ALenum, float_arg(args, 2)? as sys::ALfloat, float_arg(args, 3)? as sys::ALfloat, float_arg(args, 4)? as sys::ALfloat),
+			"alSourcefv" => {
+				let values = floats_arg(args, 2)?;
+				api.alSourcefv(self.source(args)?, int_arg(args, 1)? as sys::ALenum, values.as_ptr());
+			},
+			"alSourcei" | "alSource3i" | "alSourceiv" => {
+				let param = int_arg(args, 1)? as sys::ALenum;
+				let mut values = match &entry.name[..] {
+					"alSourcei" => vec![int_arg(args, 2)?],
+					"alSource3i" => vec![int_arg(args, 2)?, int_arg(args, 3)?, int_arg(args, 4)?],
+					_ => ints_arg(args, 2)?,
+				};
+				self.map_values(&entry.name, param, &mut values)?;
+				let values: Vec<_> = values.into_iter().map(|v| v as sys::ALint).collect();
+				match &entry.name[..] {
+					"alSourcei" => api.alSourcei(self.source(args)?, param, values[0]),
+					"alSource3i" => api.alSource3i(self.source(args)?, param, values[0], values[1], values[2]),
+					_ => api.alSourceiv(self.source(args)?, param, values.as_ptr()),
+				}
+			},
+			"alSourcedSOFT" => api.alSourcedSOFT(self.al_exts()?.AL_SOFT_source_latency()?.alSourcedSOFT?, self.source(args)?, int_arg(args, 1)? as sys::ALenum, float_arg(args, 2)?),
+			"alSource3dSOFT" => api.alSource3dSOFT(self.al_exts()?.AL_SOFT_source_latency()?.alSource3dSOFT?, self.source(args)?, int_arg(args, 1)? as sys::ALenum, float_arg(args, 2)?, float_arg(args, 3)?, float_arg(args, 4)?),
+			"alSourcedvSOFT" => {
+				let asdv = self.al_exts()?.AL_SOFT_source_latency()?.alSourcedvSOFT?;
+				let values = match args.get(2) {
+					Some(&TraceValue::Floats(ref v)) => v.clone(),
+					_ => return Err(invalid_data("invalid argument")),
+				};
+				api.alSourcedvSOFT(asdv, self.source(args)?, int_arg(args, 1)? as sys::ALenum, values.as_ptr());
+			},
+			"alSourcei64SOFT" => api.alSourcei64SOFT(self.al_exts()?.AL_SOFT_source_latency()?.alSourcei64SOFT?, self.source(args)?, int_arg(args, 1)? as sys::ALenum, int_arg(args, 2)?),
+			"alSource3i64SOFT" => api.alSource3i64SOFT(self.al_exts()?.AL_SOFT_source_latency()?.alSource3i64SOFT?, self.source(args)?, int_arg(args, 1)? as sys::ALenum, int_arg(args, 2)?, int_arg(args, 3)?, int_arg(args, 4)?),
+			"alSourcei64vSOFT" => {
+				let asi64v = self.al_exts()?.AL_SOFT_source_latency()?.alSourcei64vSOFT?;
+				let values = ints_arg(args, 2)?;
+				api.alSourcei64vSOFT(asi64v, self.source(args)?, int_arg(args, 1)? as sys::ALenum, values.as_ptr());
+			},
+			"alSourcePlayv" | "alSourceStopv" | "alSourceRewindv" | "alSourcePausev" => {
+				let ids = self.map_ids(&entry.name, args, 1)?;
+				let n = ids.len() as sys::ALsizei;
+				match &entry.name[..] {
+					"alSourcePlayv" => api.alSourcePlayv(n, ids.as_ptr()),
+					"alSourceStopv" => api.alSourceStopv(n, ids.as_ptr()),
+					"alSourceRewindv" => api.alSourceRewindv(n, ids.as_ptr()),
+					_ => api.alSourcePausev(n, ids.as_ptr()),
+				}
+			},
+			"alSourcePlayAtTimevSOFT" => {
+				let apatv = self.al_exts()?.AL_SOFT_source_start_delay()?.alSourcePlayAtTimevSOFT?;
+				let ids = self.map_ids(&entry.name, args, 1)?;
+				api.alSourcePlayAtTimevSOFT(apatv, ids.len() as sys::ALsizei, ids.as_ptr(), int_arg(args, 2)?);
+			},
+			"alSourcePlayAtTimeSOFT" => api.alSourcePlayAtTimeSOFT(self.al_exts()?.AL_SOFT_source_start_delay()?.alSourcePlayAtTimeSOFT?, self.source(args)?, int_arg(args, 1)?),
+			"alSourcePlay" => api.alSourcePlay(self.source(args)?),
+			"alSourceStop" => api.alSourceStop(self.source(args)?),
+			"alSourceRewind" => api.alSourceRewind(self.source(args)?),
+			"alSourcePause" => api.alSourcePause(self.source(args)?),
+			"alSourceQueueBuffers" => {
+				let ids = self.map_ids("alGenBuffers", args, 2)?;
+				api.alSourceQueueBuffers(self.source(args)?, ids.len() as sys::ALsizei, ids.as_ptr());
+			},
+			"alSourceUnqueueBuffers" => {
+				let mut ids = vec![0; ints_arg(args, 2)?.len()];
+				api.alSourceUnqueueBuffers(self.source(args)?, ids.len() as sys::ALsizei, ids.as_mut_ptr());
+			},
+
+			"alBufferData" => {
+				let data = match args.get(2) {
+					Some(&TraceValue::Bytes(ref data)) => data,
+					_ => return Err(invalid_data("invalid argument")),
+				};
+				api.alBufferData(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, data.as_ptr() as *const sys::ALvoid, data.len() as sys::ALsizei, int_arg(args, 4)? as sys::ALsizei);
+			},
+			"alBufferSubDataSOFT" => {
+				let absd = self.al_exts()?.AL_SOFT_buffer_sub_data()?.alBufferSubDataSOFT?;
+				let data = match args.get(2) {
+					Some(&TraceValue::Bytes(ref data)) => data,
+					_ => return Err(invalid_data("invalid argument")),
+				};
+				api.alBufferSubDataSOFT(absd, self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, data.as_ptr() as *const sys::ALvoid, int_arg(args, 3)? as sys::ALsizei, data.len() as sys::ALsizei);
+			},
+			"alBufferf" => api.alBufferf(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, float_arg(args, 2)? as sys::ALfloat),
+			"alBuffer3f" => api.alBuffer3f(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, float_arg(args, 2)? as sys::ALfloat, float_arg(args, 3)? as sys::ALfloat, float_arg(args, 4)? as sys::ALfloat),
+			"alBufferfv" => {
+				let values = floats_arg(args, 2)?;
+				api.alBufferfv(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, values.as_ptr());
+			},
+			"alBufferi" => api.alBufferi(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, int_arg(args, 2)? as sys::ALint),
+			"alBuffer3i" => api.alBuffer3i(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, int_arg(args, 2)? as sys::ALint, int_arg(args, 3)? as sys::ALint, int_arg(args, 4)? as sys::ALint),
+			"alBufferiv" => {
+				let values = alints_arg(args, 2)?;
+				api.alBufferiv(self.buffer(int_arg(args, 0)?)?, int_arg(args, 1)? as sys::ALenum, values.as_ptr());
+			},
+
+			"alAuxiliaryEffectSloti" | "alEffecti" | "alFilteri" | "alAuxiliaryEffectSlotiv" | "alEffectiv" | "alFilteriv" => {
+				let object = self.object(&entry.name, int_arg(args, 0)?)?;
+				let param = int_arg(args, 1)? as sys::ALenum;
+				let mut values = if entry.name.ends_with('v') { ints_arg(args, 2)? } else { vec![int_arg(args, 2)?] };
+				self.map_values(&entry.name, param, &mut values)?;
+				let mut values: Vec<_> = values.into_iter().map(|v| v as sys::ALint).collect();
+				let efx = efx()?;
+				match &entry.name[..] {
+					"alAuxiliaryEffectSloti" => api.alAuxiliaryEffectSloti(efx.alAuxiliaryEffectSloti?, object, param, values[0]),
+					"alEffecti" => api.alEffecti(efx.alEffecti?, object, param, values[0]),
+					"alFilteri" => api.alFilteri(efx.alFilteri?, object, param, values[0]),
+					"alAuxiliaryEffectSlotiv" => api.alAuxiliaryEffectSlotiv(efx.alAuxiliaryEffectSlotiv?, object, param, values.as_mut_ptr()),
+					"alEffectiv" => api.alEffectiv(efx.alEffectiv?, object, param, values.as_mut_ptr()),
+					_ => api.alFilteriv(efx.alFilteriv?, object, param, values.as_mut_ptr()),
+				}
+			},
+			"alAuxiliaryEffectSlotf" | "alEffectf" | "alFilterf" | "alAuxiliaryEffectSlotfv" | "alEffectfv" | "alFilterfv" => {
+				let object = self.object(&entry.name, int_arg(args, 0)?)?;
+				let param = int_arg(args, 1)? as sys::ALenum;
+				let mut values = if entry.name.ends_with('v') { floats_arg(args, 2)? } else { vec![float_arg(args, 2)? as sys::ALfloat] };
+				let efx = efx()?;
+				match &entry.name[..] {
+					"alAuxiliaryEffectSlotf" => api.alAuxiliaryEffectSlotf(efx.alAuxiliaryEffectSlotf?, object, param, values[0]),
+					"alEffectf" => api.alEffectf(efx.alEffectf?, object, param, values[0]),
+					"alFilterf" => api.alFilterf(efx.alFilterf?, object, param, values[0]),
+					"alAuxiliaryEffectSlotfv" => api.alAuxiliaryEffectSlotfv(efx.alAuxiliaryEffectSlotfv?, object, param, values.as_mut_ptr()),
+					"alEffectfv" => api.alEffectfv(efx.alEffectfv?, object, param, values.as_mut_ptr()),
+					_ => api.alFilterfv(efx.alFilterfv?, object, param, values.as_mut_ptr()),
+				}
+			},
+
+			"alDeferUpdatesSOFT" => api.alDeferUpdatesSOFT(self.al_exts()?.AL_SOFT_deferred_updates()?.alDeferUpdatesSOFT?),
+			"alProcessUpdatesSOFT" => api.alProcessUpdatesSOFT(self.al_exts()?.AL_SOFT_deferred_updates()?.alProcessUpdatesSOFT?),
+
+			_ => return Ok(()),
+		}
+
+		// Errors are part of the recording; don't let them leak into later calls.
+		if entry.name.starts_with("al") && !entry.name.starts_with("alc") && self.current.is_some() {
+			api.alGetError();
+		}
+
+		Ok(())
+	}
+
+
+	/// The recorded handle that owns objects of the kind created or deleted by `call`.
+	/// Sources and effect slots belong to a context; buffers, effects, and filters to a device.
+	fn object_owner(&self, call: &str) -> AltoResult<u64> {
+		let ctx = self.current.ok_or(AltoError::InvalidContext)?;
+		if call.contains("Source") || call.contains("AuxiliaryEffectSlot") {
+			Ok(ctx)
+		} else {
+			Ok(self.contexts[&ctx].dev)
+		}
+	}
+
+
+	fn objects(&mut self, call: &str) -> &mut HashMap<(u64, i64), sys::ALuint> {
+		if call.contains("Source") {
+			&mut self.sources
+		} else if call.contains("AuxiliaryEffectSlot") {
+			&mut self.slots
+		} else if call.contains("Effect") {
+			&mut self.effects
+		} else if call.contains("Filter") {
+			&mut self.filters
+		} else {
+			&mut self.buffers
+		}
+	}
+
+
+	/// The replayed name of an object of the kind named by `call`. The null name maps to itself.
+	fn object(&mut self, call: &str, id: i64) -> AltoResult<sys::ALuint> {
+		if id == 0 {
+			return Ok(0);
+		}
+		let owner = self.object_owner(call)?;
+		self.objects(call).get(&(owner, id)).cloned().ok_or(AltoError::InvalidName)
+	}
+
+
+	/// Translate the object names among the integer values that `call` sets for `param`.
+	fn map_values(&mut self, call: &str, param: sys::ALenum, values: &mut [i64]) -> AltoResult<()> {
+		if values.is_empty() {
+			return Ok(());
+		}
+
+		if call.starts_with("alSource") {
+			match param {
+				sys::AL_BUFFER => values[0] = self.object("Buffer", values[0])? as i64,
+				p if p == sys::AL_DIRECT_FILTER as sys::ALenum => values[0] = self.object("Filter", values[0])? as i64,
+				p if p == sys::AL_AUXILIARY_SEND_FILTER as sys::ALenum && values.len() >= 3 => {
+					values[0] = self.object("AuxiliaryEffectSlot", values[0])? as i64;
+					values[2] = self.object("Filter", values[2])? as i64;
+				},
+				_ => (),
+			}
+		} else if call.starts_with("alAuxiliaryEffectSlot") && param == sys::AL_EFFECTSLOT_EFFECT {
+			values[0] = self.object("Effect", values[0])? as i64;
+		}
+
+		Ok(())
+	}
+
+
+	/// Extensions of the current replay context.
+	fn al_exts(&self) -> AltoResult<&ext::AlCache> {
+		let ctx = self.current.ok_or(AltoError::InvalidContext)?;
+		Ok(&self.contexts[&ctx].exts)
+	}
+
+
+	/// The attributes recorded at `idx`, with the loopback attributes of the replay in place of any recorded format or frequency.
+	fn merge_attrs(&self, args: &[TraceValue], idx: usize) -> AltoResult<Vec<sys::ALCint>> {
+		let asl = self.dev.0.alto.0.exts.ALC_SOFT_loopback()?;
+		let replaced = [sys::ALC_FREQUENCY, asl.ALC_FORMAT_CHANNELS_SOFT?, asl.ALC_FORMAT_TYPE_SOFT?];
+
+		let mut attrs = self.attrs[.. self.attrs.len() - 1].to_vec();
+		match args.get(idx) {
+			Some(&TraceValue::Ints(ref recorded)) => {
+				for pair in recorded.chunks(2).take_while(|p| p.len() == 2 && p[0] != 0) {
+					if !replaced.contains(&(pair[0] as sys::ALCint)) {
+						attrs.extend(pair.iter().map(|&v| v as sys::ALCint));
+					}
+				}
+			},
+			Some(&TraceValue::Null) => (),
+			_ => return Err(invalid_data("invalid argument")),
+		}
+		attrs.push(0);
+		Ok(attrs)
+	}
+
+
+	fn map_ids(&mut self, call: &str, args: &[TraceValue], idx: usize) -> AltoResult<Vec<sys::ALuint>> {
+		let owner = self.object_owner(call)?;
+		let objects = self.objects(call);
+		ints_arg(args, idx)?.into_iter()
+			.map(|id| if id == 0 { Ok(0) } else { objects.get(&(owner, id)).cloned().ok_or(AltoError::InvalidName) })
+			.collect()
+	}
+
+
+	fn source(&mut self, args: &[TraceValue]) -> AltoResult<sys::ALuint> {
+		let owner = self.object_owner("Source")?;
+		let id = int_arg(args, 0)?;
+		self.sources.get(&(owner, id)).cloned().ok_or(AltoError::InvalidName)
+	}
+
+
+	fn buffer(&mut self, id: i64) -> AltoResult<sys::ALuint> {
+		let owner = self.object_owner("Buffer")?;
+		self.buffers.get(&(owner, id)).cloned().ok_or(AltoError::InvalidName)
+	}
+}
+
+
+impl<F: LoopbackFrame> Drop for Replayer<F> {
+	fn drop(&mut self) {
+		let api = &self.dev.0.alto.0.api;
+		unsafe {
+			api.alcMakeContextCurrent(ptr::null_mut());
+			for replay in self.contexts.values() {
+				api.alcDestroyContext(replay.raw);
+			}
+		}
+	}
+}
+
+
+fn invalid_data(msg: &'static str) -> AltoError {
+	AltoError::Io(io::Error::new(io::ErrorKind::InvalidData, msg))
+}
+
+
+fn int_arg(args: &[TraceValue], idx: usize) -> AltoResult<i64> {
+	match args.get(idx) {
+		Some(&TraceValue::Int(v)) => Ok(v),
+		_ => Err(invalid_data("invalid argument")),
+	}
+}
+
+
+fn float_arg(args: &[TraceValue], idx: usize) -> AltoResult<f64> {
+	match args.get(idx) {
+		Some(&TraceValue::Float(v)) => Ok(v),
+		_ => Err(invalid_data("invalid argument")),
+	}
+}
+
+
+fn handle_arg(args: &[TraceValue], idx: usize) -> AltoResult<u64> {
+	match args.get(idx) {
+		Some(&TraceValue::Handle(v)) => Ok(v),
+		Some(&TraceValue::Null) => Ok(0),
+		_ => Err(invalid_data("invalid argument")),
+	}
+}
+
+
+fn ints_arg(args: &[TraceValue], idx: usize) -> AltoResult<Vec<i64>> {
+	match args.get(idx) {
+		Some(&TraceValue::Ints(ref v)) => Ok(v.clone()),
+		_ => Err(invalid_data("invalid argument")),
+	}
+}
+
+
+fn alints_arg(args: &[TraceValue], idx: usize) -> AltoResult<Vec<sys::ALint>> {
+	Ok(ints_arg(args, idx)?.into_iter().map(|v| v as sys::ALint).collect())
+}
+
+
+fn floats_arg(args: &[TraceValue], idx: usize) -> AltoResult<Vec<sys::ALfloat>> {
+	match args.get(idx) {
+		Some(&TraceValue::Floats(ref v)) => Ok(v.iter().map(|&v| v as sys::ALfloat).collect()),
+		_ => Err(invalid_data("invalid argument")),
+	}
+}
+
+
+fn write_value<W: Write>(w: &mut W, value: &TraceValue) -> io::Result<()> {
+	match *value {
+		TraceValue::Void => write!(w, "-"),
+		TraceValue::Null => write!(w, "n"),
+		TraceValue::Int(v) => write!(w, "i:{}", v),
+		TraceValue::Float(v) => write!(w, "f:{}", v),
+		TraceValue::Handle(v) => write!(w, "h:{:x}", v),
+		TraceValue::Str(ref v) => { write!(w, "s:")?; write_hex(w, v) },
+		TraceValue::Bytes(ref v) => { write!(w, "b:")?; write_hex(w, v) },
+		TraceValue::Ints(ref v) => {
+			write!(w, "I:")?;
+			for (i, v) in v.iter().enumerate() {
+				write!(w, "{}{}", if i > 0 { "," } else { "" }, v)?;
+			}
+			Ok(())
+		},
+		TraceValue::Floats(ref v) => {
+			write!(w, "F:")?;
+			for (i, v) in v.iter().enumerate() {
+				write!(w, "{}{}", if i > 0 { "," } else { "" }, v)?;
+			}
+			Ok(())
+		},
+	}
+}
+
+
+fn write_hex<W: Write>(w: &mut W, bytes: &[u8]) -> io::Result<()> {
+	for b in bytes {
+		write!(w, "{:02x}", b)?;
+	}
+	Ok(())
+}
+
+
+fn read_value(token: &str) -> AltoResult<TraceValue> {
+	let bad = || invalid_data("invalid value");
+
+	match token {
+		"-" => return Ok(TraceValue::Void),
+		"n" => return Ok(TraceValue::Null),
+		_ => (),
+	}
+
+	let mut parts = token.splitn(2, ':');
+	let (tag, body) = match (parts.next(), parts.next()) {
+		(Some(tag), Some(body)) => (tag, body),
+		_ => return Err(bad()),
+	};
+
+	Ok(match tag {
+		"i" => TraceValue::Int(body.parse().map_err(|_| bad())?),
+		"f" => TraceValue::Float(body.parse().map_err(|_| bad())?),
+		"h" => TraceValue::Handle(u64::from_str_radix(body, 16).map_err(|_| bad())?),
+		"s" => TraceValue::Str(read_hex(body).ok_or_else(bad)?),
+		"b" => TraceValue::Bytes(read_hex(body).ok_or_else(bad)?),
+		"I" => TraceValue::Ints(body.split(',').filter(|s| !s.is_empty()).map(|s| s.parse()).collect::<Result<_, _>>().map_err(|_| bad())?),
+		"F" => TraceValue::Floats(body.split(',').filter(|s| !s.is_empty()).map(|s| s.parse()).collect::<Result<_, _>>().map_err(|_| bad())?),
+		_ => return Err(bad()),
+	})
+}
+
+
+fn read_hex(s: &str) -> Option<Vec<u8>> {
+	if s.len() % 2 != 0 {
+		return None;
+	}
+	(0 .. s.len() / 2).map(|i| s.get(i * 2 .. i * 2 + 2).and_then(|b| u8::from_str_radix(b, 16).ok())).collect()
+}
+
+
+/// Number of values read or written through the pointer of a vector property.
+fn arity(param: sys::ALenum) -> usize {
+	match param {
+		sys::AL_POSITION | sys::AL_VELOCITY | sys::AL_DIRECTION => 3,
+		sys::AL_ORIENTATION => 6,
+		0x2015 /* AL_LOOP_POINTS_SOFT */ | 0x1030 /* AL_STEREO_ANGLES */ => 2,
+		0x20006 /* AL_AUXILIARY_SEND_FILTER */ => 3,
+		0x1200 /* AL_SAMPLE_OFFSET_LATENCY_SOFT */ | 0x1201 /* AL_SEC_OFFSET_LATENCY_SOFT */ => 2,
+		0x1202 /* AL_SAMPLE_OFFSET_CLOCK_SOFT */ | 0x1203 /* AL_SEC_OFFSET_CLOCK_SOFT */ => 2,
+		_ => 1,
+	}
+}
+
+
+/// Number of values read or written through the pointer of a vector effect, filter, or slot property.
+/// These share a numbering space distinct from source and buffer properties.
+fn efx_arity(param: sys::ALenum) -> usize {
+	match param {
+		sys::AL_EAXREVERB_REFLECTIONS_PAN | sys::AL_EAXREVERB_LATE_REVERB_PAN => 3,
+		_ => 1,
+	}
+}
+
+
+fn count(n: sys::ALsizei) -> usize { if n > 0 { n as usize } else { 0 } }
+
+
+fn int<T: Into<i64>>(v: T) -> TraceValue { TraceValue::Int(v.into()) }
+fn float<T: Into<f64>>(v: T) -> TraceValue { TraceValue::Float(v.into()) }
+
+
+fn handle<T>(p: *const T) -> TraceValue {
+	if p == ptr::null() { TraceValue::Null } else { TraceValue::Handle(p as usize as u64) }
+}
+
+
+unsafe fn cstr(p: *const c_char) -> TraceValue {
+	if p == ptr::null() { TraceValue::Null } else { TraceValue::Str(CStr::from_ptr(p).to_bytes().to_vec()) }
+}
+
+
+unsafe fn ints<T: Copy + Into<i64>>(p: *const T, n: usize) -> TraceValue {
+	if p == ptr::null() { TraceValue::Null } else { TraceValue::Ints(slice::from_raw_parts(p, n).iter().map(|&v| v.into()).collect()) }
+}
+
+
+unsafe fn floats<T: Copy + Into<f64>>(p: *const T, n: usize) -> TraceValue {
+	if p == ptr::null() { TraceValue::Null } else { TraceValue::Floats(slice::from_raw_parts(p, n).iter().map(|&v| v.into()).collect()) }
+}
+
+
+unsafe fn bytes(p: *const c_void, n: usize) -> TraceValue {
+	if p == ptr::null() { TraceValue::Null } else { TraceValue::Bytes(slice::from_raw_parts(p as *const u8, n).to_vec()) }
+}
+
+
+/// The pointer stored by a call that writes one through `p`.
+unsafe fn pointee(p: *mut *mut c_void) -> TraceValue {
+	if p == ptr::null_mut() { TraceValue::Null } else { handle(*p) }
+}
+
+
+/// Zero-terminated list of attribute pairs, including the terminator.
+unsafe fn attrs(p: *const sys::ALCint) -> TraceValue {
+	if p == ptr::null() {
+		return TraceValue::Null;
+	}
+
+	let mut len = 0;
+	while *p.offset(len) != 0 {
+		len += 2;
+	}
+	ints(p, len as usize + 1)
+}
+
+
+unsafe fn capture(call: &sys::AlCall) -> (Vec<TraceValue>, TraceValue) {
+	use sys::AlCall::*;
+	use self::TraceValue::Void;
+
+	match *call {
+		alcCreateContext{device, attrlist, ret} => (vec![handle(device), attrs(attrlist)], handle(ret)),
+		alcMakeContextCurrent{context, ret} | alcSetThreadContext{context, ret} => (vec![handle(context)], int(ret)),
+		alcProcessContext{context, ..} | alcSuspendContext{context, ..} | alcDestroyContext{context, ..} => (vec![handle(context)], Void),
+		alcGetCurrentContext{ret} | alcGetThreadContext{ret} => (vec![], handle(ret)),
+		alcGetContextsDevice{context, ret} => (vec![handle(context)], handle(ret)),
+		alcOpenDevice{devicename, ret} => (vec![cstr(devicename)], handle(ret)),
+		alcCloseDevice{device, ret} | alcCaptureCloseDevice{device, ret} => (vec![handle(device)], int(ret)),
+		alcGetError{device, ret} => (vec![handle(device)], int(ret)),
+		alcIsExtensionPresent{device, extname, ret} => (vec![handle(device), cstr(extname)], int(ret)),
+		alcGetProcAddress{device, funcname, ret} => (vec![handle(device), cstr(funcname)], handle(ret)),
+		alcGetEnumValue{device, enumname, ret} => (vec![handle(device), cstr(enumname)], int(ret)),
+		alcGetString{device, param, ret} => (vec![handle(device), int(param)], cstr(ret)),
+		alcGetIntegerv{device, param, size, values, ..} => (vec![handle(device), int(param), int(size), ints(values, count(size))], Void),
+		alcCaptureOpenDevice{devicename, frequency, format, buffersize, ret} => (vec![cstr(devicename), int(frequency), int(format), int(buffersize)], handle(ret)),
+		alcCaptureStart{device, ..} | alcCaptureStop{device, ..} => (vec![handle(device)], Void),
+		alcCaptureSamples{device, buffer, samples, ..} => (vec![handle(device), handle(buffer), int(samples)], Void),
+
+		alDopplerFactor{value, ..} | alDopplerVelocity{value, ..} | alSpeedOfSound{value, ..} => (vec![float(value)], Void),
+		alDistanceModel{distanceModel, ..} => (vec![int(distanceModel)], Void),
+		alEnable{capability, ..} | alDisable{capability, ..} => (vec![int(capability)], Void),
+		alIsEnabled{capability, ret} => (vec![int(capability)], int(ret)),
+		alGetString{param, ret} => (vec![int(param)], cstr(ret)),
+		alGetBooleanv{param, values, ..} => (vec![int(param), ints(values, 1)], Void),
+		alGetIntegerv{param, values, ..} => (vec![int(param), ints(values, 1)], Void),
+		alGetFloatv{param, values, ..} => (vec![int(param), floats(values, 1)], Void),
+		alGetDoublev{param, values, ..} => (vec![int(param), floats(values, 1)], Void),
+		alGetBoolean{param, ret} => (vec![int(param)], int(ret)),
+		alGetInteger{param, ret} => (vec![int(param)], int(ret)),
+		alGetFloat{param, ret} => (vec![int(param)], float(ret)),
+		alGetDouble{param, ret} => (vec![int(param)], float(ret)),
+		alGetError{ret} => (vec![], int(ret)),
+		alIsExtensionPresent{extname, ret} => (vec![cstr(extname)], int(ret)),
+		alGetProcAddress{fname, ret} => (vec![cstr(fname)], handle(ret)),
+		alGetEnumValue{ename, ret} => (vec![cstr(ename)], int(ret)),
+
+		alListenerf{param, value, ..} => (vec![int(param), float(value)], Void),
+		alListener3f{param, value1, value2, value3, ..} => (vec![int(param), float(value1), float(value2), float(value3)], Void),
+		alListenerfv{param, values, ..} => (vec![int(param), floats(values, arity(param))], Void),
+		alListeneri{param, value, ..} => (vec![int(param), int(value)], Void),
+		alListener3i{param, value1, value2, value3, ..} => (vec![int(param), int(value1), int(value2), int(value3)], Void),
+		alListeneriv{param, values, ..} => (vec![int(param), ints(values, arity(param))], Void),
+		alGetListenerf{param, value, ..} => (vec![int(param), floats(value, 1)], Void),
+		alGetListener3f{param, value1, value2, value3, ..} => (vec![int(param), floats(value1, 1), floats(value2, 1), floats(value3, 1)], Void),
+		alGetListenerfv{param, values, ..} => (vec![int(param), floats(values, arity(param))], Void),
+		alGetListeneri{param, value, ..} => (vec![int(param), ints(value, 1)], Void),
+		alGetListener3i{param, value1, value2, value3, ..} => (vec![int(param), ints(value1, 1), ints(value2, 1), ints(value3, 1)], Void),
+		alGetListeneriv{param, values, ..} => (vec![int(param), ints(values, arity(param))], Void),
+
+		alGenSources{n, sources, ..} | alGenBuffers{n, buffers: sources, ..} => (vec![int(n), ints(sources, count(n))], Void),
+		alDeleteSources{n, sources, ..} | alDeleteBuffers{n, buffers: sources, ..}
+			| alSourcePlayv{n, sources, ..} | alSourceStopv{n, sources, ..} | alSourceRewindv{n, sources, ..} | alSourcePausev{n, sources, ..}
+			=> (vec![int(n), ints(sources, count(n))], Void),
+		alIsSource{source, ret} | alIsBuffer{buffer: source, ret} => (vec![int(source)], int(ret)),
+
+		alSourcef{source, param, value, ..} | alBufferf{buffer: source, param, value, ..} => (vec![int(source), int(param), float(value)], Void),
+		alSource3f{source, param, value1, value2, value3, ..} | alBuffer3f{buffer: source, param, value1, value2, value3, ..}
+			=> (vec![int(source), int(param), float(value1), float(value2), float(value3)], Void),
+		alSourcefv{source, param, values, ..} | alBufferfv{buffer: source, param, values, ..} => (vec![int(source), int(param), floats(values, arity(param))], Void),
+		alSourcei{source, param, value, ..} | alBufferi{buffer: source, param, value, ..} => (vec![int(source), int(param), int(value)], Void),
+		alSource3i{source, param, value1, value2, value3, ..} | alBuffer3i{buffer: source, param, value1, value2, value3, ..}
+			=> (vec![int(source), int(param), int(value1), int(value2), int(value3)], Void),
+		alSourceiv{source, param, values, ..} | alBufferiv{buffer: source, param, values, ..} => (vec![int(source), int(param), ints(values, arity(param))], Void),
+		alGetSourcef{source, param, value, ..} | alGetBufferf{buffer: source, param, value, ..} => (vec![int(source), int(param), floats(value, 1)], Void),
+		alGetSource3f{source, param, value1, value2, value3, ..} | alGetBuffer3f{buffer: source, param, value1, value2, value3, ..}
+			=> (vec![int(source), int(param), floats(value1, 1), floats(value2, 1), floats(value3, 1)], Void),
+		alGetSourcefv{source, param, values, ..} | alGetBufferfv{buffer: source, param, values, ..} => (vec![int(source), int(param), floats(values, arity(param))], Void),
+		alGetSourcei{source, param, value, ..} | alGetBufferi{buffer: source, param, value, ..} => (vec![int(source), int(param), ints(value, 1)], Void),
+		alGetSource3i{source, param, value1, value2, value3, ..} | alGetBuffer3i{buffer: source, param, value1, value2, value3, ..}
+			=> (vec![int(source), int(param), ints(value1, 1), ints(value2, 1), ints(value3, 1)], Void),
+		alGetSourceiv{source, param, values, ..} | alGetBufferiv{buffer: source, param, values, ..} => (vec![int(source), int(param), ints(values, arity(param))], Void),
+
+		alSourcePlay{source, ..} | alSourceStop{source, ..} | alSourceRewind{source, ..} | alSourcePause{source, ..} => (vec![int(source)], Void),
+		alSourceQueueBuffers{source, nb, buffers, ..} => (vec![int(source), int(nb), ints(buffers, count(nb))], Void),
+		alSourceUnqueueBuffers{source, nb, buffers, ..} => (vec![int(source), int(nb), ints(buffers, count(nb))], Void),
+		alBufferData{buffer, format, data, size, freq, ..} => (vec![int(buffer), int(format), bytes(data, count(size)), int(size), int(freq)], Void),
+
+		alcLoopbackOpenDeviceSOFT{deviceName, ret} => (vec![cstr(deviceName)], handle(ret)),
+		alcIsRenderFormatSupportedSOFT{device, frequency, channels, type_, ret} => (vec![handle(device), int(frequency), int(channels), int(type_)], int(ret)),
+		alcRenderSamplesSOFT{device, buffer, samples, ..} => (vec![handle(device), handle(buffer), int(samples)], Void),
+		alcGetStringiSOFT{dev, paramName, index, ret} => (vec![handle(dev), int(paramName), int(index)], cstr(ret)),
+		alcResetDeviceSOFT{dev, attrList, ret} => (vec![handle(dev), attrs(attrList)], int(ret)),
+		alcDevicePauseSOFT{dev, ..} | alcDeviceResumeSOFT{dev, ..} => (vec![handle(dev)], Void),
+		alcGetInteger64vSOFT{dev, pname, size, values, ..} => (vec![handle(dev), int(pname), int(size), ints(values, count(size))], Void),
+		alcReopenDeviceSOFT{dev, deviceName, attribs, ret} => (vec![handle(dev), cstr(deviceName), attrs(attribs)], int(ret)),
+
+		alGenAuxiliaryEffectSlots{n, auxiliaryeffectslots: objects, ..} | alGenEffects{n, effects: objects, ..} | alGenFilters{n, filters: objects, ..}
+			| alDeleteAuxiliaryEffectSlots{n, auxiliaryeffectslots: objects, ..} | alDeleteEffects{n, effects: objects, ..} | alDeleteFilters{n, filters: objects, ..}
+			=> (vec![int(n), ints(objects, count(n))], Void),
+		alAuxiliaryEffectSloti{auxiliaryeffectslot: object, param, iValue, ..} | alEffecti{effect: object, param, iValue, ..} | alFilteri{filter: object, param, iValue, ..}
+			=> (vec![int(object), int(param), int(iValue)], Void),
+		alAuxiliaryEffectSlotf{auxiliaryeffectslot: object, param, flValue, ..} | alEffectf{effect: object, param, flValue, ..} | alFilterf{filter: object, param, flValue, ..}
+			=> (vec![int(object), int(param), float(flValue)], Void),
+		alAuxiliaryEffectSlotiv{auxiliaryeffectslot: object, param, piValues, ..} | alEffectiv{effect: object, param, piValues, ..} | alFilteriv{filter: object, param, piValues, ..}
+			| alGetAuxiliaryEffectSlotiv{auxiliaryeffectslot: object, param, piValues, ..} | alGetEffectiv{effect: object, param, piValues, ..} | alGetFilteriv{filter: object, param, piValues, ..}
+			=> (vec![int(object), int(param), ints(piValues, efx_arity(param))], Void),
+		alAuxiliaryEffectSlotfv{auxiliaryeffectslot: object, param, pflValues, ..} | alEffectfv{effect: object, param, pflValues, ..} | alFilterfv{filter: object, param, pflValues, ..}
+			| alGetAuxiliaryEffectSlotfv{auxiliaryeffectslot: object, param, pflValues, ..} | alGetEffectfv{effect: object, param, pflValues, ..} | alGetFilterfv{filter: object, param, pflValues, ..}
+			=> (vec![int(object), int(param), floats(pflValues, efx_arity(param))], Void),
+		alGetAuxiliaryEffectSloti{auxiliaryeffectslot: object, param, piValue, ..} | alGetEffecti{effect: object, param, piValue, ..} | alGetFilteri{filter: object, param, piValue, ..}
+			=> (vec![int(object), int(param), ints(piValue, 1)], Void),
+		alGetAuxiliaryEffectSlotf{auxiliaryeffectslot: object, param, pflValue, ..} | alGetEffectf{effect: object, param, pflValue, ..} | alGetFilterf{filter: object, param, pflValue, ..}
+			=> (vec![int(object), int(param), floats(pflValue, 1)], Void),
+
+		alBufferSubDataSOFT{buffer, format, data, offset, length, ..} => (vec![int(buffer), int(format), bytes(data, count(length)), int(offset), int(length)], Void),
+		alBufferCallbackSOFT{buffer, format, freq, callback, userptr, ..} => (vec![int(buffer), int(format), int(freq), callback.map_or(TraceValue::Null, |f| handle(f as *const c_void)), handle(userptr)], Void),
+		alGetBufferPtrSOFT{buffer, param, ptr, ..} | alGetBufferPtrvSOFT{buffer, param, ptr, ..} => (vec![int(buffer), int(param), pointee(ptr)], Void),
+		alGetBuffer3PtrSOFT{buffer, param, ptr0, ptr1, ptr2, ..} => (vec![int(buffer), int(param), pointee(ptr0), pointee(ptr1), pointee(ptr2)], Void),
+		alDeferUpdatesSOFT{..} | alProcessUpdatesSOFT{..} => (vec![], Void),
+		alEventControlSOFT{count: n, types, enable, ..} => (vec![int(n), ints(types, count(n)), int(enable)], Void),
+		alEventCallbackSOFT{callback, userParam, ..} => (vec![callback.map_or(TraceValue::Null, |f| handle(f as *const c_void)), handle(userParam)], Void),
+		alGetPointerSOFT{pname, ret} => (vec![int(pname)], handle(ret)),
+		alGetPointervSOFT{pname, values, ..} => (vec![int(pname), pointee(values)], Void),
+
+		alSourcedSOFT{source, param, value, ..} => (vec![int(source), int(param), float(value)], Void),
+		alSource3dSOFT{source, param, value1, value2, value3, ..} => (vec![int(source), int(param), float(value1), float(value2), float(value3)], Void),
+		alSourcedvSOFT{source, param, values, ..} => (vec![int(source), int(param), floats(values, arity(param))], Void),
+		alGetSourcedSOFT{source, param, value, ..} => (vec![int(source), int(param), floats(value, 1)], Void),
+		alGetSource3dSOFT{source, param, value1, value2, value3, ..} => (vec![int(source), int(param), floats(value1, 1), floats(value2, 1), floats(value3, 1)], Void),
+		alGetSourcedvSOFT{source, param, values, ..} => (vec![int(source), int(param), floats(values, arity(param))], Void),
+		alSourcei64SOFT{source, param, value, ..} => (vec![int(source), int(param), int(value)], Void),
+		alSource3i64SOFT{source, param, value1, value2, value3, ..} => (vec![int(source), int(param), int(value1), int(value2), int(value3)], Void),
+		alSourcei64vSOFT{source, param, values, ..} => (vec![int(source), int(param), ints(values, arity(param))], Void),
+		alGetSourcei64SOFT{source, param, value, ..} => (vec![int(source), int(param), ints(value, 1)], Void),
+		alGetSource3i64SOFT{source, param, value1, value2, value3, ..} => (vec![int(source), int(param), ints(value1, 1), ints(value2, 1), ints(value3, 1)], Void),
+		alGetSourcei64vSOFT{source, param, values, ..} => (vec![int(source), int(param), ints(values, arity(param))], Void),
+		alSourcePlayAtTimeSOFT{source, start_time, ..} => (vec![int(source), int(start_time)], Void),
+		alSourcePlayAtTimevSOFT{n, sources, start_time, ..} => (vec![int(n), ints(sources, count(n)), int(start_time)], Void),
+		alGetStringiSOFT{paramName, index, ret} => (vec![int(paramName), int(index)], cstr(ret)),
+	}
+}
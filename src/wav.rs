@@ -0,0 +1,579 @@
+use std::io::{self, Read, Write, Seek, SeekFrom};
+use std::fs::File;
+use std::marker::PhantomData;
+use std::mem;
+use std::path::Path;
+use std::slice;
+
+use ::{AltoError, AltoResult};
+use sys;
+use al::*;
+use ext;
+
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_ADPCM: u16 = 0x0002;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const WAVE_FORMAT_ALAW: u16 = 0x0006;
+const WAVE_FORMAT_MULAW: u16 = 0x0007;
+const WAVE_FORMAT_IMA_ADPCM: u16 = 0x0011;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+const KSDATAFORMAT_SUBTYPE_TAIL: [u8; 14] = [0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71];
+
+const MSADPCM_COEFS: [(i16, i16); 7] = [(256, 0), (512, -256), (0, 0), (192, 64), (240, 0), (460, -208), (392, -232)];
+
+// Block alignments assumed by implementations that lack `AL_SOFT_block_alignment`.
+const IMA4_DEFAULT_BLOCK_SAMPLES: u32 = 65;
+const MSADPCM_DEFAULT_BLOCK_SAMPLES: u32 = 64;
+
+
+/// Sample encoding of the data chunk of a WAV file.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum WavEncoding {
+	/// Unsigned 8-bit PCM.
+	PcmU8,
+	/// Signed 16-bit PCM.
+	PcmI16,
+	/// 32-bit IEEE float.
+	FloatF32,
+	/// 64-bit IEEE float.
+	FloatF64,
+	/// ITU G.711 A-law.
+	ALaw,
+	/// ITU G.711 mu-law.
+	MuLaw,
+	/// IMA ADPCM, stored in blocks of `samples_per_block` sample frames.
+	Ima4,
+	/// Microsoft ADPCM, stored in blocks of `samples_per_block` sample frames.
+	MsAdpcm,
+}
+
+
+/// Properties of a parsed WAV file.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WavInfo {
+	/// Encoding of the sample data.
+	pub encoding: WavEncoding,
+	/// The OpenAL format the sample data will be uploaded as.
+	pub format: Format,
+	/// Number of interleaved channels.
+	pub channels: u16,
+	/// Sample rate in hertz.
+	pub frequency: sys::ALint,
+	/// Size in bytes of a sample frame, or of a compressed block for ADPCM encodings.
+	pub block_align: u16,
+	/// Number of sample frames per block. This is 1 for uncompressed encodings.
+	pub samples_per_block: u32,
+	/// Total number of sample frames in the data chunk.
+	pub frames: usize,
+	/// Loop start and end in sample frames, as specified by the first loop of the `smpl` chunk.
+	/// The end is exclusive, matching `AL_LOOP_POINTS_SOFT`. A loop extending past the end of the data
+	/// is clamped to it, and a loop starting past the end is ignored.
+	pub loop_points: Option<(sys::ALint, sys::ALint)>,
+}
+
+
+/// A parsed RIFF/WAVE file whose sample data can be uploaded to a `Buffer`.
+pub struct Wav {
+	info: WavInfo,
+	data: Vec<u8>,
+}
+
+
+/// Writes sample frames to a RIFF/WAVE stream.
+/// The chunk sizes in the header are filled in by `finish`, or on a best-effort basis when the writer is dropped.
+pub struct WavWriter<W: Write + Seek, F: SampleFrame> {
+	writer: Option<W>,
+	start: u64,
+	header_len: u64,
+	data_len: u64,
+	marker: PhantomData<F>,
+}
+
+
+/// Implemented for sample types that can be read from a little-endian WAV data chunk.
+trait WavSample: Copy {
+	const SIZE: usize;
+
+	fn read(bytes: &[u8]) -> Self;
+
+
+	fn read_all(bytes: &[u8]) -> Vec<Self> {
+		bytes.chunks(Self::SIZE).filter(|c| c.len() == Self::SIZE).map(Self::read).collect()
+	}
+}
+
+
+impl WavSample for u8 {
+	const SIZE: usize = 1;
+	fn read(bytes: &[u8]) -> u8 { bytes[0] }
+}
+impl WavSample for i16 {
+	const SIZE: usize = 2;
+	fn read(bytes: &[u8]) -> i16 { i16::from_le_bytes([bytes[0], bytes[1]]) }
+}
+impl WavSample for f32 {
+	const SIZE: usize = 4;
+	fn read(bytes: &[u8]) -> f32 { f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) }
+}
+impl WavSample for f64 {
+	const SIZE: usize = 8;
+	fn read(bytes: &[u8]) -> f64 { f64::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]) }
+}
+impl WavSample for ALawSample {
+	const SIZE: usize = 1;
+	fn read(bytes: &[u8]) -> ALawSample { ALawSample(bytes[0]) }
+}
+impl WavSample for MuLawSample {
+	const SIZE: usize = 1;
+	fn read(bytes: &[u8]) -> MuLawSample { MuLawSample(bytes[0]) }
+}
+
+
+/// Binds the sample frame type matching an uncompressed encoding and channel count to `$f`
+/// and evaluates `$body`, or evaluates `$none` if alto has no such frame type.
+macro_rules! with_frame {
+	($enc:expr, $channels:expr, $f:ident => $body:expr, $none:expr) => {
+		match ($enc, $channels) {
+			(WavEncoding::PcmU8, 1) => { type $f = Mono<u8>; $body },
+			(WavEncoding::PcmU8, 2) => { type $f = Stereo<u8>; $body },
+			(WavEncoding::PcmU8, 4) => { type $f = McQuad<u8>; $body },
+			(WavEncoding::PcmU8, 6) => { type $f = Mc51Chn<u8>; $body },
+			(WavEncoding::PcmU8, 7) => { type $f = Mc61Chn<u8>; $body },
+			(WavEncoding::PcmU8, 8) => { type $f = Mc71Chn<u8>; $body },
+			(WavEncoding::PcmI16, 1) => { type $f = Mono<i16>; $body },
+			(WavEncoding::PcmI16, 2) => { type $f = Stereo<i16>; $body },
+			(WavEncoding::PcmI16, 4) => { type $f = McQuad<i16>; $body },
+			(WavEncoding::PcmI16, 6) => { type $f = Mc51Chn<i16>; $body },
+			(WavEncoding::PcmI16, 7) => { type $f = Mc61Chn<i16>; $body },
+			(WavEncoding::PcmI16, 8) => { type $f = Mc71Chn<i16>; $body },
+			(WavEncoding::FloatF32, 1) => { type $f = Mono<f32>; $body },
+			(WavEncoding::FloatF32, 2) => { type $f = Stereo<f32>; $body },
+			(WavEncoding::FloatF32, 4) => { type $f = McQuad<f32>; $body },
+			(WavEncoding::FloatF32, 6) => { type $f = Mc51Chn<f32>; $body },
+			(WavEncoding::FloatF32, 7) => { type $f = Mc61Chn<f32>; $body },
+			(WavEncoding::FloatF32, 8) => { type $f = Mc71Chn<f32>; $body },
+			(WavEncoding::FloatF64, 1) => { type $f = Mono<f64>; $body },
+			(WavEncoding::FloatF64, 2) => { type $f = Stereo<f64>; $body },
+			(WavEncoding::ALaw, 1) => { type $f = Mono<ALawSample>; $body },
+			(WavEncoding::ALaw, 2) => { type $f = Stereo<ALawSample>; $body },
+			(WavEncoding::MuLaw, 1) => { type $f = Mono<MuLawSample>; $body },
+			(WavEncoding::MuLaw, 2) => { type $f = Stereo<MuLawSample>; $body },
+			(WavEncoding::MuLaw, 4) => { type $f = McQuad<MuLawSample>; $body },
+			(WavEncoding::MuLaw, 6) => { type $f = Mc51Chn<MuLawSample>; $body },
+			(WavEncoding::MuLaw, 7) => { type $f = Mc61Chn<MuLawSample>; $body },
+			(WavEncoding::MuLaw, 8) => { type $f = Mc71Chn<MuLawSample>; $body },
+			_ => $none,
+		}
+	};
+}
+
+
+impl Wav {
+	/// Parse a RIFF/WAVE stream.
+	pub fn read<R: Read>(mut reader: R) -> AltoResult<Wav> {
+		let mut bytes = Vec::new();
+		reader.read_to_end(&mut bytes)?;
+		Wav::parse(bytes)
+	}
+
+
+	/// Parse a RIFF/WAVE file from disk.
+	pub fn open<P: AsRef<Path>>(path: P) -> AltoResult<Wav> {
+		Wav::read(File::open(path)?)
+	}
+
+
+	fn parse(bytes: Vec<u8>) -> AltoResult<Wav> {
+		if bytes.len() < 12 || &bytes[0 .. 4] != b"RIFF" || &bytes[8 .. 12] != b"WAVE" {
+			return Err(invalid_data("not a RIFF/WAVE file"));
+		}
+
+		let mut fmt = None;
+		let mut data = None;
+		let mut loop_points = None;
+
+		let mut pos = 12;
+		while pos + 8 <= bytes.len() {
+			let id = &bytes[pos .. pos + 4];
+			let size = read_u32(&bytes[pos + 4 ..]) as usize;
+			let start = pos + 8;
+			// Truncated files are common, so clamp the final chunk rather than rejecting it.
+			let end = if size > bytes.len() - start { bytes.len() } else { start + size };
+			let chunk = &bytes[start .. end];
+
+			match id {
+				b"fmt " => fmt = Some(Fmt::parse(chunk)?),
+				b"data" if data.is_none() => data = Some((start, end)),
+				b"smpl" => loop_points = parse_smpl(chunk),
+				_ => (),
+			}
+
+			pos = end + (size & 1);
+		}
+
+		let fmt = fmt.ok_or_else(|| invalid_data("missing fmt chunk"))?;
+		let (start, end) = data.ok_or_else(|| invalid_data("missing data chunk"))?;
+
+		let (encoding, samples_per_block) = fmt.encoding()?;
+		let format = match encoding {
+			WavEncoding::Ima4 => match fmt.channels {
+				1 => Format::ExtIma4(ExtIma4Format::Mono),
+				2 => Format::ExtIma4(ExtIma4Format::Stereo),
+				_ => return Err(invalid_data("unsupported channel count")),
+			},
+			WavEncoding::MsAdpcm => match fmt.channels {
+				1 => Format::SoftMsadpcm(SoftMsadpcmFormat::Mono),
+				2 => Format::SoftMsadpcm(SoftMsadpcmFormat::Stereo),
+				_ => return Err(invalid_data("unsupported channel count")),
+			},
+			_ => with_frame!(encoding, fmt.channels, F => <F as SampleFrame>::format(), return Err(invalid_data("unsupported channel count"))),
+		};
+
+		// Only whole frames or blocks can be uploaded.
+		let blocks = (end - start) / fmt.block_align as usize;
+		let data_len = blocks * fmt.block_align as usize;
+		let mut bytes = bytes;
+		bytes.truncate(start + data_len);
+		bytes.drain(.. start);
+
+		let frames = blocks * samples_per_block as usize;
+		let loop_points = loop_points
+			.map(|(s, e)| (s, e.min(frames.min(sys::ALint::max_value() as usize) as sys::ALint)))
+			.filter(|&(s, e)| s < e);
+
+		Ok(Wav{
+			info: WavInfo{
+				encoding: encoding,
+				format: format,
+				channels: fmt.channels,
+				frequency: fmt.frequency as sys::ALint,
+				block_align: fmt.block_align,
+				samples_per_block: samples_per_block,
+				frames: frames,
+				loop_points: loop_points,
+			},
+			data: bytes,
+		})
+	}
+
+
+	/// Properties of the parsed file.
+	pub fn info(&self) -> &WavInfo { &self.info }
+	/// Raw contents of the data chunk, truncated to a whole number of frames or blocks.
+	pub fn data(&self) -> &[u8] { &self.data }
+
+
+	/// Upload the sample data to a new buffer.
+	/// If the file specifies loop points and `AL_SOFT_loop_points` is present, they are applied to the buffer.
+	pub fn to_buffer(&self, ctx: &Context) -> AltoResult<Buffer> {
+		let info = &self.info;
+		let buf = match info.encoding {
+			WavEncoding::Ima4 | WavEncoding::MsAdpcm => {
+				let default_block_samples = if info.encoding == WavEncoding::Ima4 { IMA4_DEFAULT_BLOCK_SAMPLES } else { MSADPCM_DEFAULT_BLOCK_SAMPLES };
+				let block_align = if ctx.is_extension_present(ext::Al::SoftBlockAlignment) {
+					Some(info.samples_per_block as sys::ALint)
+				} else if info.samples_per_block == default_block_samples {
+					None
+				} else {
+					return Err(AltoError::ExtensionNotPresent);
+				};
+
+				Buffer::new_raw(ctx.clone(), info.format, &self.data, info.frames as sys::ALsizei, info.frequency, block_align)?
+			},
+			_ => with_frame!(
+				info.encoding,
+				info.channels,
+				F => ctx.new_buffer::<F, _>(<<F as SampleFrame>::Sample as WavSample>::read_all(&self.data), info.frequency)?,
+				return Err(invalid_data("unsupported channel count"))
+			),
+		};
+
+		if let Some(loop_points) = info.loop_points {
+			if ctx.is_extension_present(ext::Al::SoftLoopPoints) {
+				buf.set_soft_loop_points(loop_points)?;
+			}
+		}
+
+		Ok(buf)
+	}
+}
+
+
+/// Parse a RIFF/WAVE stream and upload its contents to a new buffer.
+pub fn load<R: Read>(ctx: &Context, reader: R) -> AltoResult<(Buffer, WavInfo)> {
+	let wav = Wav::read(reader)?;
+	wav.to_buffer(ctx).map(|buf| (buf, wav.info))
+}
+
+
+impl<W: Write + Seek, F: SampleFrame> WavWriter<W, F> {
+	/// Write a header for `F` at the current position of `writer`.
+	/// Uncompressed formats with up to 8 channels are supported, except `AL_FORMAT_REAR*` and B-Format.
+	pub fn new(mut writer: W, frequency: sys::ALint) -> AltoResult<WavWriter<W, F>> {
+		let encoding = frame_encoding(F::format()).ok_or(AltoError::InvalidValue)?;
+		if frequency <= 0 {
+			return Err(AltoError::InvalidValue);
+		}
+
+		let (tag, bits) = match encoding {
+			WavEncoding::PcmU8 => (WAVE_FORMAT_PCM, 8),
+			WavEncoding::PcmI16 => (WAVE_FORMAT_PCM, 16),
+			WavEncoding::FloatF32 => (WAVE_FORMAT_IEEE_FLOAT, 32),
+			WavEncoding::FloatF64 => (WAVE_FORMAT_IEEE_FLOAT, 64),
+			WavEncoding::ALaw => (WAVE_FORMAT_ALAW, 8),
+			WavEncoding::MuLaw => (WAVE_FORMAT_MULAW, 8),
+			WavEncoding::Ima4 | WavEncoding::MsAdpcm => unreachable!(),
+		};
+		let channels = F::len() as u16;
+		let block_align = mem::size_of::<F>() as u16;
+		let byte_rate = (frequency as u32).checked_mul(block_align as u32).ok_or(AltoError::InvalidValue)?;
+		// Layouts beyond stereo need an explicit speaker mask to be interpreted correctly.
+		let mask: u32 = match channels {
+			1 | 2 => 0,
+			4 => 0x33,
+			6 => 0x3F,
+			7 => 0x13F,
+			8 => 0x63F,
+			_ => return Err(AltoError::InvalidValue),
+		};
+
+		let mut fmt = Vec::with_capacity(40);
+		fmt.extend_from_slice(&(if mask != 0 { WAVE_FORMAT_EXTENSIBLE } else { tag }).to_le_bytes());
+		fmt.extend_from_slice(&channels.to_le_bytes());
+		fmt.extend_from_slice(&(frequency as u32).to_le_bytes());
+		fmt.extend_from_slice(&byte_rate.to_le_bytes());
+		fmt.extend_from_slice(&block_align.to_le_bytes());
+		fmt.extend_from_slice(&(bits as u16).to_le_bytes());
+		if mask != 0 {
+			fmt.extend_from_slice(&22u16.to_le_bytes());
+			fmt.extend_from_slice(&(bits as u16).to_le_bytes());
+			fmt.extend_from_slice(&mask.to_le_bytes());
+			fmt.extend_from_slice(&tag.to_le_bytes());
+			fmt.extend_from_slice(&KSDATAFORMAT_SUBTYPE_TAIL);
+		} else if tag != WAVE_FORMAT_PCM {
+			fmt.extend_from_slice(&0u16.to_le_bytes());
+		}
+
+		let start = writer.seek(SeekFrom::Current(0))?;
+		writer.write_all(b"RIFF\0\0\0\0WAVEfmt ")?;
+		writer.write_all(&(fmt.len() as u32).to_le_bytes())?;
+		writer.write_all(&fmt)?;
+		writer.write_all(b"data\0\0\0\0")?;
+
+		Ok(WavWriter{
+			writer: Some(writer),
+			start: start,
+			header_len: 12 + 8 + fmt.len() as u64 + 8,
+			data_len: 0,
+			marker: PhantomData,
+		})
+	}
+
+
+	/// Append sample frames to the data chunk.
+	pub fn write_frames(&mut self, frames: &[F]) -> AltoResult<()> {
+		let len = frames.len() * mem::size_of::<F>();
+		if self.data_len + len as u64 + self.header_len > u32::max_value() as u64 {
+			return Err(AltoError::Io(io::Error::new(io::ErrorKind::InvalidInput, "WAV data chunk would exceed 4 GiB")));
+		}
+
+		let writer = self.writer.as_mut().unwrap();
+		let bytes = unsafe { slice::from_raw_parts(frames.as_ptr() as *const u8, len) };
+		if cfg!(target_endian = "little") {
+			writer.write_all(bytes)?;
+		} else {
+			let size = mem::size_of::<F>() / F::len();
+			for sample in bytes.chunks(size) {
+				writer.write_all(&sample.iter().rev().cloned().collect::<Vec<_>>())?;
+			}
+		}
+
+		self.data_len += len as u64;
+		Ok(())
+	}
+
+
+	/// Number of sample frames written so far.
+	pub fn frames(&self) -> u64 { self.data_len / mem::size_of::<F>() as u64 }
+
+
+	/// Fill in the chunk sizes and return the underlying writer, positioned after the end of the file.
+	pub fn finish(mut self) -> AltoResult<W> {
+		self.update_header()?;
+		Ok(self.writer.take().unwrap())
+	}
+
+
+	fn update_header(&mut self) -> AltoResult<()> {
+		let header_len = self.header_len;
+		let data_len = self.data_len;
+		let writer = self.writer.as_mut().unwrap();
+
+		if data_len & 1 == 1 {
+			writer.write_all(&[0])?;
+		}
+		let end = writer.seek(SeekFrom::Current(0))?;
+
+		writer.seek(SeekFrom::Start(self.start + 4))?;
+		writer.write_all(&((header_len - 8 + data_len + (data_len & 1)) as u32).to_le_bytes())?;
+		writer.seek(SeekFrom::Start(self.start + header_len - 4))?;
+		writer.write_all(&(data_len as u32).to_le_bytes())?;
+		writer.seek(SeekFrom::Start(end))?;
+		writer.flush()?;
+		Ok(())
+	}
+}
+
+
+impl<W: Write + Seek, F: SampleFrame> Drop for WavWriter<W, F> {
+	fn drop(&mut self) {
+		if self.writer.is_some() {
+			let _ = self.update_header();
+		}
+	}
+}
+
+
+/// The encoding with which frames of the given format are stored in a WAV file.
+fn frame_encoding(format: Format) -> Option<WavEncoding> {
+	Some(match format {
+		Format::Standard(StandardFormat::MonoU8) | Format::Standard(StandardFormat::StereoU8) => WavEncoding::PcmU8,
+		Format::Standard(StandardFormat::MonoI16) | Format::Standard(StandardFormat::StereoI16) => WavEncoding::PcmI16,
+		Format::ExtFloat32(..) => WavEncoding::FloatF32,
+		Format::ExtDouble(..) => WavEncoding::FloatF64,
+		Format::ExtALaw(..) => WavEncoding::ALaw,
+		Format::ExtMuLaw(..) => WavEncoding::MuLaw,
+		Format::ExtMuLawMcFormats(ExtMuLawMcFormat::Rear) => return None,
+		Format::ExtMuLawMcFormats(..) => WavEncoding::MuLaw,
+		Format::ExtMcFormats(f) => match f {
+			ExtMcFormat::QuadU8 | ExtMcFormat::Mc51ChnU8 | ExtMcFormat::Mc61ChnU8 | ExtMcFormat::Mc71ChnU8 => WavEncoding::PcmU8,
+			ExtMcFormat::QuadI16 | ExtMcFormat::Mc51ChnI16 | ExtMcFormat::Mc61ChnI16 | ExtMcFormat::Mc71ChnI16 => WavEncoding::PcmI16,
+			ExtMcFormat::QuadF32 | ExtMcFormat::Mc51ChnF32 | ExtMcFormat::Mc61ChnF32 | ExtMcFormat::Mc71ChnF32 => WavEncoding::FloatF32,
+			ExtMcFormat::RearU8 | ExtMcFormat::RearI16 | ExtMcFormat::RearF32 => return None,
+		},
+		_ => return None,
+	})
+}
+
+
+struct Fmt {
+	tag: u16,
+	channels: u16,
+	frequency: u32,
+	block_align: u16,
+	bits: u16,
+	ext: Vec<u8>,
+}
+
+
+impl Fmt {
+	fn parse(chunk: &[u8]) -> AltoResult<Fmt> {
+		if chunk.len() < 16 {
+			return Err(invalid_data("fmt chunk too short"));
+		}
+
+		let mut fmt = Fmt{
+			tag: read_u16(&chunk[0 ..]),
+			channels: read_u16(&chunk[2 ..]),
+			frequency: read_u32(&chunk[4 ..]),
+			block_align: read_u16(&chunk[12 ..]),
+			bits: read_u16(&chunk[14 ..]),
+			ext: Vec::new(),
+		};
+
+		if chunk.len() >= 18 {
+			let ext_len = read_u16(&chunk[16 ..]) as usize;
+			fmt.ext.extend(chunk[18 ..].iter().take(ext_len));
+		}
+
+		if fmt.tag == WAVE_FORMAT_EXTENSIBLE {
+			// The sub-format GUID begins with the actual format tag.
+			if fmt.ext.len() < 22 {
+				return Err(invalid_data("WAVE_FORMAT_EXTENSIBLE chunk too short"));
+			}
+			fmt.tag = read_u16(&fmt.ext[6 ..]);
+		}
+
+		if fmt.channels == 0 || fmt.block_align == 0 || fmt.frequency == 0 || fmt.frequency > sys::ALint::max_value() as u32 {
+			return Err(invalid_data("invalid fmt chunk"));
+		}
+
+		Ok(fmt)
+	}
+
+
+	fn encoding(&self) -> AltoResult<(WavEncoding, u32)> {
+		let channels = self.channels as u32;
+		let block_align = self.block_align as u32;
+
+		let encoding = match (self.tag, self.bits) {
+			(WAVE_FORMAT_PCM, 8) => WavEncoding::PcmU8,
+			(WAVE_FORMAT_PCM, 16) => WavEncoding::PcmI16,
+			(WAVE_FORMAT_IEEE_FLOAT, 32) => WavEncoding::FloatF32,
+			(WAVE_FORMAT_IEEE_FLOAT, 64) => WavEncoding::FloatF64,
+			(WAVE_FORMAT_ALAW, 8) => WavEncoding::ALaw,
+			(WAVE_FORMAT_MULAW, 8) => WavEncoding::MuLaw,
+			(WAVE_FORMAT_IMA_ADPCM, 4) => {
+				if block_align % (4 * channels) != 0 || block_align / channels < 8 {
+					return Err(invalid_data("invalid IMA ADPCM block alignment"));
+				}
+				return Ok((WavEncoding::Ima4, (block_align / channels - 4) * 2 + 1));
+			},
+			(WAVE_FORMAT_ADPCM, 4) => {
+				if block_align % channels != 0 || block_align / channels < 7 {
+					return Err(invalid_data("invalid MS ADPCM block alignment"));
+				}
+				if self.ext.len() >= 4 {
+					let num_coefs = read_u16(&self.ext[2 ..]) as usize;
+					let coefs = &self.ext[4 ..];
+					if num_coefs != MSADPCM_COEFS.len() || coefs.len() < num_coefs * 4
+						|| MSADPCM_COEFS.iter().enumerate().any(|(i, &(c1, c2))| read_u16(&coefs[i * 4 ..]) as i16 != c1 || read_u16(&coefs[i * 4 + 2 ..]) as i16 != c2)
+					{
+						return Err(invalid_data("MS ADPCM with non-standard coefficients is unsupported"));
+					}
+				}
+				return Ok((WavEncoding::MsAdpcm, (block_align / channels - 7) * 2 + 2));
+			},
+			_ => return Err(invalid_data("unsupported WAVE format")),
+		};
+
+		if self.bits as u32 / 8 * channels != block_align {
+			return Err(invalid_data("block alignment does not match sample format"));
+		}
+
+		Ok((encoding, 1))
+	}
+}
+
+
+fn parse_smpl(chunk: &[u8]) -> Option<(sys::ALint, sys::ALint)> {
+	if chunk.len() < 36 + 24 || read_u32(&chunk[28 ..]) == 0 {
+		return None;
+	}
+
+	let start = read_u32(&chunk[36 + 8 ..]);
+	let end = read_u32(&chunk[36 + 12 ..]);
+	// The end of a sampler loop is inclusive.
+	if start > end || end >= sys::ALint::max_value() as u32 {
+		return None;
+	}
+
+	Some((start as sys::ALint, end as sys::ALint + 1))
+}
+
+
+fn read_u16(bytes: &[u8]) -> u16 {
+	u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+
+fn read_u32(bytes: &[u8]) -> u32 {
+	u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+
+fn invalid_data(msg: &'static str) -> AltoError {
+	AltoError::Io(io::Error::new(io::ErrorKind::InvalidData, msg))
+}
@@ -0,0 +1,87 @@
+extern crate alto;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use alto::capture::CaptureStream;
+use alto::sys::AlApi;
+use alto::{Alto, AltoError, Mono};
+
+/// Poll `cond` until it holds, failing the test if it doesn't within a few seconds.
+fn wait_for<C: FnMut() -> bool>(mut cond: C) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !cond() {
+        assert!(Instant::now() < deadline, "timed out");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn pull() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let cap = a.open_capture::<Mono<i16>>(None, 10_000, 1000).unwrap();
+    let mut stream = CaptureStream::new(cap, 500, Duration::from_millis(5)).unwrap();
+
+    wait_for(|| stream.available() == 500 && stream.stats().dropped > 0);
+    assert!(stream.is_running());
+
+    let mut out = vec![Mono { center: 1i16 }; 600];
+    assert_eq!(stream.read(&mut out), 500);
+    assert!(out[.. 500].iter().all(|f| f.center == 0));
+
+    let stats = stream.stats();
+    assert!(stats.captured >= 500);
+    assert!(stats.dropped > 0);
+    assert!(stats.captured - stats.dropped >= 500);
+    assert!(stream.take_error().is_none());
+}
+
+#[test]
+fn device_overruns() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+
+    // A 10 frame device buffer fills in 1ms, far quicker than it is polled.
+    let cap = a.open_capture::<Mono<i16>>(None, 10_000, 10).unwrap();
+    let stream = CaptureStream::new(cap, 1000, Duration::from_millis(20)).unwrap();
+    wait_for(|| stream.stats().device_overruns >= 2);
+    assert!(stream.is_running());
+
+    // A device buffer holding a whole second is never full between polls.
+    let cap = a.open_capture::<Mono<i16>>(None, 10_000, 10_000).unwrap();
+    let stream = CaptureStream::new(cap, 1000, Duration::from_millis(5)).unwrap();
+    wait_for(|| stream.stats().captured >= 100);
+    assert_eq!(stream.stats().device_overruns, 0);
+}
+
+#[test]
+fn push() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let cap = a.open_capture::<Mono<u8>>(None, 10_000, 1000).unwrap();
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = count.clone();
+    let stream = CaptureStream::with_callback(cap, Duration::from_millis(5), move |frames: &[Mono<u8>]| {
+        assert!(frames.iter().all(|f| f.center == 0x80));
+        counter.fetch_add(frames.len(), Ordering::SeqCst);
+    }).unwrap();
+
+    wait_for(|| count.load(Ordering::SeqCst) > 0);
+    drop(stream);
+    let captured = count.load(Ordering::SeqCst);
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(count.load(Ordering::SeqCst), captured);
+}
+
+#[test]
+fn callback_panic() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let cap = a.open_capture::<Mono<u8>>(None, 10_000, 1000).unwrap();
+    let stream = CaptureStream::with_callback(cap, Duration::from_millis(5), |_: &[Mono<u8>]| panic!("callback failed")).unwrap();
+
+    wait_for(|| !stream.is_running());
+    match stream.take_error() {
+        Some(AltoError::Io(_)) => (),
+        e => panic!("{:?}", e),
+    }
+}
@@ -0,0 +1,149 @@
+extern crate alto;
+
+use alto::convert::{self, Converter, Dither, Layout};
+use alto::sys::AlApi;
+use alto::{ALawSample, Alto, BFormat2D, DeviceObject, ExtFloat32Format, Format, Mc51Chn, Mono, MuLawSample, StandardFormat, Stereo};
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+#[test]
+fn sample_scaling() {
+    let src = [Mono { center: 0i16 }, Mono { center: 16384 }, Mono { center: -32768 }, Mono { center: 32767 }];
+
+    let bytes: Vec<Mono<u8>> = Converter::new(Dither::None).convert_vec(&src);
+    assert_eq!(bytes.iter().map(|f| f.center).collect::<Vec<_>>(), vec![128, 192, 0, 255]);
+
+    let floats: Vec<Mono<f32>> = convert::convert(&src);
+    assert_eq!(floats[1].center, 0.5);
+    assert_eq!(floats[2].center, -1.0);
+
+    let back: Vec<Mono<i16>> = Converter::new(Dither::None).convert_vec(&floats);
+    assert_eq!(back, src);
+
+    let wide: Vec<Mono<i16>> = convert::convert(&bytes);
+    assert_eq!(wide.iter().map(|f| f.center).collect::<Vec<_>>(), vec![0, 16384, -32768, 32512]);
+}
+
+#[test]
+fn dither() {
+    let src: Vec<_> = (0 .. 4096).map(|_| Mono { center: 64i16 }).collect();
+
+    let plain: Vec<Mono<u8>> = Converter::new(Dither::None).convert_vec(&src);
+    assert!(plain.iter().all(|f| f.center == 128));
+
+    let dithered: Vec<Mono<u8>> = Converter::new(Dither::Triangular).convert_vec(&src);
+    assert!(dithered.iter().all(|f| f.center >= 127 && f.center <= 129));
+    assert!(dithered.iter().any(|f| f.center != 128));
+
+    // Lossless conversions are never dithered.
+    let same: Vec<Mono<i16>> = Converter::new(Dither::Triangular).convert_vec(&src);
+    assert_eq!(same, src);
+}
+
+#[test]
+fn g711() {
+    for &(pcm, a, u) in &[(0i16, 0xD5u8, 0xFFu8), (1000, 0xFA, 0xCE), (-1000, 0x7A, 0x4E), (32767, 0xAA, 0x80), (-32768, 0x2A, 0x00)] {
+        assert_eq!(convert::alaw_encode(pcm), a);
+        assert_eq!(convert::mulaw_encode(pcm), u);
+    }
+
+    for code in 0 .. 256 {
+        let code = code as u8;
+        assert_eq!(convert::alaw_encode(convert::alaw_decode(code)), code);
+        if code != 0x7F {
+            assert_eq!(convert::mulaw_encode(convert::mulaw_decode(code)), code);
+        }
+    }
+
+    let src = [Stereo { left: 0.25f32, right: -0.5 }];
+    let alaw: Vec<Stereo<ALawSample>> = convert::convert(&src);
+    let mulaw: Vec<Stereo<MuLawSample>> = convert::convert(&src);
+    let a: Vec<Stereo<f32>> = convert::convert(&alaw);
+    let u: Vec<Stereo<f32>> = convert::convert(&mulaw);
+    assert!((a[0].left - 0.25).abs() < 0.01 && (a[0].right + 0.5).abs() < 0.02);
+    assert!((u[0].left - 0.25).abs() < 0.01 && (u[0].right + 0.5).abs() < 0.02);
+}
+
+#[test]
+fn downmix() {
+    let src = [Mc51Chn { front_left: 0.1f32, front_right: 0.2, front_center: 0.3, low_freq: 0.9, back_left: 0.4, back_right: 0.5 }];
+    let st: Vec<Stereo<f32>> = convert::convert(&src);
+    assert!(close(st[0].left, 0.1 + 0.3 * 0.7071 + 0.4 * 0.7071));
+    assert!(close(st[0].right, 0.2 + 0.3 * 0.7071 + 0.5 * 0.7071));
+
+    let mono: Vec<Mono<f32>> = convert::convert(&st);
+    assert!(close(mono[0].center, (st[0].left + st[0].right) / 2.0));
+
+    let up: Vec<Stereo<f32>> = convert::convert(&[Mono { center: 0.5f32 }]);
+    assert!(close(up[0].left, 0.5 * 0.7071) && close(up[0].right, up[0].left));
+}
+
+#[test]
+fn bformat() {
+    let enc: Vec<BFormat2D<f32>> = convert::convert(&[Mono { center: 0.5f32 }]);
+    assert!(close(enc[0].w, 0.5 * 0.7071) && close(enc[0].x, 0.5) && close(enc[0].y, 0.0));
+
+    let dec: Vec<Stereo<f32>> = convert::convert(&[BFormat2D { w: 0.7071f32, x: 0.0, y: 1.0 }]);
+    assert!(dec[0].left > dec[0].right);
+
+    let mono: Vec<Mono<f32>> = convert::convert(&enc);
+    assert!(close(mono[0].center, 0.5));
+}
+
+#[test]
+fn custom_matrix() {
+    assert_eq!(convert::mix_matrix(Layout::Stereo, Layout::Stereo), vec![1.0, 0.0, 0.0, 1.0]);
+    assert!(Converter::<Stereo<f32>, Stereo<f32>>::with_matrix(vec![1.0], Dither::None).is_err());
+
+    let mut swap = Converter::<Stereo<f32>, Stereo<f32>>::with_matrix(vec![0.0, 1.0, 1.0, 0.0], Dither::None).unwrap();
+    let mut out = [Stereo { left: 0.0, right: 0.0 }; 2];
+    assert_eq!(swap.convert(&[Stereo { left: 0.25, right: -0.25 }], &mut out), 1);
+    assert_eq!(out[0], Stereo { left: -0.25, right: 0.25 });
+}
+
+#[test]
+fn best_effort() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let ctx = a.open(None).unwrap().new_context(None).unwrap();
+
+    let (_, conv) = ctx.new_buffer_best_effort(&[Stereo { left: 0i16, right: 0 }; 10], 44100).unwrap();
+    assert!(!conv.is_converted());
+
+    let (mut buf, conv) = ctx.new_buffer_best_effort(&[Mono { center: MuLawSample(0xFF) }; 10], 44100).unwrap();
+    assert_eq!(conv.uploaded, Format::Standard(StandardFormat::MonoI16));
+    assert!(conv.is_converted() && !conv.downmixed);
+    assert_eq!(buf.size(), 20);
+
+    let conv = buf.set_data_best_effort(&[Stereo { left: 0.5f64, right: 0.5 }; 10], 44100).unwrap();
+    assert_eq!(conv.uploaded, Format::ExtFloat32(ExtFloat32Format::Stereo));
+    assert_eq!(buf.size(), 80);
+
+    let frame = Mc51Chn { front_left: 0.5f32, front_right: 0.5, front_center: 0.0, low_freq: 0.0, back_left: 0.0, back_right: 0.0 };
+    let conv = buf.set_data_best_effort(&[frame; 10], 44100).unwrap();
+    assert_eq!(conv.uploaded, Format::ExtFloat32(ExtFloat32Format::Stereo));
+    assert!(conv.downmixed);
+
+    let conv = buf.set_data_best_effort(&[Mc51Chn { front_left: 0u8, front_right: 0, front_center: 0, low_freq: 0, back_left: 0, back_right: 0 }; 10], 44100).unwrap();
+    assert_eq!(conv.uploaded, Format::Standard(StandardFormat::StereoU8));
+}
+
+#[test]
+fn best_effort_without_float32() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let dev = a.open(None).unwrap();
+    assert!(AlApi::software_hide_extensions(dev.as_raw(), &["AL_EXT_float32"]));
+    let ctx = dev.new_context(None).unwrap();
+    assert!(!ctx.is_extension_present(alto::ext::Al::Float32));
+
+    let (buf, conv) = ctx.new_buffer_best_effort(&[Mono { center: 0.5f32 }, Mono { center: -1.0 }], 44100).unwrap();
+    assert_eq!(conv.uploaded, Format::Standard(StandardFormat::MonoI16));
+    assert!(conv.is_converted() && !conv.downmixed);
+    assert_eq!(buf.bits(), 16);
+    assert_eq!(buf.size(), 4);
+
+    let (_, conv) = ctx.new_buffer_best_effort(&[Stereo { left: 0.25f64, right: 0.25 }; 10], 44100).unwrap();
+    assert_eq!(conv.uploaded, Format::Standard(StandardFormat::StereoI16));
+    assert!(conv.is_converted() && !conv.downmixed);
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "symphonia")]
+
+extern crate alto;
+
+use std::io::Cursor;
+
+use alto::decode::MediaFile;
+use alto::stream::Decoder;
+use alto::sys::AlApi;
+use alto::wav::WavWriter;
+use alto::{Alto, DeviceObject, ExtFloat32Format, Format, Mono, StandardFormat, Stereo};
+
+fn wav<F: alto::LoopbackFrame>(frames: &[F]) -> MediaFile {
+    let mut writer = WavWriter::new(Cursor::new(Vec::new()), 8000).unwrap();
+    writer.write_frames(frames).unwrap();
+    let bytes = writer.finish().unwrap().into_inner();
+    MediaFile::from_source(Box::new(Cursor::new(bytes)), Some("wav")).unwrap()
+}
+
+#[test]
+fn load_pcm16() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let ctx = a.open(None).unwrap().new_context(None).unwrap();
+
+    let frames: Vec<_> = (0 .. 1000).map(|i| Stereo { left: i as i16, right: -(i as i16) }).collect();
+    let file = wav(&frames);
+    assert_eq!(file.channels(), 2);
+    assert_eq!(file.frequency(), 8000);
+    assert_eq!(file.format(&ctx).unwrap(), Format::Standard(StandardFormat::StereoI16));
+
+    let buf = file.load_buffer(&ctx).unwrap();
+    assert_eq!(buf.frequency(), 8000);
+    assert_eq!(buf.size(), 4000);
+}
+
+#[test]
+fn float_and_seek() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let ctx = a.open(None).unwrap().new_context(None).unwrap();
+
+    let frames: Vec<_> = (0 .. 1000).map(|i| Mono { center: i as f32 / 1000.0 }).collect();
+    let file = wav(&frames);
+    assert_eq!(file.format(&ctx).unwrap(), Format::ExtFloat32(ExtFloat32Format::Mono));
+    assert!(wav(&frames).into_decoder::<Stereo<f32>>().is_err());
+
+    let mut dec = file.into_decoder::<Mono<f32>>().unwrap();
+    let mut out = Vec::new();
+    dec.decode(&mut out, 10).unwrap();
+    assert_eq!(out, &frames[.. 10]);
+
+    dec.seek(500).unwrap();
+    out.clear();
+    dec.decode(&mut out, 2000).unwrap();
+    assert_eq!(out, &frames[500 ..]);
+    out.clear();
+    dec.decode(&mut out, 10).unwrap();
+    assert!(out.is_empty());
+}
+
+/// A 2210-byte stereo FLAC of 1000 frames at 8000 Hz, encoded with fixed prediction and Rice-coded residuals.
+/// The left channel is `(i * 37) % 2000 - 1000` and the right channel is its negation.
+const TRIANGLE_FLAC: &[u8] = include_bytes!("data/triangle.flac");
+
+fn triangle(i: i32) -> Stereo<i16> {
+    let left = ((i * 37) % 2000 - 1000) as i16;
+    Stereo { left: left, right: -left }
+}
+
+#[test]
+fn decode_flac() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let ctx = a.open(None).unwrap().new_context(None).unwrap();
+
+    let file = MediaFile::from_source(Box::new(Cursor::new(TRIANGLE_FLAC)), Some("flac")).unwrap();
+    assert_eq!(file.channels(), 2);
+    assert_eq!(file.frequency(), 8000);
+    assert_eq!(file.format(&ctx).unwrap(), Format::Standard(StandardFormat::StereoI16));
+
+    let mut dec = file.into_decoder::<Stereo<i16>>().unwrap();
+    let mut out = Vec::new();
+    dec.decode(&mut out, 2000).unwrap();
+    assert_eq!(out, (0 .. 1000).map(triangle).collect::<Vec<_>>());
+
+    dec.seek(700).unwrap();
+    out.clear();
+    dec.decode(&mut out, 2000).unwrap();
+    assert_eq!(out, (700 .. 1000).map(triangle).collect::<Vec<_>>());
+
+    let file = MediaFile::from_source(Box::new(Cursor::new(TRIANGLE_FLAC)), Some("flac")).unwrap();
+    let buf = file.load_buffer(&ctx).unwrap();
+    assert_eq!(buf.size(), 4000);
+}
+
+#[test]
+fn float_falls_back_to_i16() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let dev = a.open(None).unwrap();
+    assert!(AlApi::software_hide_extensions(dev.as_raw(), &["AL_EXT_float32"]));
+    let ctx = dev.new_context(None).unwrap();
+
+    let frames: Vec<_> = (0 .. 1000).map(|i| Mono { center: i as f32 / 1000.0 }).collect();
+    let file = wav(&frames);
+    assert_eq!(file.format(&ctx).unwrap(), Format::Standard(StandardFormat::MonoI16));
+
+    let buf = file.load_buffer(&ctx).unwrap();
+    assert_eq!(buf.bits(), 16);
+    assert_eq!(buf.size(), 2000);
+}
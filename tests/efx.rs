@@ -0,0 +1,66 @@
+extern crate alto;
+
+use alto::efx::*;
+use alto::sys::AlApi;
+use alto::{Alto, Context};
+
+fn new_context() -> Context {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let dev = a.open(None).unwrap();
+    dev.new_context(None).unwrap()
+}
+
+#[test]
+fn effect_snapshot() {
+    let ctx = new_context();
+    let mut chorus = ctx.new_effect::<ChorusEffect>().unwrap();
+    chorus.set_waveform(ChorusWaveform::Triangle).unwrap();
+    chorus.set_phase(90).unwrap();
+    chorus.set_rate(1.5).unwrap();
+    chorus.set_depth(0.25).unwrap();
+    chorus.set_feedback(-0.5).unwrap();
+    chorus.set_delay(0.01).unwrap();
+
+    let snapshot = chorus.snapshot();
+    assert_eq!(snapshot, ChorusSnapshot {
+        waveform: ChorusWaveform::Triangle,
+        phase: 90,
+        rate: 1.5,
+        depth: 0.25,
+        feedback: -0.5,
+        delay: 0.01,
+    });
+
+    let mut other = ctx.new_effect::<ChorusEffect>().unwrap();
+    other.apply_snapshot(&snapshot).unwrap();
+    assert_eq!(other.waveform(), ChorusWaveform::Triangle);
+    assert_eq!(other.phase(), 90);
+    assert_eq!(other.rate(), 1.5);
+    assert_eq!(other.feedback(), -0.5);
+    assert_eq!(other.snapshot(), snapshot);
+}
+
+#[test]
+fn eax_reverb_snapshot() {
+    let ctx = new_context();
+    let mut reverb = ctx.new_effect::<EaxReverbEffect>().unwrap();
+    reverb.apply_snapshot(&REVERB_PRESET_CASTLE_HALL).unwrap();
+    assert_eq!(reverb.decay_time(), REVERB_PRESET_CASTLE_HALL.decay_time);
+    assert_eq!(reverb.reflections_pan::<[f32; 3]>(), REVERB_PRESET_CASTLE_HALL.reflections_pan);
+    assert_eq!(reverb.snapshot(), REVERB_PRESET_CASTLE_HALL);
+}
+
+#[test]
+fn filter_snapshot() {
+    let ctx = new_context();
+    let mut lowpass = ctx.new_filter::<LowpassFilter>().unwrap();
+    lowpass.set_gain(0.75).unwrap();
+    lowpass.set_gainhf(0.125).unwrap();
+    let snapshot = lowpass.snapshot();
+    assert_eq!(snapshot, LowpassSnapshot { gain: 0.75, gainhf: 0.125 });
+
+    let mut other = ctx.new_filter::<LowpassFilter>().unwrap();
+    other.apply_snapshot(&snapshot).unwrap();
+    assert_eq!(other.gain(), 0.75);
+    assert_eq!(other.gainhf(), 0.125);
+}
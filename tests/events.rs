@@ -0,0 +1,140 @@
+extern crate alto;
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use alto::events::{ContextEvent, EventListener};
+use alto::sys::AlApi;
+use alto::{Alto, Mono, Source, SourceState};
+
+#[test]
+fn native() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let listener = EventListener::new(&ctx, Duration::from_millis(10)).unwrap();
+    assert!(listener.is_native());
+
+    let buf = Arc::new(ctx.new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap());
+    let mut a_src = ctx.new_static_source().unwrap();
+    let mut b_src = ctx.new_static_source().unwrap();
+    a_src.set_buffer(buf.clone()).unwrap();
+    b_src.set_buffer(buf).unwrap();
+    listener.watch(&a_src, "a").unwrap();
+    a_src.play();
+    b_src.play();
+
+    let mut out = vec![Mono { center: 0.0f32 }; 20];
+    dev.soft_render_samples(&mut out[..]);
+
+    let events: Vec<_> = listener.events().try_iter().collect();
+    assert_eq!(events.len(), 2);
+    match events[0] {
+        ContextEvent::BufferCompleted("a", 1) => (),
+        ref e => panic!("{:?}", e),
+    }
+    match events[1] {
+        ContextEvent::SourceStateChanged("a", SourceState::Stopped) => (),
+        ref e => panic!("{:?}", e),
+    }
+
+    listener.unwatch(&a_src);
+    a_src.play();
+    dev.soft_render_samples(&mut out[..]);
+    assert!(listener.events().try_recv().is_err());
+}
+
+#[test]
+fn polling() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let listener = EventListener::polling(&ctx, Duration::from_millis(5)).unwrap();
+    assert!(!listener.is_native());
+
+    let mut src = ctx.new_streaming_source().unwrap();
+    for _ in 0 .. 3 {
+        src.queue_buffer(ctx.new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap()).unwrap();
+    }
+    listener.watch(&src, 7u32).unwrap();
+    src.play();
+
+    let event = listener.events().recv_timeout(Duration::from_secs(1)).unwrap();
+    match event {
+        ContextEvent::SourceStateChanged(7, SourceState::Playing) => (),
+        e => panic!("{:?}", e),
+    }
+
+    let mut out = vec![Mono { center: 0.0f32 }; 35];
+    dev.soft_render_samples(&mut out[..]);
+    thread::sleep(Duration::from_millis(50));
+
+    let events: Vec<_> = listener.events().try_iter().collect();
+    match &events[..] {
+        &[ContextEvent::BufferCompleted(7, 3), ContextEvent::SourceStateChanged(7, SourceState::Stopped)] => (),
+        e => panic!("{:?}", e),
+    }
+}
+
+#[test]
+fn single_native_listener() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let first = EventListener::new(&ctx, Duration::from_millis(5)).unwrap();
+    assert!(first.is_native());
+    let second = EventListener::<u32>::new(&ctx, Duration::from_millis(5)).unwrap();
+    assert!(!second.is_native());
+    drop(second);
+
+    // Dropping the polling listener leaves the native callback of the first in place.
+    let buf = Arc::new(ctx.new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap());
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_buffer(buf).unwrap();
+    first.watch(&src, "src").unwrap();
+    src.play();
+    let mut out = vec![Mono { center: 0.0f32 }; 20];
+    dev.soft_render_samples(&mut out[..]);
+    assert_eq!(first.events().try_iter().count(), 2);
+
+    drop(first);
+    let third = EventListener::<u32>::new(&ctx, Duration::from_millis(5)).unwrap();
+    assert!(third.is_native());
+}
+
+#[test]
+fn polling_unqueued() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let listener = EventListener::polling(&ctx, Duration::from_millis(200)).unwrap();
+    let mut src = ctx.new_streaming_source().unwrap();
+    for _ in 0 .. 3 {
+        src.queue_buffer(ctx.new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap()).unwrap();
+    }
+    listener.watch(&src, 7u32).unwrap();
+    src.play();
+
+    // Two buffers complete and are unqueued well within one polling period.
+    let mut out = vec![Mono { center: 0.0f32 }; 25];
+    dev.soft_render_samples(&mut out[..]);
+    src.unqueue_buffer().unwrap();
+    src.unqueue_buffer().unwrap();
+    dev.soft_render_samples(&mut out[.. 10]);
+
+    let mut completed = 0;
+    loop {
+        match listener.events().recv_timeout(Duration::from_secs(2)).unwrap() {
+            ContextEvent::BufferCompleted(7, n) => completed += n,
+            ContextEvent::SourceStateChanged(7, SourceState::Stopped) => break,
+            ContextEvent::SourceStateChanged(7, SourceState::Playing) => (),
+            e => panic!("{:?}", e),
+        }
+    }
+    assert_eq!(completed, 3);
+}
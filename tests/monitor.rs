@@ -0,0 +1,117 @@
+extern crate alto;
+
+use std::ffi::CString;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use alto::monitor::{DeviceEvent, DeviceMonitor};
+use alto::sys::AlApi;
+use alto::{Alto, DeviceObject};
+
+/// The software device lists are process global, so tests that depend on them must not overlap.
+static DEVICES: Mutex<()> = Mutex::new(());
+
+fn reset_devices() {
+    AlApi::software_set_devices(&["Alto Software"], &["Alto Software Capture"]);
+}
+
+fn next_event(monitor: &DeviceMonitor) -> DeviceEvent {
+    monitor.events().recv_timeout(Duration::from_secs(5)).expect("expected an event")
+}
+
+/// The next `n` events, which may arrive in any order if the monitor polls while the devices are changing.
+fn next_events(monitor: &DeviceMonitor, n: usize) -> Vec<DeviceEvent> {
+    (0 .. n).map(|_| next_event(monitor)).collect()
+}
+
+fn name(name: &str) -> CString {
+    CString::new(name).unwrap()
+}
+
+#[test]
+fn steady_state() {
+    let _lock = DEVICES.lock().unwrap_or_else(|e| e.into_inner());
+    reset_devices();
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let monitor = DeviceMonitor::new(&a, Duration::from_millis(1)).unwrap();
+    assert!(monitor.events().recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn watch_reports_disconnect() {
+    let _lock = DEVICES.lock().unwrap_or_else(|e| e.into_inner());
+    reset_devices();
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let monitor = DeviceMonitor::new(&a, Duration::from_millis(1)).unwrap();
+    let dev = a.open(None).unwrap();
+    let other = a.open(None).unwrap();
+    monitor.watch(&dev).unwrap();
+    monitor.watch(&other).unwrap();
+    monitor.unwatch(&other);
+    assert!(monitor.events().recv_timeout(Duration::from_millis(20)).is_err());
+
+    assert!(AlApi::software_disconnect(dev.as_raw()));
+    assert!(AlApi::software_disconnect(other.as_raw()));
+    match monitor.events().recv_timeout(Duration::from_secs(5)) {
+        Ok(DeviceEvent::Disconnected(d)) => assert_eq!(d.as_raw(), dev.as_raw()),
+        _ => panic!("expected a disconnect"),
+    }
+    assert!(monitor.events().recv_timeout(Duration::from_millis(20)).is_err());
+}
+
+#[test]
+fn output_changes() {
+    let _lock = DEVICES.lock().unwrap_or_else(|e| e.into_inner());
+    reset_devices();
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let monitor = DeviceMonitor::new(&a, Duration::from_millis(1)).unwrap();
+
+    AlApi::software_set_devices(&["Alto Software", "Headphones"], &["Alto Software Capture"]);
+    match next_event(&monitor) {
+        DeviceEvent::OutputAdded(ref spec) if *spec == name("Headphones") => (),
+        e => panic!("{:?}", e),
+    }
+    let dev = a.open(Some(&name("Headphones"))).unwrap();
+    assert_eq!(dev.specifier(), Some(name("Headphones")));
+
+    AlApi::software_set_devices(&["Headphones", "Alto Software"], &["Alto Software Capture"]);
+    match next_event(&monitor) {
+        DeviceEvent::DefaultOutputChanged(Some(ref spec)) if *spec == name("Headphones") => (),
+        e => panic!("{:?}", e),
+    }
+
+    AlApi::software_set_devices(&["Headphones"], &["Alto Software Capture"]);
+    match next_event(&monitor) {
+        DeviceEvent::OutputRemoved(ref spec) if *spec == name("Alto Software") => (),
+        e => panic!("{:?}", e),
+    }
+    assert!(a.open(Some(&name("Alto Software"))).is_err());
+
+    AlApi::software_set_devices(&[], &["Alto Software Capture"]);
+    let events = next_events(&monitor, 2);
+    assert!(events.iter().any(|e| matches!(*e, DeviceEvent::OutputRemoved(ref spec) if *spec == name("Headphones"))), "{:?}", events);
+    assert!(events.iter().any(|e| matches!(*e, DeviceEvent::DefaultOutputChanged(None))), "{:?}", events);
+    assert!(monitor.events().recv_timeout(Duration::from_millis(20)).is_err());
+    reset_devices();
+}
+
+#[test]
+fn capture_changes() {
+    let _lock = DEVICES.lock().unwrap_or_else(|e| e.into_inner());
+    reset_devices();
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let monitor = DeviceMonitor::new(&a, Duration::from_millis(1)).unwrap();
+
+    AlApi::software_set_devices(&["Alto Software"], &["Microphone", "Alto Software Capture"]);
+    let events = next_events(&monitor, 2);
+    assert!(events.iter().any(|e| matches!(*e, DeviceEvent::CaptureAdded(ref spec) if *spec == name("Microphone"))), "{:?}", events);
+    assert!(events.iter().any(|e| matches!(*e, DeviceEvent::DefaultCaptureChanged(Some(ref spec)) if *spec == name("Microphone"))), "{:?}", events);
+
+    AlApi::software_set_devices(&["Alto Software"], &["Microphone"]);
+    match next_event(&monitor) {
+        DeviceEvent::CaptureRemoved(ref spec) if *spec == name("Alto Software Capture") => (),
+        e => panic!("{:?}", e),
+    }
+    assert!(monitor.events().recv_timeout(Duration::from_millis(20)).is_err());
+    reset_devices();
+}
@@ -0,0 +1,66 @@
+extern crate alto;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+
+use alto::sys::AlApi;
+use alto::{Alto, Orientation};
+
+fn assert_close(a: [f32; 3], b: [f32; 3]) {
+    assert!(a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-6), "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn quaternion() {
+    // Quarter turn to the left about +Y.
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    let o = Orientation::from_quaternion([0.0, h, 0.0], h);
+    assert_close(o.at, [-1.0, 0.0, 0.0]);
+    assert_close(o.up, [0.0, 1.0, 0.0]);
+
+    let o = Orientation::from_quaternion([0.0, 0.0, 0.0], 1.0);
+    assert_eq!(o, Orientation::from(([0.0, 0.0, -1.0], [0.0, 1.0, 0.0])));
+}
+
+#[test]
+fn matrix() {
+    let o = Orientation::from_matrix([[0.0, 0.0, -2.0], [0.0, 2.0, 0.0], [2.0, 0.0, 0.0]]);
+    assert_eq!(o.at, [-2.0, 0.0, 0.0]);
+    assert_eq!(o.up, [0.0, 2.0, 0.0]);
+}
+
+#[test]
+fn set_listener() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(None).unwrap();
+
+    ctx.set_orientation(([0.0, 0.0, 1.0], [0.0, 1.0, 0.0])).unwrap();
+    assert_eq!(ctx.orientation::<[f32; 3]>(), ([0.0, 0.0, 1.0], [0.0, 1.0, 0.0]));
+
+    ctx.set_orientation(Orientation::from_quaternion([1.0, 0.0, 0.0], 0.0)).unwrap();
+    let (at, up) = ctx.orientation::<[f32; 3]>();
+    assert_close(at, [0.0, 0.0, 1.0]);
+    assert_close(up, [0.0, -1.0, 0.0]);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_types() {
+    let q = glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+    let o = Orientation::from(q);
+    assert_close(o.at, [-1.0, 0.0, 0.0]);
+    assert_eq!(Orientation::from(glam::Mat3::from_quat(q)).up, o.up);
+    let o = Orientation::from(glam::Mat4::from_rotation_translation(q, glam::Vec3::new(1.0, 2.0, 3.0)));
+    assert_close(o.at, [-1.0, 0.0, 0.0]);
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn cgmath_types() {
+    use cgmath::Rotation3;
+    let q = cgmath::Quaternion::from_angle_y(cgmath::Deg(90.0f32));
+    assert_close(Orientation::from(q).at, [-1.0, 0.0, 0.0]);
+    assert_close(Orientation::from(cgmath::Matrix3::from(q)).at, [-1.0, 0.0, 0.0]);
+}
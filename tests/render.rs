@@ -0,0 +1,112 @@
+extern crate alto;
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use alto::render::{OfflineRenderer, RenderUntil};
+use alto::sys::AlApi;
+use alto::wav::{Wav, WavEncoding, WavWriter};
+use alto::{Alto, Mono, Source, Stereo};
+
+fn load_alto() -> Alto {
+    Alto::from_api(AlApi::software()).unwrap()
+}
+
+#[test]
+fn render_until_stopped() {
+    let a = load_alto();
+    let mut r = OfflineRenderer::<Stereo<f32>>::new(&a, 100, None).unwrap();
+    r.set_block_len(4);
+
+    let buf = r.context().new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap();
+    let mut src = r.context().new_static_source().unwrap();
+    src.set_buffer(Arc::new(buf)).unwrap();
+    src.play();
+
+    let mut writer = WavWriter::new(Cursor::new(Vec::new()), 100).unwrap();
+    let frames = r.render(&mut writer, RenderUntil::Stopped, |_, _| Ok(true)).unwrap();
+    assert_eq!(frames, 12);
+
+    let wav = Wav::read(&writer.finish().unwrap().into_inner()[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::FloatF32);
+    assert_eq!(wav.info().channels, 2);
+    assert_eq!(wav.info().frequency, 100);
+    assert_eq!(wav.info().frames, 12);
+    assert_eq!(&wav.data()[.. 4], &0.5f32.to_le_bytes());
+    assert_eq!(&wav.data()[80 ..], &[0; 16]);
+}
+
+#[test]
+fn render_scripted() {
+    let a = load_alto();
+    let mut r = OfflineRenderer::<Mono<i16>>::new(&a, 100, None).unwrap();
+    r.set_block_len(5);
+
+    let buf = Arc::new(r.context().new_buffer(vec![Mono { center: 1000i16 }; 5], 100).unwrap());
+    let mut src = r.context().new_static_source().unwrap();
+    src.set_buffer(buf).unwrap();
+
+    let mut writer = WavWriter::new(Cursor::new(Vec::new()), 100).unwrap();
+    let mut starts = Vec::new();
+    let frames = r.render(&mut writer, RenderUntil::Elapsed(Duration::from_millis(225)), |_, pos| {
+        starts.push(pos);
+        if pos == 10 {
+            src.play();
+        }
+        Ok(true)
+    }).unwrap();
+    assert_eq!(frames, 22);
+    assert_eq!(starts, vec![0, 5, 10, 15, 20]);
+
+    let data = writer.finish().unwrap().into_inner();
+    let wav = Wav::read(&data[..]).unwrap();
+    let samples: Vec<i16> = wav.data().chunks(2).map(|c| i16::from_le_bytes([c[0], c[1]])).collect();
+    assert!(samples[.. 10].iter().all(|&s| s == 0));
+    assert!(samples[10 .. 15].iter().all(|&s| (s - 1000).abs() <= 1));
+    assert!(samples[15 ..].iter().all(|&s| s == 0));
+
+    // Nothing is playing, but the callback still gets the chance to start a source.
+    let mut calls = 0;
+    let frames = r.render(&mut WavWriter::new(Cursor::new(Vec::new()), 100).unwrap(), RenderUntil::Stopped, |_, _| {
+        calls += 1;
+        Ok(true)
+    }).unwrap();
+    assert_eq!((frames, calls), (0, 1));
+
+    src.play();
+    let frames = r.render(&mut WavWriter::new(Cursor::new(Vec::new()), 100).unwrap(), RenderUntil::Stopped, |_, pos| Ok(pos < 5)).unwrap();
+    assert_eq!(frames, 5);
+}
+
+#[test]
+fn render_unwatched_sources() {
+    let a = load_alto();
+    let mut r = OfflineRenderer::<Mono<i16>>::new(&a, 100, None).unwrap();
+    r.set_block_len(5);
+
+    let buf = Arc::new(r.context().new_buffer(vec![Mono { center: 1000i16 }; 10], 100).unwrap());
+    let mut short = r.context().new_static_source().unwrap();
+    short.set_buffer(buf.clone()).unwrap();
+    let mut long = r.context().new_streaming_source().unwrap();
+    for _ in 0 .. 3 {
+        long.queue_buffer(r.context().new_buffer(vec![Mono { center: 1000i16 }; 10], 100).unwrap()).unwrap();
+    }
+
+    // A source started by the callback and one dropped while playing are both accounted for.
+    let mut dropped = Some(r.context().new_static_source().unwrap());
+    dropped.as_mut().unwrap().set_buffer(buf).unwrap();
+    dropped.as_mut().unwrap().play();
+    let frames = r.render(&mut WavWriter::new(Cursor::new(Vec::new()), 100).unwrap(), RenderUntil::Stopped, |_, pos| {
+        match pos {
+            0 => short.play(),
+            5 => {
+                long.play();
+                dropped = None;
+            },
+            _ => (),
+        }
+        Ok(true)
+    }).unwrap();
+    assert_eq!(frames, 35);
+}
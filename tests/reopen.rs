@@ -0,0 +1,143 @@
+extern crate alto;
+
+use alto::efx::{EffectSnapshot, FilterSnapshot, LowpassSnapshot, REVERB_PRESET_CASTLE_HALL};
+use alto::reopen::ReopenableDevice;
+use alto::sys::{self, AlApi};
+use alto::{Alto, AltoError, DeviceObject, Mono, Source, SourceState};
+
+#[test]
+fn reopen_restores_objects() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = ReopenableDevice::open(&a, None, None).unwrap();
+    dev.context().set_gain(0.5).unwrap();
+
+    let buf = dev.new_buffer(vec![Mono { center: 0.25f32 }; 100], 100).unwrap();
+    let st = dev.new_static_source().unwrap();
+    dev.set_buffer(st, Some(buf)).unwrap();
+    {
+        let src = dev.static_source(st).unwrap();
+        src.set_pitch(2.0).unwrap();
+        src.set_looping(true);
+        src.set_sample_offset(40).unwrap();
+        src.play();
+    }
+
+    let sm = dev.new_streaming_source().unwrap();
+    dev.queue_data(sm, vec![Mono { center: 0i16 }; 30], 100).unwrap();
+    dev.queue_data(sm, vec![Mono { center: 0i16 }; 30], 100).unwrap();
+    {
+        let src = dev.streaming_source(sm).unwrap();
+        src.set_sample_offset(45).unwrap();
+        src.play();
+        src.pause();
+    }
+
+    let old = dev.device().as_raw();
+    assert!(!dev.check().unwrap());
+    dev.reopen(None).unwrap();
+    assert!(dev.device().as_raw() != old);
+
+    assert_eq!(dev.context().gain(), 0.5);
+    assert_eq!(dev.buffer(buf).unwrap().size(), 400);
+    {
+        let src = dev.static_source(st).unwrap();
+        assert_eq!(src.pitch(), 2.0);
+        assert!(src.looping());
+        assert_eq!(src.state(), SourceState::Playing);
+        assert_eq!(src.sample_offset(), 40);
+    }
+    let src = dev.streaming_source(sm).unwrap();
+    assert_eq!(src.buffers_queued(), 2);
+    assert_eq!(src.state(), SourceState::Paused);
+    assert_eq!(src.sample_offset(), 45);
+}
+
+
+#[test]
+fn check_migrates_after_disconnect() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = ReopenableDevice::open(&a, None, None).unwrap();
+
+    let slot = dev.new_aux_effect_slot().unwrap();
+    dev.aux_effect_slot(slot).unwrap().set_gain(0.5).unwrap();
+    dev.aux_effect_slot(slot).unwrap().set_aux_send_auto(false).unwrap();
+    dev.set_slot_effect(slot, Some(EffectSnapshot::EaxReverb(REVERB_PRESET_CASTLE_HALL))).unwrap();
+
+    let buf = dev.new_buffer(vec![Mono { center: 0.25f32 }; 100], 100).unwrap();
+    let src = dev.new_static_source().unwrap();
+    dev.set_buffer(src, Some(buf)).unwrap();
+    dev.set_direct_filter(src, Some(FilterSnapshot::Lowpass(LowpassSnapshot { gain: 0.75, gainhf: 0.25 }))).unwrap();
+    dev.set_aux_send(src, 1, Some(slot)).unwrap();
+    {
+        let src = dev.static_source(src).unwrap();
+        src.set_sample_offset(40).unwrap();
+        src.play();
+        src.pause();
+    }
+    assert!(!dev.check().unwrap());
+
+    let old = dev.device().as_raw();
+    assert!(AlApi::software_disconnect(old));
+    assert_eq!(dev.device().connected().unwrap(), false);
+    assert_eq!(dev.static_source(src).unwrap().state(), SourceState::Stopped);
+
+    assert!(dev.check().unwrap());
+    assert!(dev.device().as_raw() != old);
+    assert_eq!(dev.device().connected().unwrap(), true);
+    assert!(!dev.check().unwrap());
+
+    let ctx = dev.context().as_raw();
+    let slot_raw = {
+        let slot = dev.aux_effect_slot(slot).unwrap();
+        assert_eq!(slot.gain(), 0.5);
+        assert!(!slot.aux_send_auto());
+        slot.as_raw()
+    };
+    assert_eq!(AlApi::software_slot_effect(ctx, slot_raw, sys::AL_EFFECT_TYPE), Some(sys::AL_EFFECT_EAXREVERB as f32));
+    assert_eq!(AlApi::software_slot_effect(ctx, slot_raw, sys::AL_EAXREVERB_DECAY_TIME), Some(REVERB_PRESET_CASTLE_HALL.decay_time));
+
+    let src_raw = {
+        let src = dev.static_source(src).unwrap();
+        assert_eq!(src.state(), SourceState::Paused);
+        assert_eq!(src.sample_offset(), 40);
+        src.as_raw()
+    };
+    assert_eq!(AlApi::software_direct_filter(ctx, src_raw, sys::AL_FILTER_TYPE), Some(sys::AL_FILTER_LOWPASS as f32));
+    assert_eq!(AlApi::software_direct_filter(ctx, src_raw, sys::AL_LOWPASS_GAINHF), Some(0.25));
+    assert_eq!(AlApi::software_aux_send(ctx, src_raw, 0), None);
+    assert_eq!(AlApi::software_aux_send(ctx, src_raw, 1), Some(slot_raw));
+
+    // Sends and effects can still be changed through the new objects.
+    dev.set_aux_send(src, 1, None).unwrap();
+    assert_eq!(AlApi::software_aux_send(ctx, src_raw, 1), None);
+    dev.set_slot_effect(slot, None).unwrap();
+    assert_eq!(AlApi::software_slot_effect(ctx, slot_raw, sys::AL_EFFECT_TYPE), None);
+}
+
+
+#[test]
+fn ids_are_checked() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = ReopenableDevice::open(&a, None, None).unwrap();
+    let buf = dev.new_buffer(vec![Mono { center: 0u8 }; 10], 100).unwrap();
+    let src = dev.new_streaming_source().unwrap();
+
+    match dev.static_source(src) {
+        Err(AltoError::InvalidName) => (),
+        _ => panic!(),
+    }
+    match dev.set_buffer(src, Some(buf)) {
+        Err(AltoError::InvalidName) => (),
+        _ => panic!(),
+    }
+
+    let st = dev.new_static_source().unwrap();
+    dev.set_buffer(st, Some(buf)).unwrap();
+    match dev.delete_buffer(buf) {
+        Err(AltoError::InvalidOperation) => (),
+        _ => panic!(),
+    }
+    dev.delete_source(st).unwrap();
+    dev.delete_buffer(buf).unwrap();
+    assert!(dev.buffer(buf).is_err());
+}
@@ -0,0 +1,103 @@
+extern crate alto;
+
+use std::f32::consts::PI;
+
+use alto::resample::{self, Quality, ResampleDecoder, Resampler};
+use alto::stream::Decoder;
+use alto::{AltoResult, Mono, Stereo};
+
+struct VecDecoder {
+    frames: Vec<Mono<f32>>,
+    pos: usize,
+}
+
+impl Decoder for VecDecoder {
+    type Frame = Mono<f32>;
+
+    fn frequency(&self) -> i32 {
+        22050
+    }
+
+    fn decode(&mut self, frames: &mut Vec<Mono<f32>>, max: usize) -> AltoResult<()> {
+        let end = (self.pos + max.min(100)).min(self.frames.len());
+        frames.extend_from_slice(&self.frames[self.pos .. end]);
+        self.pos = end;
+        Ok(())
+    }
+
+    fn seek(&mut self, frame: u64) -> AltoResult<()> {
+        self.pos = (frame as usize).min(self.frames.len());
+        Ok(())
+    }
+}
+
+fn sine(freq: f32, rate: f32, len: usize) -> Vec<Mono<f32>> {
+    (0 .. len).map(|i| Mono { center: 0.5 * (2.0 * PI * freq * i as f32 / rate).sin() }).collect()
+}
+
+#[test]
+fn linear() {
+    let src = [Stereo { left: 0.0f32, right: 0.5 }, Stereo { left: 0.5, right: 0.0 }];
+    let out = resample::resample(&src, 1, 2, Quality::Linear).unwrap();
+    assert_eq!(out.len(), 4);
+    assert_eq!(out[0], src[0]);
+    assert_eq!(out[1], Stereo { left: 0.25, right: 0.25 });
+    assert_eq!(out[2], src[1]);
+    assert_eq!(out[3], Stereo { left: 0.25, right: 0.0 });
+}
+
+#[test]
+fn sinc_accuracy() {
+    let src = sine(1000.0, 48000.0, 48000);
+    for &quality in &[Quality::Low, Quality::Medium, Quality::High] {
+        let out = resample::resample(&src, 48000, 44100, quality).unwrap();
+        assert_eq!(out.len(), 44100);
+
+        let expected = sine(1000.0, 44100.0, 44100);
+        let err = out[100 .. 44000].iter().zip(&expected[100 .. 44000]).map(|(a, b)| (a.center - b.center).abs()).fold(0.0, f32::max);
+        assert!(err < 1e-2, "{:?}: {}", quality, err);
+    }
+
+    let i16s: Vec<_> = src.iter().map(|f| Mono { center: (f.center * 32767.0) as i16 }).collect();
+    assert_eq!(resample::resample(&i16s, 48000, 96000, Quality::Medium).unwrap().len(), 96000);
+}
+
+#[test]
+fn chunked() {
+    let src = sine(440.0, 44100.0, 5000);
+    let whole = resample::resample(&src, 44100, 48000, Quality::Medium).unwrap();
+
+    let mut r = Resampler::new(44100, 48000, Quality::Medium).unwrap();
+    let mut chunked = Vec::new();
+    for chunk in src.chunks(333) {
+        r.process(chunk, &mut chunked);
+    }
+    r.flush(&mut chunked);
+    assert_eq!(chunked, whole);
+
+    assert!(Resampler::<Mono<f32>>::new(0, 48000, Quality::Low).is_err());
+}
+
+#[test]
+fn decoder() {
+    let frames = sine(440.0, 22050.0, 2205);
+    let mut dec = ResampleDecoder::new(VecDecoder { frames: frames, pos: 0 }, 44100, Quality::Low).unwrap();
+    assert_eq!(dec.frequency(), 44100);
+
+    let mut out = Vec::new();
+    loop {
+        let len = out.len();
+        dec.decode(&mut out, 256).unwrap();
+        assert!(out.len() - len <= 256);
+        if out.len() == len {
+            break;
+        }
+    }
+    assert_eq!(out.len(), 4410);
+
+    dec.seek(2000).unwrap();
+    assert_eq!(dec.get_ref().pos, 1000);
+    let mut rest = Vec::new();
+    dec.decode(&mut rest, 10).unwrap();
+    assert_eq!(rest.len(), 10);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+
+extern crate alto;
+extern crate serde_json;
+
+use alto::efx::*;
+
+#[test]
+fn eax_reverb_preset_round_trip() {
+    let json = serde_json::to_string(&EffectSnapshot::EaxReverb(REVERB_PRESET_CASTLE_HALL)).unwrap();
+    match serde_json::from_str(&json).unwrap() {
+        EffectSnapshot::EaxReverb(p) => assert_eq!(p, REVERB_PRESET_CASTLE_HALL),
+        s => panic!("{:?}", s),
+    }
+}
+
+#[test]
+fn effect_round_trip() {
+    let chorus = ChorusSnapshot {
+        waveform: ChorusWaveform::Triangle,
+        phase: 90,
+        rate: 1.1,
+        depth: 0.1,
+        feedback: 0.25,
+        delay: 0.016,
+    };
+    let json = serde_json::to_string(&chorus).unwrap();
+    assert_eq!(serde_json::from_str::<ChorusSnapshot>(&json).unwrap(), chorus);
+
+    let morpher = EffectSnapshot::VocalMorpher(VocalMorpherSnapshot {
+        phonemea: VocalMorpherPhoneme::A,
+        phonemeb: VocalMorpherPhoneme::R,
+        phonemea_coarse_tuning: 0,
+        phonemeb_coarse_tuning: -3,
+        waveform: VocalMorpherWaveform::Sawtooth,
+        rate: 1.41,
+    });
+    let json = serde_json::to_string(&morpher).unwrap();
+    assert_eq!(serde_json::from_str::<EffectSnapshot>(&json).unwrap(), morpher);
+}
+
+#[test]
+fn filter_from_data() {
+    let json = r#"{"Bandpass": {"gain": 0.5, "gainlf": 0.25, "gainhf": 1.0}}"#;
+    assert_eq!(
+        serde_json::from_str::<FilterSnapshot>(json).unwrap(),
+        FilterSnapshot::Bandpass(BandpassSnapshot { gain: 0.5, gainlf: 0.25, gainhf: 1.0 })
+    );
+}
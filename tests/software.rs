@@ -0,0 +1,458 @@
+extern crate alto;
+
+use std::ffi::CString;
+use std::mem;
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use alto::sys::{self, AlApi};
+use alto::{Alto, AltoError, DeviceObject, Mono, Source, SourceState, Stereo};
+
+fn load_alto() -> Alto {
+    Alto::from_api(AlApi::software()).unwrap()
+}
+
+#[test]
+fn open_output() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(None).unwrap();
+
+    let buf = ctx.new_buffer(vec![Mono { center: 0i16 }; 100], 22050).unwrap();
+    assert_eq!(buf.frequency(), 22050);
+    assert_eq!(buf.bits(), 16);
+    assert_eq!(buf.channels(), 1);
+    assert_eq!(buf.size(), 200);
+
+    let mut src = ctx.new_static_source().unwrap();
+    assert_eq!(src.state(), SourceState::Initial);
+    src.set_gain(0.5).unwrap();
+    assert_eq!(src.gain(), 0.5);
+    assert!(src.set_gain(-1.0).is_err());
+}
+
+#[test]
+fn loopback_mix() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Stereo<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let buf = ctx.new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap();
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_buffer(Arc::new(buf)).unwrap();
+    src.play();
+
+    let mut out = vec![Stereo { left: 0.0f32, right: 0.0 }; 20];
+    assert_eq!(dev.soft_render_samples(&mut out[..]), 20);
+
+    assert!(out[.. 10].iter().all(|f| f.left == 0.5 && f.right == 0.5));
+    assert!(out[10 ..].iter().all(|f| f.left == 0.0 && f.right == 0.0));
+    assert_eq!(src.state(), SourceState::Stopped);
+}
+
+#[test]
+fn loopback_streaming() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Mono<i16>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let mut src = ctx.new_streaming_source().unwrap();
+    for _ in 0 .. 3 {
+        src.queue_buffer(ctx.new_buffer(vec![Mono { center: 1000i16 }; 10], 100).unwrap()).unwrap();
+    }
+    assert!(src.unqueue_buffer().is_err());
+
+    src.play();
+    let mut out = vec![Mono { center: 0i16 }; 15];
+    dev.soft_render_samples(&mut out[..]);
+    assert_eq!(src.buffers_processed(), 1);
+    assert_eq!(src.sample_offset(), 15);
+
+    src.unqueue_buffer().unwrap();
+    assert_eq!(src.buffers_queued(), 2);
+
+    dev.soft_render_samples(&mut out[..]);
+    assert_eq!(src.state(), SourceState::Stopped);
+    assert_eq!(src.buffers_processed(), 2);
+}
+
+#[test]
+fn invalid_buffer_data() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(None).unwrap();
+
+    match ctx.new_buffer(vec![Mono { center: 0u8 }; 10], 0) {
+        Err(AltoError::InvalidValue) => (),
+        r => panic!("{:?}", r.map(|_| ())),
+    }
+}
+
+#[test]
+fn buffer_sub_data() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+    assert!(ctx.is_extension_present(alto::ext::Al::SoftBufferSubData));
+
+    let buf = Arc::new(ctx.new_buffer(vec![Mono { center: 0.25f32 }; 10], 100).unwrap());
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_buffer(buf.clone()).unwrap();
+    src.set_looping(true);
+    src.play();
+
+    let mut out = vec![Mono { center: 0.0f32 }; 10];
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out.iter().all(|f| f.center == 0.25));
+
+    buf.sub_data(5, &[Mono { center: -0.5f32 }; 5][..]).unwrap();
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out[.. 5].iter().all(|f| f.center == 0.25));
+    assert!(out[5 ..].iter().all(|f| f.center == -0.5));
+
+    match buf.sub_data(6, &[Mono { center: 0.0f32 }; 5][..]) {
+        Err(AltoError::InvalidValue) => (),
+        r => panic!("{:?}", r),
+    }
+    match buf.sub_data(0, &[Stereo { left: 0.0f32, right: 0.0 }][..]) {
+        Err(AltoError::InvalidValue) => (),
+        r => panic!("{:?}", r),
+    }
+    assert_eq!(src.state(), SourceState::Playing);
+}
+
+#[test]
+fn callback_buffer() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+    assert!(ctx.is_extension_present(alto::ext::Al::SoftCallbackBuffer));
+
+    let dropped = Arc::new(());
+    let guard = dropped.clone();
+    let mut next = 0;
+    let buf = ctx
+        .new_callback_buffer(100, move |frames: &mut [Mono<f32>]| {
+            let _ = &guard;
+            let len = frames.len().min(15 - next);
+            for f in &mut frames[.. len] {
+                f.center = next as f32;
+                next += 1;
+            }
+            len
+        })
+        .unwrap();
+    assert_eq!(Arc::strong_count(&dropped), 2);
+
+    let buf = Arc::new(buf);
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_buffer(buf.clone()).unwrap();
+    src.play();
+
+    let mut out = vec![Mono { center: -1.0f32 }; 10];
+    dev.soft_render_samples(&mut out[..]);
+    assert_eq!(out.iter().map(|f| f.center).collect::<Vec<_>>(), (0 .. 10).map(|i| i as f32).collect::<Vec<_>>());
+
+    dev.soft_render_samples(&mut out[..]);
+    assert_eq!(out[.. 5].iter().map(|f| f.center).collect::<Vec<_>>(), (10 .. 15).map(|i| i as f32).collect::<Vec<_>>());
+    assert!(out[5 ..].iter().all(|f| f.center == 0.0));
+    assert_eq!(src.state(), SourceState::Stopped);
+
+    src.clear_buffer();
+    drop(buf);
+    assert_eq!(Arc::strong_count(&dropped), 1);
+}
+
+#[test]
+fn callback_buffer_queue() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let cb_ctx = ctx.clone();
+    let mut next = 0;
+    let buf = ctx
+        .new_callback_buffer(100, move |frames: &mut [Mono<f32>]| {
+            // Calls back into the implementation, which must not hold its lock while the callback runs.
+            assert_eq!(cb_ctx.gain(), 1.0);
+            for f in frames.iter_mut() {
+                f.center = next as f32;
+                next += 1;
+            }
+            frames.len()
+        })
+        .unwrap();
+
+    let mut src = ctx.new_streaming_source().unwrap();
+    src.queue_buffer(ctx.new_buffer(vec![Mono { center: -1.0f32 }; 5], 100).unwrap()).unwrap();
+    src.queue_buffer(buf).unwrap();
+    src.play();
+
+    let mut out = vec![Mono { center: 0.0f32 }; 10];
+    dev.soft_render_samples(&mut out[..]);
+    assert_eq!(out.iter().map(|f| f.center).collect::<Vec<_>>(), vec![-1.0, -1.0, -1.0, -1.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0]);
+
+    for _ in 0 .. 100 {
+        dev.soft_render_samples(&mut out[..]);
+    }
+    assert_eq!(out.iter().map(|f| f.center).collect::<Vec<_>>(), (995 .. 1005).map(|i| i as f32).collect::<Vec<_>>());
+    assert_eq!(src.state(), SourceState::Playing);
+}
+
+static CALLBACK_FREQUENCY: AtomicIsize = AtomicIsize::new(0);
+
+unsafe extern "C" fn query_frequency(_: sys::ALenum, _: sys::ALuint, _: sys::ALuint, _: sys::ALsizei, _: *const sys::ALchar, device: *mut c_void) {
+    let mut frequency = 0;
+    AlApi::software().alcGetIntegerv(device as *mut sys::ALCdevice, sys::ALC_FREQUENCY, 1, &mut frequency);
+    CALLBACK_FREQUENCY.store(frequency as isize, Ordering::SeqCst);
+}
+
+#[test]
+fn event_callback_reenters() {
+    let a = load_alto();
+    let api = a.raw_api();
+
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(None).unwrap();
+    let name = |name: &str| CString::new(name).unwrap();
+
+    unsafe {
+        let set_thread_context: unsafe extern "C" fn(*mut sys::ALCcontext) -> sys::ALCboolean =
+            mem::transmute(api.alcGetProcAddress(dev.as_raw(), name("alcSetThreadContext").as_ptr()));
+        let event_control: unsafe extern "C" fn(sys::ALsizei, *const sys::ALenum, sys::ALboolean) =
+            mem::transmute(api.alGetProcAddress(name("alEventControlSOFT").as_ptr()));
+        let event_callback: unsafe extern "C" fn(Option<unsafe extern "C" fn(sys::ALenum, sys::ALuint, sys::ALuint, sys::ALsizei, *const sys::ALchar, *mut c_void)>, *mut c_void) =
+            mem::transmute(api.alGetProcAddress(name("alEventCallbackSOFT").as_ptr()));
+
+        set_thread_context(ctx.as_raw());
+        let disconnected = api.alGetEnumValue(name("AL_EVENT_TYPE_DISCONNECTED_SOFT").as_ptr());
+        event_control(1, &disconnected, sys::AL_TRUE);
+        event_callback(Some(query_frequency), dev.as_raw() as *mut c_void);
+        set_thread_context(std::ptr::null_mut());
+    }
+
+    assert!(AlApi::software_disconnect(dev.as_raw()));
+    assert_eq!(CALLBACK_FREQUENCY.load(Ordering::SeqCst), 44100);
+}
+
+#[test]
+fn device_clock() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    assert!(dev.is_extension_present(alto::ext::Alc::SoftDeviceClock));
+    let _ctx = dev.new_context(None).unwrap();
+
+    assert_eq!(dev.latency().unwrap(), Duration::from_millis(20));
+    let start = dev.clock().unwrap();
+    thread::sleep(Duration::from_millis(50));
+    let (clock, latency) = dev.clock_latency().unwrap();
+    assert!(clock >= start + Duration::from_millis(40), "{:?} {:?}", start, clock);
+    assert_eq!(latency, Duration::from_millis(20));
+}
+
+#[test]
+fn play_at() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+    assert!(ctx.is_extension_present(alto::ext::Al::SoftSourceStartDelay));
+
+    let buf = Arc::new(ctx.new_buffer(vec![Mono { center: 0.25f32 }; 10], 100).unwrap());
+    let mut src1 = ctx.new_static_source().unwrap();
+    src1.set_buffer(buf.clone()).unwrap();
+    src1.set_looping(true);
+    let mut src2 = ctx.new_static_source().unwrap();
+    src2.set_buffer(buf.clone()).unwrap();
+    src2.set_looping(true);
+
+    // Each frame advances the device clock by 10ms.
+    let mut out = vec![Mono { center: 0.0f32 }; 10];
+    dev.soft_render_samples(&mut out[..]);
+
+    src1.play_at(Duration::from_millis(150)).unwrap();
+    assert_eq!(src1.state(), SourceState::Playing);
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out[.. 5].iter().all(|f| f.center == 0.0));
+    assert!(out[5 ..].iter().all(|f| f.center == 0.25));
+
+    src1.stop();
+    let mut group = ctx.new_source_group();
+    group.add(&src1).unwrap();
+    group.add(&src2).unwrap();
+    group.play_at(Duration::from_millis(270)).unwrap();
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out[.. 7].iter().all(|f| f.center == 0.0));
+    assert!(out[7 ..].iter().all(|f| f.center == 0.5));
+
+    match src1.play_at(Duration::from_millis(0)) {
+        Err(AltoError::InvalidValue) => (),
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn source_group() {
+    let a = load_alto();
+
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let mut stat = ctx.new_static_source().unwrap();
+    stat.set_buffer(Arc::new(ctx.new_buffer(vec![Mono { center: 0.25f32 }; 20], 100).unwrap())).unwrap();
+    let mut stream = ctx.new_streaming_source().unwrap();
+    stream.queue_buffer(ctx.new_buffer(vec![Mono { center: 0.5f32 }; 20], 100).unwrap()).unwrap();
+
+    let mut group = ctx.new_source_group();
+    group.add(&stat).unwrap();
+    group.add(&stream).unwrap();
+    group.add(&stat).unwrap();
+    assert_eq!(group.len(), 2);
+
+    group.play();
+    assert_eq!(stat.state(), SourceState::Playing);
+    assert_eq!(stream.state(), SourceState::Playing);
+    let mut out = vec![Mono { center: 0.0f32 }; 10];
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out.iter().all(|f| f.center == 0.75));
+
+    group.pause();
+    assert_eq!(stat.state(), SourceState::Paused);
+    assert_eq!(stream.state(), SourceState::Paused);
+    group.stop();
+    assert_eq!(stat.state(), SourceState::Stopped);
+    assert_eq!(stream.state(), SourceState::Stopped);
+    group.rewind();
+    assert_eq!(stat.state(), SourceState::Initial);
+    assert_eq!(stream.state(), SourceState::Initial);
+
+    group.remove(&stat);
+    assert_eq!(group.len(), 1);
+    drop(stream);
+    assert!(group.is_empty());
+    group.play();
+    assert_eq!(stat.state(), SourceState::Initial);
+
+    let other = dev.new_context(100, None).unwrap();
+    match group.add(&other.new_static_source().unwrap()) {
+        Err(AltoError::WrongContext) => (),
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn reopen_device() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    assert!(dev.is_extension_present(alto::ext::Alc::SoftReopenDevice));
+    let ctx = dev.new_context(None).unwrap();
+    let spec = dev.specifier().unwrap();
+
+    let buf = Arc::new(ctx.new_buffer(vec![Mono { center: 0.25f32 }; 100], 100).unwrap());
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_buffer(buf.clone()).unwrap();
+    src.set_looping(true);
+    src.play();
+
+    let attrs = alto::ContextAttrs { frequency: Some(48000), ..Default::default() };
+    dev.reopen(Some(&spec), Some(attrs)).unwrap();
+    assert_eq!(dev.specifier(), Some(spec.clone()));
+    assert_eq!(src.state(), SourceState::Playing);
+    assert_eq!(buf.frequency(), 100);
+
+    let bogus = CString::new("No Such Device").unwrap();
+    match dev.reopen(Some(&bogus), None) {
+        Err(AltoError::InvalidValue) => (),
+        r => panic!("{:?}", r),
+    }
+    assert_eq!(dev.specifier(), Some(spec.clone()));
+    assert_eq!(src.state(), SourceState::Playing);
+
+    dev.reopen(None, None).unwrap();
+    assert_eq!(dev.specifier(), a.default_output());
+    assert_eq!(src.state(), SourceState::Playing);
+}
+
+#[test]
+fn output_mode() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    assert!(dev.is_extension_present(alto::ext::Alc::SoftOutputMode));
+    let attrs = alto::ContextAttrs {
+        soft_output_mode: Some(alto::SoftOutputMode::StereoHrtf),
+        sync: Some(false),
+        ..Default::default()
+    };
+    let _ctx = dev.new_context(Some(attrs)).unwrap();
+    assert_eq!(dev.soft_output_mode().unwrap(), alto::SoftOutputMode::StereoBasic);
+
+    let attrs = alto::ContextAttrs { soft_output_mode: Some(alto::SoftOutputMode::Surround51), ..Default::default() };
+    dev.reopen(None, Some(attrs)).unwrap();
+    assert_eq!(dev.soft_output_mode().unwrap(), alto::SoftOutputMode::Surround51);
+
+    let dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let _ctx = dev.new_context(100, None).unwrap();
+    assert_eq!(dev.soft_output_mode().unwrap(), alto::SoftOutputMode::Mono);
+}
+
+#[test]
+fn device_attributes() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    let attrs = alto::ContextAttrs { frequency: Some(48000), mono_sources: Some(64), ..Default::default() };
+    let _ctx = dev.new_context(Some(attrs)).unwrap();
+    let attrs = dev.attributes().unwrap();
+    assert_eq!(attrs.frequency, Some(48000));
+    assert_eq!(attrs.mono_sources, Some(64));
+    assert_eq!(attrs.sync, Some(false));
+    assert_eq!(attrs.soft_output_mode, Some(alto::SoftOutputMode::StereoBasic));
+    assert!(attrs.other.is_empty());
+
+    let dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let _ctx = dev.new_context(100, None).unwrap();
+    let attrs = dev.attributes().unwrap();
+    assert_eq!(attrs.frequency, Some(100));
+    assert_eq!(attrs.soft_output_mode, Some(alto::SoftOutputMode::Mono));
+    assert_eq!(attrs.other.len(), 2);
+}
+
+#[test]
+fn capability_report() {
+    let a = load_alto();
+
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(None).unwrap();
+    let report = alto::report::CapabilityReport::new(&ctx);
+    assert_eq!(report.device.as_ref().map(|s| s.as_str()), Some("Alto Software"));
+    assert_eq!(report.alc_version, (1, 1));
+    assert_eq!(report.vendor.as_ref().map(|s| s.as_str()), Some("alto"));
+    assert!(report.alc_extensions.recognized.iter().any(|e| e == "ALC_SOFT_reopen_device"));
+    assert!(report.alc_extensions.unrecognized.iter().any(|e| e == "ALC_EXT_CAPTURE"));
+    assert!(report.al_extensions.recognized.iter().any(|e| e == "AL_SOFT_events"));
+    assert!(report.al_extensions.unrecognized.is_empty());
+    assert!(report.hrtfs.is_empty());
+    assert_eq!(report.max_aux_sends, 2);
+    assert_eq!(report.gain_limit, None);
+    assert!(report.to_string().contains("Renderer: Software"));
+
+    #[cfg(feature = "serde")]
+    {
+        extern crate serde_json;
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<alto::report::CapabilityReport>(&json).unwrap(), report);
+    }
+}
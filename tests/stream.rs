@@ -0,0 +1,214 @@
+extern crate alto;
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use alto::stream::{Decoder, StreamEvent, StreamPlayer};
+use alto::sys::AlApi;
+use alto::{Alto, AltoResult, Context, Mono, Source, SourceState};
+
+/// Counters shared between a test and the decoder running on the player's thread.
+#[derive(Default)]
+struct Log {
+    decodes: AtomicUsize,
+    frames: AtomicUsize,
+    seeks: Mutex<Vec<u64>>,
+    stall: AtomicBool,
+}
+
+/// Decodes `len` frames of a constant tone.
+struct Tone {
+    pos: u64,
+    len: u64,
+    log: Arc<Log>,
+}
+
+impl Decoder for Tone {
+    type Frame = Mono<i16>;
+
+    fn frequency(&self) -> i32 {
+        44100
+    }
+
+    fn decode(&mut self, frames: &mut Vec<Mono<i16>>, max: usize) -> AltoResult<()> {
+        if self.log.stall.swap(false, Ordering::SeqCst) {
+            thread::sleep(Duration::from_millis(200));
+        }
+
+        let n = (self.len - self.pos).min(max as u64) as usize;
+        frames.extend((0 .. n).map(|_| Mono { center: 1000 }));
+        self.pos += n as u64;
+        self.log.decodes.fetch_add(1, Ordering::SeqCst);
+        self.log.frames.fetch_add(n, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn seek(&mut self, frame: u64) -> AltoResult<()> {
+        self.pos = frame.min(self.len);
+        self.log.seeks.lock().unwrap().push(frame);
+        Ok(())
+    }
+}
+
+fn new_context() -> Context {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let dev = a.open(None).unwrap();
+    dev.new_context(None).unwrap()
+}
+
+fn new_player(ctx: &Context, len: u64) -> (StreamPlayer, Arc<Log>) {
+    let log = Arc::new(Log::default());
+    let tone = Tone { pos: 0, len: len, log: log.clone() };
+    let player = StreamPlayer::new(ctx.new_streaming_source().unwrap(), tone, 441, 3).unwrap();
+    (player, log)
+}
+
+/// Wait for the next event other than an underrun.
+fn next_event(player: &StreamPlayer) -> StreamEvent {
+    loop {
+        match player.events().recv_timeout(Duration::from_secs(5)).expect("timed out") {
+            StreamEvent::Underrun => (),
+            e => return e,
+        }
+    }
+}
+
+#[test]
+fn refill_and_end_of_stream() {
+    let ctx = new_context();
+    let (player, log) = new_player(&ctx, 8820);
+    player.play();
+
+    match next_event(&player) {
+        StreamEvent::EndOfStream => (),
+        e => panic!("{:?}", e),
+    }
+    // 20 buffers worth of audio were streamed through 3 buffers, plus the empty decode that ended the stream.
+    assert_eq!(log.decodes.load(Ordering::SeqCst), 21);
+    assert_eq!(log.frames.load(Ordering::SeqCst), 8820);
+    assert_eq!(*log.seeks.lock().unwrap(), vec![0]);
+    assert_eq!(player.source().buffers_queued(), 0);
+
+    // Playing again restarts the stream.
+    player.play();
+    match next_event(&player) {
+        StreamEvent::EndOfStream => (),
+        e => panic!("{:?}", e),
+    }
+    assert_eq!(log.frames.load(Ordering::SeqCst), 8820 * 2);
+}
+
+#[test]
+fn underrun_recovery() {
+    let ctx = new_context();
+    let (player, log) = new_player(&ctx, 44100);
+    player.play();
+
+    while log.decodes.load(Ordering::SeqCst) < 5 {
+        thread::sleep(Duration::from_millis(1));
+    }
+    // The queue holds 30ms of audio, so a 200ms stall drains it.
+    log.stall.store(true, Ordering::SeqCst);
+    match player.events().recv_timeout(Duration::from_secs(5)).expect("timed out") {
+        StreamEvent::Underrun => (),
+        e => panic!("{:?}", e),
+    }
+    assert_eq!(player.source().state(), SourceState::Playing);
+
+    match next_event(&player) {
+        StreamEvent::EndOfStream => (),
+        e => panic!("{:?}", e),
+    }
+    assert_eq!(log.frames.load(Ordering::SeqCst), 44100);
+}
+
+#[test]
+fn seek() {
+    let ctx = new_context();
+    let (player, log) = new_player(&ctx, 4410);
+
+    player.seek(1000);
+    player.play();
+    match next_event(&player) {
+        StreamEvent::EndOfStream => (),
+        e => panic!("{:?}", e),
+    }
+    assert_eq!(*log.seeks.lock().unwrap(), vec![1000, 0]);
+    assert_eq!(log.frames.load(Ordering::SeqCst), 3410);
+}
+
+#[test]
+fn source_properties() {
+    let ctx = new_context();
+    let (player, _) = new_player(&ctx, 4410);
+
+    player.source().set_gain(0.5).unwrap();
+    assert_eq!(player.source().gain(), 0.5);
+    assert_eq!(player.source().state(), SourceState::Initial);
+}
+
+/// Decodes buffers whose frames hold the number of the decode call, and blocks in the third call until released.
+struct Counter {
+    calls: u32,
+    entered: mpsc::Sender<()>,
+    release: mpsc::Receiver<()>,
+}
+
+impl Decoder for Counter {
+    type Frame = Mono<f32>;
+
+    fn frequency(&self) -> i32 {
+        100
+    }
+
+    fn decode(&mut self, frames: &mut Vec<Mono<f32>>, max: usize) -> AltoResult<()> {
+        self.calls += 1;
+        if self.calls == 3 {
+            self.entered.send(()).unwrap();
+            self.release.recv().unwrap();
+        }
+        frames.extend((0 .. max).map(|_| Mono { center: self.calls as f32 }));
+        Ok(())
+    }
+
+    fn seek(&mut self, _: u64) -> AltoResult<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn underrun_during_decode() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut dev = a.open_loopback::<Mono<f32>>(None).unwrap();
+    let ctx = dev.new_context(100, None).unwrap();
+
+    let (entered_tx, entered) = mpsc::channel();
+    let (release, release_rx) = mpsc::channel();
+    let counter = Counter { calls: 0, entered: entered_tx, release: release_rx };
+    let player = StreamPlayer::new(ctx.new_streaming_source().unwrap(), counter, 10, 2).unwrap();
+    player.play();
+    while player.source().state() != SourceState::Playing {
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    let mut out = vec![Mono { center: 0.0f32 }; 10];
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out.iter().all(|f| f.center == 1.0));
+
+    // The first buffer is refilled while the second one plays out and the source stops.
+    entered.recv_timeout(Duration::from_secs(5)).expect("timed out");
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out.iter().all(|f| f.center == 2.0));
+    assert_eq!(player.source().state(), SourceState::Stopped);
+    release.send(()).unwrap();
+
+    match player.events().recv_timeout(Duration::from_secs(5)).expect("timed out") {
+        StreamEvent::Underrun => (),
+        e => panic!("{:?}", e),
+    }
+    // The restarted source continues with new audio rather than replaying the second buffer.
+    dev.soft_render_samples(&mut out[..]);
+    assert!(out.iter().all(|f| f.center == 3.0), "{:?}", out);
+}
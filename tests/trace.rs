@@ -0,0 +1,111 @@
+extern crate alto;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use alto::efx::{ChorusEffect, LowpassFilter};
+use alto::sys::{self, AlApi};
+use alto::trace::{TraceLog, TraceValue, Tracer, Replayer};
+use alto::{Alto, ContextAttrs, DeviceObject, Mono, Source, Stereo};
+
+/// A replay makes its contexts current for the whole software implementation, so replays must not overlap.
+static REPLAY: Mutex<()> = Mutex::new(());
+
+#[test]
+fn record_and_replay() {
+    let _lock = REPLAY.lock().unwrap();
+    let tracer = Arc::new(Tracer::new());
+    let a = Alto::from_api(AlApi::traced(AlApi::software(), tracer.clone())).unwrap();
+
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(None).unwrap();
+    let buf = ctx.new_buffer(vec![Mono { center: 0.5f32 }; 10], 100).unwrap();
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_buffer(Arc::new(buf)).unwrap();
+    src.set_gain(0.5).unwrap();
+    assert!(src.set_gain(-1.0).is_err());
+    src.play();
+
+    let log = tracer.log();
+    // The software implementation has thread-local contexts, which tracing leaves in use.
+    assert!(log.entries.iter().any(|e| e.name == "alcSetThreadContext" && e.args[0] == TraceValue::Handle(ctx.as_raw() as u64)));
+    assert!(!log.entries.iter().any(|e| e.name == "alcMakeContextCurrent"));
+    let play = log.entries.iter().find(|e| e.name == "alSourcePlay").unwrap();
+    assert_eq!(play.ret, TraceValue::Void);
+    assert!(log.entries.iter().any(|e| e.name == "alGetError" && e.ret != TraceValue::Int(0)));
+    assert!(log.entries.iter().any(|e| e.name == "alBufferData" && e.args[2] == TraceValue::Bytes([0, 0, 0, 0x3f].repeat(10))));
+
+    let mut text = Vec::new();
+    log.write(&mut text).unwrap();
+    let mut log = TraceLog::read(&text[..]).unwrap();
+    assert_eq!(tracer.log().entries[.. log.entries.len()], log.entries[..]);
+
+    for entry in &mut log.entries {
+        entry.time = Duration::from_secs(0);
+    }
+    let mut replayer = Replayer::<Stereo<f32>>::new(&Alto::from_api(AlApi::software()).unwrap(), 100).unwrap();
+    assert!(replayer.replay(&log).unwrap().is_empty());
+
+    let out = replayer.render(20);
+    assert!(out[.. 10].iter().all(|f| f.left == 0.25 && f.right == 0.25));
+    assert!(out[10 ..].iter().all(|f| f.left == 0.0 && f.right == 0.0));
+}
+
+#[test]
+fn record_and_replay_efx() {
+    let _lock = REPLAY.lock().unwrap();
+    let tracer = Arc::new(Tracer::new());
+    let a = Alto::from_api(AlApi::traced(AlApi::software(), tracer.clone())).unwrap();
+
+    let dev = a.open(None).unwrap();
+    let ctx = dev.new_context(Some(ContextAttrs { mono_sources: Some(7), ..Default::default() })).unwrap();
+    let _spare = ctx.new_aux_effect_slot().unwrap();
+    let mut slot = ctx.new_aux_effect_slot().unwrap();
+    let mut chorus = ctx.new_effect::<ChorusEffect>().unwrap();
+    chorus.set_rate(1.5).unwrap();
+    assert_eq!(chorus.rate(), 1.5);
+    slot.set_effect(&chorus).unwrap();
+    let mut lowpass = ctx.new_filter::<LowpassFilter>().unwrap();
+    lowpass.set_gain(0.5).unwrap();
+    let mut src = ctx.new_static_source().unwrap();
+    src.set_direct_filter(&lowpass).unwrap();
+    src.set_aux_send_filter(0, &mut slot, &lowpass).unwrap();
+
+    let mut log = tracer.log();
+    for name in &["alGenEffects", "alEffectf", "alGetEffectf", "alGenAuxiliaryEffectSlots", "alAuxiliaryEffectSloti", "alGenFilters", "alFilterf", "alSourceiv"] {
+        assert!(log.entries.iter().any(|e| e.name == *name), "{} was not recorded", name);
+    }
+
+    // Without the spare slot, every slot in the replay is named differently from the recording.
+    let spare = log.entries.iter().position(|e| e.name == "alGenAuxiliaryEffectSlots").unwrap();
+    log.entries.remove(spare);
+    for entry in &mut log.entries {
+        entry.time = Duration::from_secs(0);
+    }
+
+    let mut replayer = Replayer::<Stereo<f32>>::new(&Alto::from_api(AlApi::software()).unwrap(), 100).unwrap();
+    // Likewise, effects and filters already on the device push the replayed names past the recorded ones.
+    let other = replayer.device().new_context(100, None).unwrap();
+    let _effect = other.new_effect::<ChorusEffect>().unwrap();
+    let _filter = other.new_filter::<LowpassFilter>().unwrap();
+    replayer.replay(&log).unwrap();
+
+    unsafe {
+        let raw = AlApi::software().alcGetCurrentContext();
+        let replayed_slot = AlApi::software_aux_send(raw, src.as_raw(), 0).unwrap();
+        assert_ne!(replayed_slot, slot.as_raw());
+        assert_eq!(AlApi::software_slot_effect(raw, replayed_slot, sys::AL_CHORUS_RATE), Some(1.5));
+        assert_eq!(AlApi::software_direct_filter(raw, src.as_raw(), sys::AL_LOWPASS_GAIN), Some(0.5));
+    }
+
+    let attrs = replayer.device().attributes().unwrap();
+    assert_eq!(attrs.mono_sources, Some(7));
+    assert_eq!(attrs.frequency, Some(100));
+}
+
+#[test]
+fn reject_garbage() {
+    assert!(TraceLog::read(&b"0.000000000 alSourcePlay i:1"[..]).is_err());
+    assert!(TraceLog::read(&b"0.000000000 alSourcePlay x:1 -> -"[..]).is_err());
+    assert!(TraceLog::read(&b"\n"[..]).unwrap().entries.is_empty());
+}
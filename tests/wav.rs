@@ -0,0 +1,275 @@
+extern crate alto;
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use alto::render::{OfflineRenderer, RenderUntil};
+use alto::sys::AlApi;
+use alto::wav::{Wav, WavEncoding, WavWriter};
+use alto::{
+    Alto, AltoError, ExtALawFormat, ExtDoubleFormat, ExtFloat32Format, ExtIma4Format, ExtMcFormat, ExtMuLawFormat, Format, Mc51Chn, McRear, Mono, SoftMsadpcmFormat,
+    Source, StandardFormat, Stereo,
+};
+
+fn wav_bytes(tag: u16, channels: u16, bits: u16, data: &[u8], extra: &[u8]) -> Vec<u8> {
+    wav_bytes_aligned(tag, channels, bits, channels * bits / 8, data, extra)
+}
+
+fn wav_bytes_aligned(tag: u16, channels: u16, bits: u16, block_align: u16, data: &[u8], extra: &[u8]) -> Vec<u8> {
+    wav_bytes_ext(tag, channels, bits, block_align, None, data, extra)
+}
+
+fn wav_bytes_ext(tag: u16, channels: u16, bits: u16, block_align: u16, fmt_ext: Option<&[u8]>, data: &[u8], extra: &[u8]) -> Vec<u8> {
+    let mut fmt = Vec::new();
+    fmt.extend_from_slice(&tag.to_le_bytes());
+    fmt.extend_from_slice(&channels.to_le_bytes());
+    fmt.extend_from_slice(&44100u32.to_le_bytes());
+    fmt.extend_from_slice(&(44100 * block_align as u32).to_le_bytes());
+    fmt.extend_from_slice(&block_align.to_le_bytes());
+    fmt.extend_from_slice(&bits.to_le_bytes());
+    if let Some(fmt_ext) = fmt_ext {
+        fmt.extend_from_slice(&(fmt_ext.len() as u16).to_le_bytes());
+        fmt.extend_from_slice(fmt_ext);
+    }
+
+    let mut body = b"WAVE".to_vec();
+    body.extend_from_slice(b"fmt ");
+    body.extend_from_slice(&(fmt.len() as u32).to_le_bytes());
+    body.extend_from_slice(&fmt);
+    body.extend_from_slice(b"data");
+    body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    body.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        body.push(0);
+    }
+    body.extend_from_slice(extra);
+
+    let mut riff = b"RIFF".to_vec();
+    riff.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    riff.extend_from_slice(&body);
+    riff
+}
+
+#[test]
+fn parse_pcm16_stereo() {
+    let data = [0u8, 0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0];
+    let wav = Wav::read(&wav_bytes(1, 2, 16, &data, &[])[..]).unwrap();
+    let info = wav.info();
+
+    assert_eq!(info.encoding, WavEncoding::PcmI16);
+    assert_eq!(info.format, Format::Standard(StandardFormat::StereoI16));
+    assert_eq!(info.channels, 2);
+    assert_eq!(info.frequency, 44100);
+    assert_eq!(info.frames, 3);
+    assert_eq!(info.loop_points, None);
+    assert_eq!(wav.data(), &data[..]);
+}
+
+fn smpl_chunk(start: u32, end: u32) -> Vec<u8> {
+    let mut smpl = vec![0u8; 36 + 24];
+    smpl[28] = 1;
+    smpl[36 + 8 .. 36 + 12].copy_from_slice(&start.to_le_bytes());
+    smpl[36 + 12 .. 36 + 16].copy_from_slice(&end.to_le_bytes());
+    let mut chunk = b"smpl".to_vec();
+    chunk.extend_from_slice(&(smpl.len() as u32).to_le_bytes());
+    chunk.extend_from_slice(&smpl);
+    chunk
+}
+
+#[test]
+fn parse_smpl_loop() {
+    let wav = Wav::read(&wav_bytes(1, 1, 8, &[128, 129, 130], &smpl_chunk(1, 2))[..]).unwrap();
+
+    assert_eq!(wav.info().encoding, WavEncoding::PcmU8);
+    assert_eq!(wav.info().frames, 3);
+    assert_eq!(wav.info().loop_points, Some((1, 3)));
+
+    let wav = Wav::read(&wav_bytes(1, 1, 8, &[128, 129, 130], &smpl_chunk(1, 99))[..]).unwrap();
+    assert_eq!(wav.info().loop_points, Some((1, 3)));
+    let wav = Wav::read(&wav_bytes(1, 1, 8, &[128, 129, 130], &smpl_chunk(3, 99))[..]).unwrap();
+    assert_eq!(wav.info().loop_points, None);
+}
+
+#[test]
+fn parse_float_and_companded() {
+    let data: Vec<u8> = [0.25f32, -0.5].iter().flat_map(|s| s.to_le_bytes().to_vec()).collect();
+    let wav = Wav::read(&wav_bytes(3, 2, 32, &data, &[])[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::FloatF32);
+    assert_eq!(wav.info().format, Format::ExtFloat32(ExtFloat32Format::Stereo));
+    assert_eq!(wav.info().frames, 1);
+    assert_eq!(wav.data(), &data[..]);
+
+    let wav = Wav::read(&wav_bytes(6, 1, 8, &[0xD5, 0x55, 0x2A], &[])[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::ALaw);
+    assert_eq!(wav.info().format, Format::ExtALaw(ExtALawFormat::Mono));
+    assert_eq!(wav.info().frames, 3);
+
+    let wav = Wav::read(&wav_bytes(7, 2, 8, &[0xFF, 0x7F, 0x00, 0x80], &[])[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::MuLaw);
+    assert_eq!(wav.info().format, Format::ExtMuLaw(ExtMuLawFormat::Stereo));
+    assert_eq!(wav.info().frames, 2);
+
+    assert!(Wav::read(&wav_bytes(3, 1, 16, &[0; 4], &[])[..]).is_err());
+}
+
+#[test]
+fn parse_ima_adpcm() {
+    // A trailing partial block is dropped.
+    let wav = Wav::read(&wav_bytes_aligned(0x11, 1, 4, 36, &[0; 36 * 2 + 10], &[])[..]).unwrap();
+    let info = wav.info();
+    assert_eq!(info.encoding, WavEncoding::Ima4);
+    assert_eq!(info.format, Format::ExtIma4(ExtIma4Format::Mono));
+    assert_eq!(info.block_align, 36);
+    assert_eq!(info.samples_per_block, 65);
+    assert_eq!(info.frames, 130);
+    assert_eq!(wav.data().len(), 72);
+
+    let wav = Wav::read(&wav_bytes_aligned(0x11, 2, 4, 72, &[0; 72], &[])[..]).unwrap();
+    assert_eq!(wav.info().format, Format::ExtIma4(ExtIma4Format::Stereo));
+    assert_eq!(wav.info().frames, 65);
+
+    assert!(Wav::read(&wav_bytes_aligned(0x11, 1, 4, 34, &[0; 34], &[])[..]).is_err());
+    assert!(Wav::read(&wav_bytes_aligned(0x11, 3, 4, 36, &[0; 36], &[])[..]).is_err());
+}
+
+/// The `fmt` extension of an MS ADPCM file: samples per block followed by the coefficient table.
+fn msadpcm_ext(samples_per_block: u16, coefs: &[(i16, i16)]) -> Vec<u8> {
+    let mut ext = samples_per_block.to_le_bytes().to_vec();
+    ext.extend_from_slice(&(coefs.len() as u16).to_le_bytes());
+    for &(c1, c2) in coefs {
+        ext.extend_from_slice(&c1.to_le_bytes());
+        ext.extend_from_slice(&c2.to_le_bytes());
+    }
+    ext
+}
+
+#[test]
+fn parse_ms_adpcm() {
+    let coefs = [(256, 0), (512, -256), (0, 0), (192, 64), (240, 0), (460, -208), (392, -232)];
+
+    // A trailing partial block is dropped.
+    let ext = msadpcm_ext(64, &coefs);
+    let wav = Wav::read(&wav_bytes_ext(2, 1, 4, 38, Some(&ext), &[0; 38 * 2 + 5], &[])[..]).unwrap();
+    let info = wav.info();
+    assert_eq!(info.encoding, WavEncoding::MsAdpcm);
+    assert_eq!(info.format, Format::SoftMsadpcm(SoftMsadpcmFormat::Mono));
+    assert_eq!(info.block_align, 38);
+    assert_eq!(info.samples_per_block, 64);
+    assert_eq!(info.frames, 128);
+    assert_eq!(wav.data().len(), 76);
+
+    // The coefficient table may be omitted, in which case the standard one is assumed.
+    let wav = Wav::read(&wav_bytes_aligned(2, 2, 4, 76, &[0; 76], &[])[..]).unwrap();
+    assert_eq!(wav.info().format, Format::SoftMsadpcm(SoftMsadpcmFormat::Stereo));
+    assert_eq!(wav.info().samples_per_block, 64);
+    assert_eq!(wav.info().frames, 64);
+
+    let mut custom = coefs;
+    custom[6] = (400, -240);
+    assert!(Wav::read(&wav_bytes_ext(2, 1, 4, 38, Some(&msadpcm_ext(64, &custom)), &[0; 38], &[])[..]).is_err());
+    assert!(Wav::read(&wav_bytes_ext(2, 1, 4, 38, Some(&msadpcm_ext(64, &coefs[.. 6])), &[0; 38], &[])[..]).is_err());
+    assert!(Wav::read(&wav_bytes_aligned(2, 1, 4, 6, &[0; 6], &[])[..]).is_err());
+}
+
+#[test]
+fn parse_float64() {
+    let data: Vec<u8> = [0.25f64, -0.5, 1.0].iter().flat_map(|s| s.to_le_bytes().to_vec()).collect();
+    let wav = Wav::read(&wav_bytes(3, 1, 64, &data, &[])[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::FloatF64);
+    assert_eq!(wav.info().format, Format::ExtDouble(ExtDoubleFormat::Mono));
+    assert_eq!(wav.info().frames, 3);
+    assert_eq!(wav.data(), &data[..]);
+
+    let mut writer = WavWriter::new(Cursor::new(Vec::new()), 22050).unwrap();
+    writer.write_frames(&[Stereo { left: 0.25f64, right: -0.75 }; 2]).unwrap();
+    let wav = Wav::read(&writer.finish().unwrap().into_inner()[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::FloatF64);
+    assert_eq!(wav.info().format, Format::ExtDouble(ExtDoubleFormat::Stereo));
+    assert_eq!(wav.info().frames, 2);
+    assert_eq!(&wav.data()[.. 16], &[0.25f64.to_le_bytes(), (-0.75f64).to_le_bytes()].concat()[..]);
+
+    assert!(Wav::read(&wav_bytes_aligned(3, 1, 64, 4, &[0; 8], &[])[..]).is_err());
+}
+
+#[test]
+fn to_buffer() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let ctx = a.open(None).unwrap().new_context(None).unwrap();
+
+    let data = [0u8, 0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0];
+    let buf = Wav::read(&wav_bytes(1, 2, 16, &data, &[])[..]).unwrap().to_buffer(&ctx).unwrap();
+    assert_eq!(buf.frequency(), 44100);
+    assert_eq!(buf.channels(), 2);
+    assert_eq!(buf.bits(), 16);
+    assert_eq!(buf.size(), 12);
+
+    let data: Vec<u8> = [0.25f32, -0.5].iter().flat_map(|s| s.to_le_bytes().to_vec()).collect();
+    let buf = Wav::read(&wav_bytes(3, 1, 32, &data, &[])[..]).unwrap().to_buffer(&ctx).unwrap();
+    assert_eq!(buf.bits(), 32);
+    assert_eq!(buf.size(), 8);
+
+    // The software backend can't decode IMA ADPCM.
+    match Wav::read(&wav_bytes_aligned(0x11, 1, 4, 36, &[0; 36], &[])[..]).unwrap().to_buffer(&ctx) {
+        Err(AltoError::ExtensionNotPresent) => (),
+        res => panic!("{:?}", res.map(|_| ())),
+    }
+}
+
+#[test]
+fn to_buffer_loop_points() {
+    let a = Alto::from_api(AlApi::software()).unwrap();
+    let mut r = OfflineRenderer::<Mono<i16>>::new(&a, 44100, None).unwrap();
+
+    let wav = Wav::read(&wav_bytes(1, 1, 8, &[128, 192, 64], &smpl_chunk(1, 2))[..]).unwrap();
+    let buf = wav.to_buffer(r.context()).unwrap();
+    assert_eq!(buf.soft_loop_points(), (1, 3));
+
+    let mut src = r.context().new_static_source().unwrap();
+    src.set_buffer(Arc::new(buf)).unwrap();
+    src.set_looping(true);
+    src.play();
+
+    let mut writer = WavWriter::new(Cursor::new(Vec::new()), 44100).unwrap();
+    r.render(&mut writer, RenderUntil::Elapsed(Duration::from_micros(7 * 1_000_000 / 44100 + 1)), |_, _| Ok(true)).unwrap();
+    let wav = Wav::read(&writer.finish().unwrap().into_inner()[..]).unwrap();
+    let samples: Vec<i16> = wav.data().chunks(2).map(|c| i16::from_le_bytes([c[0], c[1]])).collect();
+    assert_eq!(samples.len(), 7);
+    assert_eq!(samples[0], 0);
+    for pair in samples[1 ..].chunks(2) {
+        assert!(pair[0] > 10000 && pair[1] < -10000, "{:?}", samples);
+    }
+}
+
+#[test]
+fn reject_garbage() {
+    assert!(Wav::read(&b"RIFX\0\0\0\0WAVE"[..]).is_err());
+    assert!(Wav::read(&wav_bytes(0x55, 2, 16, &[0; 4], &[])[..]).is_err());
+}
+
+#[test]
+fn write_multichannel() {
+    let frame = Mc51Chn { front_left: 1i16, front_right: 2, front_center: 3, low_freq: 4, back_left: 5, back_right: 6 };
+    let mut writer = WavWriter::new(Cursor::new(Vec::new()), 48000).unwrap();
+    writer.write_frames(&[frame; 3]).unwrap();
+    assert_eq!(writer.frames(), 3);
+    let bytes = writer.finish().unwrap().into_inner();
+
+    let wav = Wav::read(&bytes[..]).unwrap();
+    assert_eq!(wav.info().encoding, WavEncoding::PcmI16);
+    assert_eq!(wav.info().format, Format::ExtMcFormats(ExtMcFormat::Mc51ChnI16));
+    assert_eq!(wav.info().frequency, 48000);
+    assert_eq!(wav.info().frames, 3);
+    assert_eq!(&wav.data()[.. 12], &[1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+    assert!(WavWriter::<_, McRear<u8>>::new(Cursor::new(Vec::new()), 48000).is_err());
+}
+
+#[test]
+fn write_large_frequency() {
+    // The byte rate would not fit in the header.
+    match WavWriter::<_, Stereo<f32>>::new(Cursor::new(Vec::new()), i32::max_value()) {
+        Err(AltoError::InvalidValue) => (),
+        res => panic!("{:?}", res.map(|_| ())),
+    }
+    assert!(WavWriter::<_, Mono<u8>>::new(Cursor::new(Vec::new()), i32::max_value()).is_ok());
+}